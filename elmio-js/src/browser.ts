@@ -11,6 +11,8 @@ export interface Browser {
     ): AbortFn;
     setInterval(handler: TimerHandler, timeout?: number): AbortFn;
     setTimeout(handler: TimerHandler, timeout?: number): AbortFn;
+    requestAnimationFrame(callback: FrameRequestCallback): AbortFn;
+    timeOrigin(): number;
     dispatchEvent(eventTarget: EventTarget, event: Event): void;
 }
 
@@ -65,6 +67,20 @@ export class RealBrowser implements Browser {
         };
     }
 
+    public requestAnimationFrame(callback: FrameRequestCallback): AbortFn {
+        const id = window.requestAnimationFrame(callback);
+
+        return {
+            abort() {
+                window.cancelAnimationFrame(id);
+            },
+        };
+    }
+
+    public timeOrigin(): number {
+        return performance.timeOrigin;
+    }
+
     public dispatchEvent(eventTarget: EventTarget, event: Event): void {
         switch (eventTarget.type) {
             case "window":
@@ -0,0 +1,14 @@
+export interface Crypto {
+    getRandomValues(length: number): Uint32Array;
+    getRandomBytes(length: number): Uint8Array;
+}
+
+export class BrowserCrypto implements Crypto {
+    public getRandomValues(length: number): Uint32Array {
+        return crypto.getRandomValues(new Uint32Array(length));
+    }
+
+    public getRandomBytes(length: number): Uint8Array {
+        return crypto.getRandomValues(new Uint8Array(length));
+    }
+}
@@ -1,9 +1,19 @@
 export interface IDate {
     now(): number;
+    timeZoneOffset(): number;
+    timeZoneName(): string | null;
 }
 
 export class BrowserDate implements IDate {
     public now(): number {
         return Date.now();
     }
+
+    public timeZoneOffset(): number {
+        return new Date().getTimezoneOffset();
+    }
+
+    public timeZoneName(): string | null {
+        return Intl.DateTimeFormat().resolvedOptions().timeZone ?? null;
+    }
 }
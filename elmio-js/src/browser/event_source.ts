@@ -0,0 +1,9 @@
+export interface EventSources {
+    open(url: string, withCredentials: boolean): EventSource;
+}
+
+export class BrowserEventSources implements EventSources {
+    public open(url: string, withCredentials: boolean): EventSource {
+        return new EventSource(url, { withCredentials });
+    }
+}
@@ -1,6 +1,7 @@
 export interface History {
     pushUrl(url: string): void;
     replaceUrl(url: string): void;
+    state(): any;
 }
 
 export class BrowserHistory implements History {
@@ -11,4 +12,8 @@ export class BrowserHistory implements History {
     public replaceUrl(url: string): void {
         history.replaceState({}, "", url);
     }
+
+    public state(): any {
+        return history.state;
+    }
 }
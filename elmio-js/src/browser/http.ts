@@ -0,0 +1,9 @@
+export interface Http {
+    fetch(url: string, init: RequestInit): Promise<Response>;
+}
+
+export class BrowserHttp implements Http {
+    public fetch(url: string, init: RequestInit): Promise<Response> {
+        return fetch(url, init);
+    }
+}
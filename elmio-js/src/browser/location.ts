@@ -1,11 +1,16 @@
 interface Location {
     assign(url: string): void;
+    href(): string;
 }
 
 class BrowserLocation implements Location {
     public assign(url: string): void {
         location.assign(url);
     }
+
+    public href(): string {
+        return location.href;
+    }
 }
 
 export { type Location, BrowserLocation };
@@ -0,0 +1,9 @@
+export interface Sockets {
+    open(url: string): WebSocket;
+}
+
+export class BrowserSockets implements Sockets {
+    public open(url: string): WebSocket {
+        return new WebSocket(url);
+    }
+}
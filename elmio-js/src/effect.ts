@@ -1,8 +1,10 @@
 import type { Browser } from "./browser";
 import type { Clipboard } from "./browser/clipboard";
 import type { Console } from "./browser/console";
+import type { Crypto } from "./browser/crypto";
 import type { IDate } from "./browser/date";
 import type { History } from "./browser/history";
+import type { Http } from "./browser/http";
 import type { LocalStorage } from "./browser/local_storage";
 import type { Location } from "./browser/location";
 import type { SessionStorage } from "./browser/session_storage";
@@ -13,10 +15,14 @@ import { ConsoleEffectHandler } from "./effect/console";
 import { CustomEffectHandler } from "./effect/custom";
 import type { Config as CustomEffectConfig } from "./effect/custom";
 import { DomEffectHandler } from "./effect/dom";
+import { EventSourceEffectHandler } from "./effect/event_source";
+import { HttpEffectHandler } from "./effect/http";
 import { LocalStorageEffectHandler } from "./effect/local_storage";
 import { NavigationEffectHandler } from "./effect/navigation";
+import { RandomEffectHandler } from "./effect/random";
 import { SessionStorageEffectHandler } from "./effect/session_storage";
 import { TimeEffectHandler } from "./effect/time";
+import { WebSocketEffectHandler } from "./effect/websocket";
 import { Domain, type Logger, Verbosity } from "./logger";
 import type {
     BrowserEffect,
@@ -25,12 +31,17 @@ import type {
     DomEffect,
     Effect,
     EffectfulMsg,
+    EventSourceEffect,
+    HttpRequest,
     LocalStorageEffect,
     Msg,
     NavigationEffect,
+    RandomEffect,
     SessionStorageEffect,
     TimeEffect,
+    WebSocketEffect,
 } from "./rust/types";
+import type { WebSocketManager } from "./subscription/websocket";
 import type JsonHelper from "./utils/json";
 
 export class EffectHandler {
@@ -40,6 +51,10 @@ export class EffectHandler {
     private readonly browserHandler: BrowserEffectHandler;
     private readonly timeHandler: TimeEffectHandler;
     private readonly navigationHandler: NavigationEffectHandler;
+    private readonly httpHandler: HttpEffectHandler;
+    private readonly webSocketHandler: WebSocketEffectHandler;
+    private readonly eventSourceHandler: EventSourceEffectHandler;
+    private readonly randomHandler: RandomEffectHandler;
     private readonly localStorageHandler: LocalStorageEffectHandler;
     private readonly sessionStorageHandler: SessionStorageEffectHandler;
     private readonly customEffectHandler: CustomEffectHandler;
@@ -53,6 +68,9 @@ export class EffectHandler {
         private readonly date: IDate,
         private readonly history: History,
         private readonly location: Location,
+        private readonly http: Http,
+        private readonly webSocketManager: WebSocketManager,
+        private readonly crypto: Crypto,
         private readonly localStorage: LocalStorage,
         private readonly sessionStorage: SessionStorage,
         private readonly jsonHelper: JsonHelper,
@@ -80,6 +98,23 @@ export class EffectHandler {
             this.logger,
         );
 
+        this.httpHandler = new HttpEffectHandler(
+            this.http,
+            this.browser,
+            this.location,
+            this.logger,
+        );
+
+        this.webSocketHandler = new WebSocketEffectHandler(
+            this.webSocketManager,
+            this.jsonHelper,
+            this.logger,
+        );
+
+        this.eventSourceHandler = new EventSourceEffectHandler(this.jsonHelper, this.logger);
+
+        this.randomHandler = new RandomEffectHandler(this.crypto, this.logger);
+
         this.localStorageHandler = new LocalStorageEffectHandler(
             this.localStorage,
             this.jsonHelper,
@@ -121,8 +156,16 @@ export class EffectHandler {
             this.clipboardHandler.handle(clipboardEffect);
         }
 
+        for (const browserEffect of groupedEffects.browserEffects) {
+            this.browserHandler.handle(browserEffect);
+        }
+
         for (const navigationEffect of groupedEffects.navigationEffects) {
-            this.navigationHandler.handle(navigationEffect);
+            this.navigationHandler.handle(navigationEffect, null);
+        }
+
+        for (const webSocketEffect of groupedEffects.webSocketEffects) {
+            this.webSocketHandler.handle(webSocketEffect, null);
         }
 
         for (const localStorageEffect of groupedEffects.localStorageEffects) {
@@ -147,7 +190,25 @@ export class EffectHandler {
                 throw new Error("Cannot run 'effectful message' effect");
 
             case "navigation":
-                return this.navigationHandler.handle(effect.config as NavigationEffect);
+                return this.navigationHandler.handle(
+                    effect.config as NavigationEffect,
+                    sourceEvent,
+                );
+
+            case "http":
+                return this.httpHandler.handle(effect.config as HttpRequest);
+
+            case "webSocket":
+                return this.webSocketHandler.handle(effect.config as WebSocketEffect, sourceEvent);
+
+            case "eventSource":
+                return this.eventSourceHandler.handle(
+                    effect.config as EventSourceEffect,
+                    sourceEvent,
+                );
+
+            case "random":
+                return this.randomHandler.handle(effect.config as RandomEffect);
 
             case "localStorage":
                 return this.localStorageHandler.handle(effect.config as LocalStorageEffect);
@@ -168,7 +229,7 @@ export class EffectHandler {
                 return this.browserHandler.handle(effect.config as BrowserEffect);
 
             case "time":
-                return this.timeHandler.handle(effect.config as TimeEffect);
+                return this.timeHandler.handle(effect.config as TimeEffect, sourceEvent);
 
             case "custom":
                 return this.customEffectHandler.handle(effect.config);
@@ -198,7 +259,9 @@ interface GroupedEffects {
     domEffects: DomEffect[];
     consoleEffects: ConsoleEffect[];
     clipboardEffects: ClipboardEffect[];
+    browserEffects: BrowserEffect[];
     navigationEffects: NavigationEffect[];
+    webSocketEffects: WebSocketEffect[];
     localStorageEffects: LocalStorageEffect[];
     sessionStorageEffects: SessionStorageEffect[];
     customEffects: any[];
@@ -210,7 +273,9 @@ function groupEffects(effects: Effect[], logger: Logger): GroupedEffects {
         domEffects: [],
         consoleEffects: [],
         clipboardEffects: [],
+        browserEffects: [],
         navigationEffects: [],
+        webSocketEffects: [],
         localStorageEffects: [],
         sessionStorageEffects: [],
         customEffects: [],
@@ -234,10 +299,18 @@ function groupEffects(effects: Effect[], logger: Logger): GroupedEffects {
                 groupedEffects.clipboardEffects.push(effect.config as ClipboardEffect);
                 break;
 
+            case "browser":
+                groupedEffects.browserEffects.push(effect.config as BrowserEffect);
+                break;
+
             case "navigation":
                 groupedEffects.navigationEffects.push(effect.config as NavigationEffect);
                 break;
 
+            case "webSocket":
+                groupedEffects.webSocketEffects.push(effect.config as WebSocketEffect);
+                break;
+
             case "localStorage":
                 groupedEffects.localStorageEffects.push(effect.config as LocalStorageEffect);
                 break;
@@ -1,8 +1,21 @@
-import type { Browser } from "../browser";
-import { Domain, type Logger } from "../logger";
-import type { BrowserEffect, SetTimeoutConfig } from "../rust/types";
+import type { AbortFn, Browser } from "../browser";
+import { Domain, type Logger, Verbosity } from "../logger";
+import type {
+    BrowserEffect,
+    ClearTimeoutConfig,
+    SetNamedTimeoutConfig,
+    SetTimeoutConfig,
+} from "../rust/types";
+
+interface State {
+    namedTimers: Map<string, AbortFn>;
+}
 
 export class BrowserEffectHandler {
+    private readonly state: State = {
+        namedTimers: new Map(),
+    };
+
     constructor(
         private readonly browser: Browser,
         private readonly logger: Logger,
@@ -15,6 +28,16 @@ export class BrowserEffectHandler {
                 break;
             }
 
+            case "setNamedTimeout": {
+                await this.setNamedTimeout(effect.config as SetNamedTimeoutConfig);
+                break;
+            }
+
+            case "clearTimeout": {
+                this.clearTimeout(effect.config as ClearTimeoutConfig);
+                break;
+            }
+
             default:
                 this.logger.warn({
                     domain: Domain.Browser,
@@ -31,4 +54,36 @@ export class BrowserEffectHandler {
             }, config.duration);
         });
     }
+
+    // The promise of a cleared or replaced timer never resolves,
+    // so its msg is never sent.
+    private setNamedTimeout({ key, duration }: SetNamedTimeoutConfig): Promise<void> {
+        this.clearTimeout({ key });
+
+        return new Promise((resolve) => {
+            const abort = this.browser.setTimeout(() => {
+                this.state.namedTimers.delete(key);
+                resolve();
+            }, duration);
+
+            this.state.namedTimers.set(key, abort);
+        });
+    }
+
+    private clearTimeout({ key }: ClearTimeoutConfig) {
+        const abort = this.state.namedTimers.get(key);
+        if (!abort) {
+            return;
+        }
+
+        abort.abort();
+        this.state.namedTimers.delete(key);
+
+        this.logger.debug({
+            domain: Domain.Browser,
+            verbosity: Verbosity.Normal,
+            message: "Cleared timer",
+            context: { key },
+        });
+    }
 }
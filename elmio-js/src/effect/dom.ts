@@ -5,14 +5,25 @@ import type {
     DispatchEvent,
     DomEffect,
     FileInfo,
+    FocusData,
     FocusElement,
     GetElementValue,
+    GetEventPayload,
     GetFiles,
+    GetFormData,
     GetRadioGroupValue,
     GetTargetDataValue,
+    Modifiers,
+    MouseData,
+    PointerData,
+    ScrollData,
     SelectInputText,
+    TouchData,
+    TouchPoint,
+    WheelData,
 } from "../rust/types";
 import type JsonHelper from "../utils/json";
+import { mouseButtonToString, mouseButtonsToStrings } from "../utils/mouse";
 
 export class DomEffectHandler {
     constructor(
@@ -52,10 +63,18 @@ export class DomEffectHandler {
                 return this.getFiles(effect.config as GetFiles);
             }
 
+            case "getFormData": {
+                return this.getFormData(effect.config as GetFormData);
+            }
+
             case "getTargetDataValue": {
                 return this.getTargetDataValue(effect.config as GetTargetDataValue, sourceEvent);
             }
 
+            case "getEventPayload": {
+                return this.getEventPayload(effect.config as GetEventPayload, sourceEvent);
+            }
+
             default:
                 this.logger.warn({
                     domain: Domain.Dom,
@@ -186,6 +205,35 @@ export class DomEffectHandler {
         return files;
     }
 
+    private getFormData({ elementId }: GetFormData): Record<string, unknown> | null {
+        const form = this.browser.getElementById(elementId);
+        if (!(form instanceof HTMLFormElement)) {
+            this.logger.error({
+                domain: Domain.Dom,
+                message: "Failed to get data from form",
+                context: {
+                    elementId,
+                },
+            });
+
+            return null;
+        }
+
+        const data = formData(form);
+
+        this.logger.debug({
+            domain: Domain.Dom,
+            verbosity: Verbosity.Normal,
+            message: "Got data from form",
+            context: {
+                elementId,
+                data,
+            },
+        });
+
+        return data;
+    }
+
     private getTargetDataValue(
         { name, parseAsJson }: GetTargetDataValue,
         sourceEvent: Event | null,
@@ -223,6 +271,295 @@ export class DomEffectHandler {
 
         return null;
     }
+
+    private getEventPayload({ kind }: GetEventPayload, sourceEvent: Event | null): unknown {
+        const payload = sourceEvent ? eventPayload(kind, sourceEvent) : null;
+
+        if (payload == null) {
+            this.logger.error({
+                domain: Domain.Dom,
+                message: "Failed to get payload from event",
+                context: {
+                    kind,
+                    sourceEvent,
+                },
+            });
+
+            return null;
+        }
+
+        this.logger.debug({
+            domain: Domain.Dom,
+            verbosity: Verbosity.Verbose,
+            message: "Got payload from event",
+            context: {
+                kind,
+                payload,
+            },
+        });
+
+        return payload;
+    }
+}
+
+const SKIPPED_INPUT_TYPES = ["submit", "button", "reset", "image"];
+
+// Mirrors the rules documented on `dom::get_form_data` in elmio-core.
+function formData(form: HTMLFormElement): Record<string, unknown> {
+    const data: Record<string, unknown> = {};
+
+    for (const elem of Array.from(form.elements)) {
+        if (
+            !(
+                elem instanceof HTMLInputElement ||
+                elem instanceof HTMLSelectElement ||
+                elem instanceof HTMLTextAreaElement
+            )
+        ) {
+            continue;
+        }
+
+        if (!elem.name || elem.disabled || SKIPPED_INPUT_TYPES.includes(elem.type)) {
+            continue;
+        }
+
+        const path = elem.name.split(".");
+
+        if (elem instanceof HTMLTextAreaElement) {
+            setField(data, path, elem.value);
+        } else if (elem instanceof HTMLSelectElement) {
+            const value = elem.multiple
+                ? Array.from(elem.selectedOptions).map((option) => option.value)
+                : elem.value;
+
+            setField(data, path, value);
+        } else if (elem.type === "checkbox") {
+            if (elem.hasAttribute("value")) {
+                const current = getField(data, path);
+                const values = Array.isArray(current) ? current : [];
+
+                setField(data, path, elem.checked ? [...values, elem.value] : values);
+            } else {
+                setField(data, path, elem.checked);
+            }
+        } else if (elem.type === "radio") {
+            if (elem.checked) {
+                setField(data, path, elem.value);
+            } else if (getField(data, path) === undefined) {
+                setField(data, path, null);
+            }
+        } else if (elem.type === "number" || elem.type === "range") {
+            setField(data, path, elem.value === "" ? null : elem.valueAsNumber);
+        } else if (elem.type === "file") {
+            const files = Array.from(elem.files ?? []).map((file) => {
+                return {
+                    name: file.name,
+                    mime: file.type,
+                    size: file.size,
+                    lastModified: file.lastModified,
+                };
+            });
+
+            setField(data, path, files);
+        } else {
+            setField(data, path, elem.value);
+        }
+    }
+
+    return data;
+}
+
+function getField(data: Record<string, unknown>, path: string[]): unknown {
+    let current: unknown = data;
+
+    for (const key of path) {
+        if (typeof current !== "object" || current === null || Array.isArray(current)) {
+            return undefined;
+        }
+
+        current = (current as Record<string, unknown>)[key];
+    }
+
+    return current;
+}
+
+function setField(data: Record<string, unknown>, path: string[], value: unknown): void {
+    let current = data;
+
+    for (const key of path.slice(0, -1)) {
+        const next = current[key];
+
+        if (typeof next !== "object" || next === null || Array.isArray(next)) {
+            current[key] = {};
+        }
+
+        current = current[key] as Record<string, unknown>;
+    }
+
+    current[path[path.length - 1]] = value;
+}
+
+function eventPayload(kind: string, event: Event): unknown {
+    switch (kind) {
+        case "mouse":
+            return event instanceof MouseEvent ? mouseData(event) : null;
+
+        case "pointer":
+            return event instanceof PointerEvent ? pointerData(event) : null;
+
+        case "wheel":
+            return event instanceof WheelEvent ? wheelData(event) : null;
+
+        // Desktop Firefox does not define `TouchEvent`.
+        case "touch":
+            return typeof TouchEvent !== "undefined" && event instanceof TouchEvent
+                ? touchData(event)
+                : null;
+
+        case "focus":
+            return event instanceof FocusEvent ? focusData(event) : null;
+
+        case "scroll":
+            return scrollData(event);
+    }
+
+    return null;
+}
+
+// Events where `MouseEvent.button` is meaningful, it is 0 for the others.
+const BUTTON_EVENTS = [
+    "mousedown",
+    "mouseup",
+    "click",
+    "dblclick",
+    "contextmenu",
+    "auxclick",
+    "pointerdown",
+    "pointerup",
+];
+
+function mouseData(event: MouseEvent): MouseData {
+    return {
+        clientX: event.clientX,
+        clientY: event.clientY,
+        pageX: event.pageX,
+        pageY: event.pageY,
+        offsetX: event.offsetX,
+        offsetY: event.offsetY,
+        button: BUTTON_EVENTS.includes(event.type) ? mouseButtonToString(event.button) : null,
+        buttons: mouseButtonsToStrings(event.buttons),
+        modifiers: modifiers(event),
+        data: targetData(event),
+    };
+}
+
+function pointerData(event: PointerEvent): PointerData {
+    return {
+        ...mouseData(event),
+        pointerId: event.pointerId,
+        pointerType: pointerType(event.pointerType),
+        isPrimary: event.isPrimary,
+        pressure: event.pressure,
+        width: event.width,
+        height: event.height,
+    };
+}
+
+function pointerType(pointerType: string): string {
+    switch (pointerType) {
+        case "mouse":
+        case "pen":
+        case "touch":
+            return pointerType;
+    }
+
+    return "unknown";
+}
+
+function wheelData(event: WheelEvent): WheelData {
+    const deltaModes = ["pixel", "line", "page"];
+
+    return {
+        ...mouseData(event),
+        deltaX: event.deltaX,
+        deltaY: event.deltaY,
+        deltaZ: event.deltaZ,
+        deltaMode: deltaModes[event.deltaMode] ?? "pixel",
+    };
+}
+
+function touchData(event: TouchEvent): TouchData {
+    return {
+        touches: Array.from(event.touches).map(touchPoint),
+        changedTouches: Array.from(event.changedTouches).map(touchPoint),
+        modifiers: modifiers(event),
+        data: targetData(event),
+    };
+}
+
+function touchPoint(touch: Touch): TouchPoint {
+    return {
+        identifier: touch.identifier,
+        clientX: touch.clientX,
+        clientY: touch.clientY,
+        pageX: touch.pageX,
+        pageY: touch.pageY,
+    };
+}
+
+function focusData(event: FocusEvent): FocusData {
+    const related = event.relatedTarget;
+
+    return {
+        relatedId: related instanceof Element && related.id ? related.id : null,
+        data: targetData(event),
+    };
+}
+
+// Window scroll events target the document, which scrolls its root element.
+function scrollData(event: Event): ScrollData | null {
+    const target = event.target instanceof Document ? event.target.scrollingElement : event.target;
+    if (!(target instanceof Element)) {
+        return null;
+    }
+
+    return {
+        scrollTop: target.scrollTop,
+        scrollLeft: target.scrollLeft,
+        scrollWidth: target.scrollWidth,
+        scrollHeight: target.scrollHeight,
+        clientWidth: target.clientWidth,
+        clientHeight: target.clientHeight,
+    };
+}
+
+function modifiers(event: MouseEvent | TouchEvent): Modifiers {
+    return {
+        alt: event.altKey,
+        ctrl: event.ctrlKey,
+        meta: event.metaKey,
+        shift: event.shiftKey,
+    };
+}
+
+// The `data-*` attributes of the target and its ancestors, the nearest
+// element winning.
+function targetData(event: Event): Record<string, string> {
+    const data: Record<string, string> = {};
+    let elem = event.target instanceof Element ? event.target : null;
+
+    while (elem) {
+        for (const attr of Array.from(elem.attributes)) {
+            const key = attr.name.startsWith("data-") ? attr.name.slice(5) : null;
+            if (key && !(key in data)) {
+                data[key] = attr.value;
+            }
+        }
+
+        elem = elem.parentElement;
+    }
+
+    return data;
 }
 
 function closestTargetFromEvent(event: Event | null, selector: string): HTMLElement | null {
@@ -0,0 +1,51 @@
+import { Domain, type Logger } from "../logger";
+import type { EventSourceEffect, GetEventData } from "../rust/types";
+import type JsonHelper from "../utils/json";
+
+export class EventSourceEffectHandler {
+    constructor(
+        private readonly jsonHelper: JsonHelper,
+        private readonly logger: Logger,
+    ) {}
+
+    public async handle(effect: EventSourceEffect, sourceEvent: Event | null) {
+        switch (effect.type) {
+            case "getEventData":
+                return this.getEventData(effect.config as GetEventData, sourceEvent);
+
+            default:
+                this.logger.warn({
+                    domain: Domain.EventSource,
+                    message: `Unknown event source effect type: ${effect.type}`,
+                    context: { type: effect.type },
+                });
+        }
+    }
+
+    private getEventData(
+        { parseAsJson, withMetadata }: GetEventData,
+        sourceEvent: Event | null,
+    ): any {
+        if (!(sourceEvent instanceof MessageEvent)) {
+            this.logger.warn({
+                domain: Domain.EventSource,
+                message: "Expected a server event",
+                context: { sourceEvent },
+            });
+
+            return null;
+        }
+
+        const data = parseAsJson ? this.jsonHelper.parse(sourceEvent.data) : sourceEvent.data;
+
+        if (!withMetadata) {
+            return data;
+        }
+
+        return {
+            id: sourceEvent.lastEventId || null,
+            eventType: sourceEvent.type,
+            data,
+        };
+    }
+}
@@ -0,0 +1,118 @@
+import type { Browser } from "../browser";
+import type { Http } from "../browser/http";
+import type { Location } from "../browser/location";
+import { Domain, type Logger, Verbosity } from "../logger";
+import type { HttpBody, HttpError, HttpRequest, HttpResult } from "../rust/types";
+
+export class HttpEffectHandler {
+    constructor(
+        private readonly http: Http,
+        private readonly browser: Browser,
+        private readonly location: Location,
+        private readonly logger: Logger,
+    ) {}
+
+    public async handle(request: HttpRequest): Promise<HttpResult> {
+        const url = new URL(request.url, this.location.href());
+        for (const [name, value] of request.query) {
+            url.searchParams.append(name, value);
+        }
+
+        const headers = new Headers(request.headers);
+        const body = prepareBody(request.body, headers);
+
+        const controller = new AbortController();
+        let timedOut = false;
+        const timeout =
+            request.timeout === null
+                ? null
+                : this.browser.setTimeout(() => {
+                      timedOut = true;
+                      controller.abort();
+                  }, request.timeout);
+
+        this.logger.debug({
+            domain: Domain.Http,
+            verbosity: Verbosity.Normal,
+            message: "Sending request",
+            context: { method: request.method, url: url.href },
+        });
+
+        let response: Response;
+        let text: string;
+
+        try {
+            response = await this.http.fetch(url.href, {
+                method: request.method,
+                headers,
+                body,
+                signal: controller.signal,
+            });
+            text = await response.text();
+        } catch (err) {
+            return failure(timedOut ? { type: "timeout" } : { type: "network", config: `${err}` });
+        } finally {
+            timeout?.abort();
+        }
+
+        this.logger.debug({
+            domain: Domain.Http,
+            verbosity: Verbosity.Verbose,
+            message: "Received response",
+            context: { url: url.href, status: response.status, body: text },
+        });
+
+        if (!response.ok) {
+            return failure({
+                type: "badStatus",
+                config: { status: response.status, body: text },
+            });
+        }
+
+        if (request.responseType === "text") {
+            return { Ok: text };
+        }
+
+        // Empty responses, e.g. 204 No Content, decode as null.
+        if (text.trim() === "") {
+            return { Ok: null };
+        }
+
+        try {
+            return { Ok: JSON.parse(text) };
+        } catch (err) {
+            return failure({ type: "decode", config: `${err}` });
+        }
+    }
+}
+
+function prepareBody(body: HttpBody | null, headers: Headers): BodyInit | null {
+    if (body === null) {
+        return null;
+    }
+
+    switch (body.type) {
+        case "json":
+            setDefaultHeader(headers, "Content-Type", "application/json");
+            return JSON.stringify(body.config);
+
+        case "text":
+            setDefaultHeader(headers, "Content-Type", "text/plain");
+            return body.config as string;
+
+        case "form":
+            return new URLSearchParams(body.config as [string, string][]);
+    }
+
+    throw new Error(`Unknown http body type: ${body.type}`);
+}
+
+function setDefaultHeader(headers: Headers, name: string, value: string) {
+    if (!headers.has(name)) {
+        headers.set(name, value);
+    }
+}
+
+function failure(error: HttpError): HttpResult {
+    return { Err: error };
+}
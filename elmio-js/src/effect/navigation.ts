@@ -1,7 +1,7 @@
 import type { History } from "../browser/history";
 import type { Location } from "../browser/location";
 import { Domain, type Logger } from "../logger";
-import type { NavigationEffect } from "../rust/types";
+import type { Location as RustLocation, NavigationEffect, UrlRequest } from "../rust/types";
 
 export class NavigationEffectHandler {
     constructor(
@@ -10,7 +10,7 @@ export class NavigationEffectHandler {
         private readonly logger: Logger,
     ) {}
 
-    public async handle(effect: NavigationEffect) {
+    public async handle(effect: NavigationEffect, sourceEvent: Event | null) {
         switch (effect.type) {
             case "pushUrl":
                 return this.pushUrl(effect.config);
@@ -21,6 +21,12 @@ export class NavigationEffectHandler {
             case "setLocation":
                 return this.setLocation(effect.config);
 
+            case "getLocation":
+                return this.getLocation();
+
+            case "getUrlRequest":
+                return this.getUrlRequest(sourceEvent);
+
             default:
                 this.logger.warn({
                     domain: Domain.Navigation,
@@ -41,4 +47,33 @@ export class NavigationEffectHandler {
     private setLocation(url: string): void {
         this.location.assign(url);
     }
+
+    private getUrlRequest(sourceEvent: Event | null): UrlRequest | null {
+        const elem = sourceEvent?.target as Element | null;
+        const link = elem && "closest" in elem ? elem.closest("a[href]") : null;
+        if (!link) {
+            this.logger.warn({
+                domain: Domain.Navigation,
+                message: "No link found for url request",
+                context: { sourceEvent },
+            });
+
+            return null;
+        }
+
+        const url = new URL(link.getAttribute("href") ?? "", this.location.href());
+        const current = new URL(this.location.href());
+
+        return {
+            type: url.origin === current.origin ? "internal" : "external",
+            config: url.href,
+        };
+    }
+
+    private getLocation(): RustLocation {
+        return {
+            url: this.location.href(),
+            state: this.history.state() ?? null,
+        };
+    }
 }
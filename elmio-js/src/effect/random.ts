@@ -0,0 +1,58 @@
+import type { Crypto } from "../browser/crypto";
+import { Domain, type Logger, Verbosity } from "../logger";
+import type { GetRandomBytes, RandomEffect } from "../rust/types";
+
+// getRandomValues throws when asked for more than 65536 bytes.
+const MAX_BYTES = 65536;
+
+export class RandomEffectHandler {
+    constructor(
+        private readonly crypto: Crypto,
+        private readonly logger: Logger,
+    ) {}
+
+    public async handle(effect: RandomEffect) {
+        switch (effect.type) {
+            case "getSeed":
+                return this.getSeed();
+
+            case "getBytes":
+                return this.getBytes(effect.config as GetRandomBytes);
+
+            default:
+                this.logger.warn({
+                    domain: Domain.Random,
+                    message: `Unknown random effect type: ${effect.type}`,
+                    context: { type: effect.type },
+                });
+        }
+    }
+
+    // A seed is the four 32-bit words of the generator state.
+    private getSeed(): number[] {
+        return Array.from(this.crypto.getRandomValues(4));
+    }
+
+    private getBytes({ length }: GetRandomBytes): number[] | null {
+        if (length > MAX_BYTES) {
+            this.logger.error({
+                domain: Domain.Random,
+                message: "Too many random bytes requested",
+                context: { length, max: MAX_BYTES },
+            });
+
+            return null;
+        }
+
+        const bytes = Array.from(this.crypto.getRandomBytes(length));
+
+        this.logger.debug({
+            domain: Domain.Random,
+            verbosity: Verbosity.Verbose,
+            message: "Got random bytes",
+            context: { length },
+        });
+
+        return bytes;
+    }
+}
@@ -1,6 +1,6 @@
 import type { IDate } from "../browser/date";
 import { Domain, type Logger } from "../logger";
-import type { TimeEffect } from "../rust/types";
+import type { AnimationFrame, TimeEffect, Zone } from "../rust/types";
 import { type Posix, posixFromMilliseconds } from "../utils/time";
 
 export class TimeEffectHandler {
@@ -9,12 +9,20 @@ export class TimeEffectHandler {
         private readonly logger: Logger,
     ) {}
 
-    public async handle(effect: TimeEffect) {
+    public async handle(effect: TimeEffect, sourceEvent: Event | null) {
         switch (effect.type) {
             case "currentTime": {
                 return this.currentTime();
             }
 
+            case "here": {
+                return this.here();
+            }
+
+            case "getAnimationFrame": {
+                return this.getAnimationFrame(sourceEvent);
+            }
+
             default:
                 this.logger.warn({
                     domain: Domain.Time,
@@ -28,4 +36,26 @@ export class TimeEffectHandler {
         const now = this.date.now();
         return posixFromMilliseconds(now);
     }
+
+    // getTimezoneOffset is in minutes west of UTC, zones use minutes east of UTC.
+    private here(): Zone {
+        return {
+            name: this.date.timeZoneName(),
+            offset: -this.date.timeZoneOffset(),
+        };
+    }
+
+    private getAnimationFrame(sourceEvent: Event | null): AnimationFrame | null {
+        if (!(sourceEvent instanceof CustomEvent) || sourceEvent.type !== "animationframe") {
+            this.logger.warn({
+                domain: Domain.Time,
+                message: "Expected an animation frame event",
+                context: { sourceEvent },
+            });
+
+            return null;
+        }
+
+        return sourceEvent.detail as AnimationFrame;
+    }
 }
@@ -0,0 +1,47 @@
+import { Domain, type Logger } from "../logger";
+import type { GetMessageData, SendWebSocketData, WebSocketEffect } from "../rust/types";
+import type { WebSocketManager } from "../subscription/websocket";
+import type JsonHelper from "../utils/json";
+
+export class WebSocketEffectHandler {
+    constructor(
+        private readonly webSocketManager: WebSocketManager,
+        private readonly jsonHelper: JsonHelper,
+        private readonly logger: Logger,
+    ) {}
+
+    public async handle(effect: WebSocketEffect, sourceEvent: Event | null) {
+        switch (effect.type) {
+            case "send":
+                return this.send(effect.config as SendWebSocketData);
+
+            case "getMessageData":
+                return this.getMessageData(effect.config as GetMessageData, sourceEvent);
+
+            default:
+                this.logger.warn({
+                    domain: Domain.WebSocket,
+                    message: `Unknown websocket effect type: ${effect.type}`,
+                    context: { type: effect.type },
+                });
+        }
+    }
+
+    private send({ url, data }: SendWebSocketData): void {
+        this.webSocketManager.send(url, data);
+    }
+
+    private getMessageData({ parseAsJson }: GetMessageData, sourceEvent: Event | null): any {
+        if (!(sourceEvent instanceof MessageEvent) || typeof sourceEvent.data !== "string") {
+            this.logger.warn({
+                domain: Domain.WebSocket,
+                message: "Expected a text message event",
+                context: { sourceEvent },
+            });
+
+            return null;
+        }
+
+        return parseAsJson ? this.jsonHelper.parse(sourceEvent.data) : sourceEvent.data;
+    }
+}
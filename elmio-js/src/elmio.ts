@@ -2,11 +2,14 @@ import morphdom from "morphdom";
 import { type Browser, RealBrowser } from "./browser";
 import { BrowserClipboard, type Clipboard } from "./browser/clipboard";
 import { BrowserConsole, type Console } from "./browser/console";
+import { BrowserCrypto, type Crypto } from "./browser/crypto";
 import { BrowserDate, type IDate } from "./browser/date";
 import { BrowserHistory, type History } from "./browser/history";
+import { BrowserHttp, type Http } from "./browser/http";
 import { BrowserLocalStorage, type LocalStorage } from "./browser/local_storage";
 import { BrowserLocation, type Location } from "./browser/location";
 import { BrowserSessionStorage, type SessionStorage } from "./browser/session_storage";
+import { BrowserSockets } from "./browser/websocket";
 import { BrowserWindow, type Window } from "./browser/window";
 import { EffectHandler } from "./effect";
 import { type Config as CustomEffectConfig, defaultCustomEffectConfig } from "./effect/custom";
@@ -18,8 +21,20 @@ import {
     Verbosity,
     defaultLoggerConfig,
 } from "./logger";
-import type { Effect, JsMsg, Model, Msg, Page } from "./rust/types";
+import { type RuntimePage, runtimePage } from "./page";
+import { applyPatches } from "./patch";
+import type {
+    Effect,
+    JsMsg,
+    Msg,
+    Page,
+    Patch,
+    PortData,
+    PortMsg,
+    StatefulPage,
+} from "./rust/types";
 import { SubscriptionManager } from "./subscription";
+import { WebSocketManager } from "./subscription/websocket";
 import { isObject } from "./utils/helper";
 import JsonHelper from "./utils/json";
 import replacePlaceholder from "./utils/msg";
@@ -27,13 +42,14 @@ import replacePlaceholder from "./utils/msg";
 export interface Config {
     loggerConfig?: LoggerConfig;
     customEffectConfig?: CustomEffectConfig;
+    // Apply DOM patches computed in Rust instead of diffing the full markup.
+    // Only stateful pages support patches, other pages ignore this option.
+    domPatching?: boolean;
 }
 
-interface State {
-    model: Model;
-}
-
-export class Elmio {
+// The port types are the declarations generated by the IncomingPort and
+// OutgoingPort derives, e.g. `new Elmio<FromJs, ToJs>(page)`.
+export class Elmio<Incoming extends PortMsg = PortMsg, Outgoing = any> {
     private readonly appElem: HTMLElement;
     private readonly browser: Browser;
     private readonly console: Console;
@@ -46,22 +62,23 @@ export class Elmio {
     private readonly jsonHelper: JsonHelper;
     private readonly history: History;
     private readonly location: Location;
+    private readonly http: Http;
+    private readonly crypto: Crypto;
+    private readonly webSocketManager: WebSocketManager;
     private readonly subscriptionManager: SubscriptionManager;
     private readonly effectHandler: EffectHandler;
-
-    private readonly state: State = {
-        model: null,
-    };
+    private readonly page: RuntimePage;
 
     constructor(
-        private readonly page: Page,
+        page: Page | StatefulPage,
         private readonly config?: Config,
     ) {
+        this.page = runtimePage(page);
         this.browser = new RealBrowser();
         this.console = new BrowserConsole();
         this.clipboard = new BrowserClipboard();
 
-        const appId = page.id();
+        const appId = this.page.id();
         const appElem = this.browser.getElementById(appId);
         if (!appElem) {
             throw new Error(`Could not find element with id '${appId}'`);
@@ -76,8 +93,17 @@ export class Elmio {
         this.jsonHelper = new JsonHelper(this.logger);
         this.history = new BrowserHistory();
         this.location = new BrowserLocation();
+        this.http = new BrowserHttp();
+        this.crypto = new BrowserCrypto();
+        this.webSocketManager = new WebSocketManager(
+            new BrowserSockets(),
+            this.browser,
+            this.logger,
+        );
         this.subscriptionManager = new SubscriptionManager(
             this.browser,
+            this.location,
+            this.webSocketManager,
             this.logger,
             (msg: Msg) => {
                 this.update(msg);
@@ -92,6 +118,9 @@ export class Elmio {
             this.date,
             this.history,
             this.location,
+            this.http,
+            this.webSocketManager,
+            this.crypto,
             this.localStorage,
             this.sessionStorage,
             this.jsonHelper,
@@ -103,15 +132,15 @@ export class Elmio {
     }
 
     public init() {
-        const { model, effects } = this.page.init();
-        this.handleModelAndEffects(model, effects);
+        const effects = this.page.init();
+        this.handleEffects(effects);
     }
 
-    public sendMessage(type: string, data: any) {
+    public sendMessage<T extends Incoming["type"]>(type: T, data: PortData<Incoming, T>) {
         this.updateFromJs({ type, data });
     }
 
-    public onCustomEffect(handler: (effect: any) => void) {
+    public onCustomEffect(handler: (effect: Outgoing) => void) {
         this.effectHandler.setCustomEffectHandler(handler);
     }
 
@@ -138,6 +167,44 @@ export class Elmio {
         });
     }
 
+    private patchDom(patches: Patch[]): boolean {
+        const applied = applyPatches(this.appElem, patches, (markup) => this.updateDom(markup));
+
+        this.logger.debug({
+            domain: Domain.Core,
+            verbosity: Verbosity.Verbose,
+            message: "Applied DOM patches",
+            context: {
+                applied,
+                patches,
+            },
+        });
+
+        return applied;
+    }
+
+    private render() {
+        const patches = this.config?.domPatching ? this.page.viewPatches() : null;
+        if (patches && this.patchDom(patches)) {
+            return;
+        }
+
+        if (patches) {
+            this.logger.warn({
+                domain: Domain.Core,
+                message: "Failed to apply DOM patches, rendering full markup",
+                context: { patches },
+            });
+
+            // The page diffs against its last view, which the DOM may only
+            // partly reflect after a failed patch.
+            this.page.resetView();
+        }
+
+        const markup = this.page.viewBody();
+        this.updateDom(markup);
+    }
+
     private async prepareMsg(msg: Msg): Promise<any> {
         if (!("effect" in msg)) {
             return msg.msg;
@@ -164,8 +231,8 @@ export class Elmio {
             },
         });
 
-        const { model, effects } = this.page.update(realMsg, this.state.model);
-        this.handleModelAndEffects(model, effects);
+        const effects = this.page.update(realMsg);
+        this.handleEffects(effects);
     }
 
     private updateFromJs(msg: JsMsg) {
@@ -178,23 +245,21 @@ export class Elmio {
             },
         });
 
-        const { model, effects } = this.page.updateFromJs(msg, this.state.model);
-        this.handleModelAndEffects(model, effects);
+        const effects = this.page.updateFromJs(msg);
+        this.handleEffects(effects);
     }
 
-    private handleModelAndEffects(model: Model, effects: Effect[]) {
+    private handleEffects(effects: Effect[]) {
         this.logger.debug({
             domain: Domain.Core,
             verbosity: Verbosity.Normal,
             message: "Updating model",
-            context: { model },
+            context: { model: this.page.model() },
         });
 
-        this.state.model = model;
-        const markup = this.page.viewBody(this.state.model);
-        this.updateDom(markup);
+        this.render();
 
-        const newSubscriptions = this.page.getSubscriptions(this.state.model);
+        const newSubscriptions = this.page.getSubscriptions();
         this.subscriptionManager.handle(newSubscriptions);
         this.effectHandler.handle(effects);
     }
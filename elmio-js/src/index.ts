@@ -3,12 +3,15 @@ import { BrowserWindow, Window } from "./browser/window";
 import { Config, Elmio } from "./elmio";
 import { defaultDebugConfig } from "./logger";
 import { rustEnum } from "./rust/enum";
-import { Page } from "./rust/types";
+import { Page, PortData, PortMsg, StatefulPage } from "./rust/types";
 
 export {
     Elmio,
     Config,
     Page,
+    StatefulPage,
+    PortMsg,
+    PortData,
     Browser,
     RealBrowser,
     Window,
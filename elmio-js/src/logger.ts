@@ -95,6 +95,11 @@ enum Domain {
     Clipboard = 12,
     Browser = 13,
     CustomEffect = 14,
+    Http = 15,
+    WebSocket = 16,
+    EventSource = 17,
+    AnimationFrame = 18,
+    Random = 19,
 }
 
 enum DebugLogger {
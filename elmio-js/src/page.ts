@@ -0,0 +1,123 @@
+import type {
+    Effect,
+    JsMsg,
+    Model,
+    Page,
+    Patch,
+    StatefulPage,
+    Subscription,
+} from "./rust/types";
+
+// The page interface used by the runtime, regardless of where the model lives
+export interface RuntimePage {
+    id(): string;
+    init(): Effect[];
+    update(msg: any): Effect[];
+    updateFromJs(msg: JsMsg): Effect[];
+    getSubscriptions(): Subscription[];
+    viewBody(): string;
+    // Returns null for stateless pages, which do not keep the previous view
+    viewPatches(): Patch[] | null;
+    // Makes the next `viewPatches` replace the whole app element
+    resetView(): void;
+    // Returns null for stateful pages, which keep the model in wasm memory
+    model(): Model | null;
+}
+
+export function runtimePage(page: Page | StatefulPage): RuntimePage {
+    if (isStatefulPage(page)) {
+        return new StatefulRuntimePage(page);
+    }
+
+    return new StatelessRuntimePage(page);
+}
+
+function isStatefulPage(page: Page | StatefulPage): page is StatefulPage {
+    return "isStateful" in page && page.isStateful();
+}
+
+class StatelessRuntimePage implements RuntimePage {
+    private state: Model = null;
+
+    constructor(private readonly page: Page) {}
+
+    public id(): string {
+        return this.page.id();
+    }
+
+    public init(): Effect[] {
+        const { model, effects } = this.page.init();
+        this.state = model;
+        return effects;
+    }
+
+    public update(msg: any): Effect[] {
+        const { model, effects } = this.page.update(msg, this.state);
+        this.state = model;
+        return effects;
+    }
+
+    public updateFromJs(msg: JsMsg): Effect[] {
+        const { model, effects } = this.page.updateFromJs(msg, this.state);
+        this.state = model;
+        return effects;
+    }
+
+    public getSubscriptions(): Subscription[] {
+        return this.page.getSubscriptions(this.state);
+    }
+
+    public viewBody(): string {
+        return this.page.viewBody(this.state);
+    }
+
+    public viewPatches(): Patch[] | null {
+        return null;
+    }
+
+    public resetView(): void {}
+
+    public model(): Model | null {
+        return this.state;
+    }
+}
+
+class StatefulRuntimePage implements RuntimePage {
+    constructor(private readonly page: StatefulPage) {}
+
+    public id(): string {
+        return this.page.id();
+    }
+
+    public init(): Effect[] {
+        return this.page.init().effects;
+    }
+
+    public update(msg: any): Effect[] {
+        return this.page.update(msg).effects;
+    }
+
+    public updateFromJs(msg: JsMsg): Effect[] {
+        return this.page.updateFromJs(msg).effects;
+    }
+
+    public getSubscriptions(): Subscription[] {
+        return this.page.getSubscriptions();
+    }
+
+    public viewBody(): string {
+        return this.page.viewBody();
+    }
+
+    public viewPatches(): Patch[] | null {
+        return this.page.viewPatches();
+    }
+
+    public resetView(): void {
+        this.page.resetView();
+    }
+
+    public model(): Model | null {
+        return null;
+    }
+}
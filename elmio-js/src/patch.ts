@@ -0,0 +1,184 @@
+import type {
+    InsertPatch,
+    Patch,
+    RemoveAttributePatch,
+    RemovePatch,
+    ReplacePatch,
+    SetAttributePatch,
+    SetTextPatch,
+} from "./rust/types";
+
+// Applies patches computed by elmio-core to the app element.
+// Returns false as soon as a patch does not fit the current DOM, e.g. when
+// the node at its path is not the one elmio-core expects, in which case the
+// caller is expected to fall back to rendering the full markup.
+export function applyPatches(
+    appElem: HTMLElement,
+    patches: Patch[],
+    morph: (markup: string) => void,
+): boolean {
+    for (const patch of patches) {
+        if (!applyPatch(appElem, patch, morph)) {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+function applyPatch(appElem: HTMLElement, patch: Patch, morph: (markup: string) => void): boolean {
+    switch (patch.type) {
+        case "replace":
+            return replace(appElem, patch.config as ReplacePatch, morph);
+
+        case "insert":
+            return insert(appElem, patch.config as InsertPatch);
+
+        case "remove":
+            return remove(appElem, patch.config as RemovePatch);
+
+        case "setAttribute":
+            return setAttribute(appElem, patch.config as SetAttributePatch);
+
+        case "removeAttribute":
+            return removeAttribute(appElem, patch.config as RemoveAttributePatch);
+
+        case "setText":
+            return setText(appElem, patch.config as SetTextPatch);
+    }
+
+    return false;
+}
+
+function replace(
+    appElem: HTMLElement,
+    { path, node: nodeName, html }: ReplacePatch,
+    morph: (markup: string) => void,
+): boolean {
+    if (path.length === 0) {
+        morph(html);
+        return true;
+    }
+
+    const node = resolvePath(appElem, path, nodeName);
+    const newNode = parseNode(html);
+    if (!node || !newNode || !(node instanceof Element || node instanceof Text)) {
+        return false;
+    }
+
+    node.replaceWith(newNode);
+    return true;
+}
+
+function insert(appElem: HTMLElement, { path, node, index, html }: InsertPatch): boolean {
+    const parent = resolvePath(appElem, path, node);
+    const newNode = parseNode(html);
+    if (!parent || !newNode || index > parent.childNodes.length) {
+        return false;
+    }
+
+    parent.insertBefore(newNode, parent.childNodes[index] ?? null);
+    return true;
+}
+
+function remove(appElem: HTMLElement, { path, node: nodeName }: RemovePatch): boolean {
+    const node = resolvePath(appElem, path, nodeName);
+    if (!node || node === appElem || !node.parentNode) {
+        return false;
+    }
+
+    node.parentNode.removeChild(node);
+    return true;
+}
+
+function setAttribute(
+    appElem: HTMLElement,
+    { path, node, name, value }: SetAttributePatch,
+): boolean {
+    const elem = resolvePath(appElem, path, node);
+    if (!(elem instanceof Element)) {
+        return false;
+    }
+
+    elem.setAttribute(name, value);
+    syncProperty(elem, name, value);
+    return true;
+}
+
+function removeAttribute(
+    appElem: HTMLElement,
+    { path, node, name }: RemoveAttributePatch,
+): boolean {
+    const elem = resolvePath(appElem, path, node);
+    if (!(elem instanceof Element)) {
+        return false;
+    }
+
+    elem.removeAttribute(name);
+    syncProperty(elem, name, null);
+    return true;
+}
+
+function setText(appElem: HTMLElement, { path, node: nodeName, text }: SetTextPatch): boolean {
+    const node = resolvePath(appElem, path, nodeName);
+    if (!(node instanceof Text)) {
+        return false;
+    }
+
+    node.data = text;
+
+    if (node.parentNode instanceof HTMLTextAreaElement) {
+        node.parentNode.value = text;
+    }
+
+    return true;
+}
+
+// Returns null when the path leads nowhere or to another node than the
+// expected one, since the browser may have built a different DOM than the
+// markup parser of elmio-core, e.g. with an implied `<tbody>`.
+function resolvePath(appElem: HTMLElement, path: number[], nodeName: string): Node | null {
+    let node: Node = appElem;
+
+    for (const index of path) {
+        const child = node.childNodes[index];
+        if (!child) {
+            return null;
+        }
+
+        node = child;
+    }
+
+    if (node.nodeName.toLowerCase() !== nodeName) {
+        return null;
+    }
+
+    return node;
+}
+
+function parseNode(html: string): Node | null {
+    const template = document.createElement("template");
+    template.innerHTML = html;
+    return template.content.firstChild;
+}
+
+// Attributes only set the initial state of form controls, so the live
+// properties are synced as well, like morphdom does.
+function syncProperty(elem: Element, name: string, value: string | null): void {
+    if (
+        name === "value" &&
+        (elem instanceof HTMLInputElement ||
+            elem instanceof HTMLTextAreaElement ||
+            elem instanceof HTMLSelectElement)
+    ) {
+        elem.value = value ?? "";
+    }
+
+    if (name === "checked" && elem instanceof HTMLInputElement) {
+        elem.checked = value != null;
+    }
+
+    if (name === "selected" && elem instanceof HTMLOptionElement) {
+        elem.selected = value != null;
+    }
+}
@@ -1,3 +1,5 @@
+import type { Posix } from "../utils/time";
+
 type Model = any;
 
 type Msg = PureMsg | EffectfulMsg;
@@ -20,14 +22,102 @@ interface Page {
     viewBody(model: Model): string;
 }
 
+interface StatefulPage {
+    id(): string;
+    isStateful(): boolean;
+    init(): Effects;
+    update(msg: Msg): Effects;
+    updateFromJs(msg: JsMsg): Effects;
+    getSubscriptions(): Subscription[];
+    viewBody(): string;
+    viewPatches(): Patch[];
+    resetView(): void;
+}
+
+interface ModelAndEffects {
+    model: Model;
+    effects: Effect[];
+}
+
+interface Effects {
+    effects: Effect[];
+}
+
+interface Patch {
+    type: string;
+    config:
+        | ReplacePatch
+        | InsertPatch
+        | RemovePatch
+        | SetAttributePatch
+        | RemoveAttributePatch
+        | SetTextPatch;
+}
+
+interface ReplacePatch {
+    path: number[];
+    node: string;
+    html: string;
+}
+
+interface InsertPatch {
+    path: number[];
+    node: string;
+    index: number;
+    html: string;
+}
+
+interface RemovePatch {
+    path: number[];
+    node: string;
+}
+
+interface SetAttributePatch {
+    path: number[];
+    node: string;
+    name: string;
+    value: string;
+}
+
+interface RemoveAttributePatch {
+    path: number[];
+    node: string;
+    name: string;
+}
+
+interface SetTextPatch {
+    path: number[];
+    node: string;
+    text: string;
+}
+
 interface JsMsg {
     type: string;
     data: any;
 }
 
+interface PortMsg {
+    type: string;
+    data: any;
+}
+
+// The data of the port message with the given type, or any for untyped ports.
+type PortData<Incoming extends PortMsg, T extends Incoming["type"]> =
+    string extends Incoming["type"] ? any : Extract<Incoming, { type: T }>["data"];
+
 interface Effect {
     type: string;
-    config: DomEffect | TimeEffect | ConsoleEffect | NavigationEffect | LocalStorageEffect;
+    config:
+        | DomEffect
+        | TimeEffect
+        | ConsoleEffect
+        | BrowserEffect
+        | NavigationEffect
+        | LocalStorageEffect
+        | HttpRequest
+        | WebSocketEffect
+        | EventSourceEffect
+        | RandomEffect;
 }
 
 interface NavigationEffect {
@@ -43,7 +133,9 @@ interface DomEffect {
         | SelectInputText
         | GetElementValue
         | GetRadioGroupValue
-        | GetTargetDataValue;
+        | GetTargetDataValue
+        | GetEventPayload
+        | GetFormData;
 }
 
 interface ConsoleEffect {
@@ -71,18 +163,92 @@ interface WriteTextResult {
 
 interface BrowserEffect {
     type: string;
-    config: SetTimeoutConfig;
+    config: SetTimeoutConfig | SetNamedTimeoutConfig | ClearTimeoutConfig;
 }
 
 interface SetTimeoutConfig {
     duration: number;
 }
 
+interface SetNamedTimeoutConfig {
+    key: string;
+    duration: number;
+}
+
+interface ClearTimeoutConfig {
+    key: string;
+}
+
+interface HttpRequest {
+    method: string;
+    url: string;
+    headers: [string, string][];
+    query: [string, string][];
+    body: HttpBody | null;
+    timeout: number | null;
+    responseType: string;
+}
+
+interface HttpBody {
+    type: string;
+    config: any;
+}
+
+interface HttpError {
+    type: string;
+    config?: string | HttpBadStatus;
+}
+
+interface HttpBadStatus {
+    status: number;
+    body: string;
+}
+
+type HttpResult = { Ok: any } | { Err: HttpError };
+
+interface WebSocketEffect {
+    type: string;
+    config: SendWebSocketData | GetMessageData;
+}
+
+interface SendWebSocketData {
+    url: string;
+    data: string;
+}
+
+interface GetMessageData {
+    parseAsJson: boolean;
+}
+
+interface EventSourceEffect {
+    type: string;
+    config: GetEventData;
+}
+
+interface GetEventData {
+    parseAsJson: boolean;
+    withMetadata: boolean;
+}
+
+interface RandomEffect {
+    type: string;
+    config: GetRandomBytes | null;
+}
+
+interface GetRandomBytes {
+    length: number;
+}
+
 interface TimeEffect {
     type: string;
     config: any;
 }
 
+interface Zone {
+    name: string | null;
+    offset: number;
+}
+
 interface DispatchEvent {
     eventTarget: EventTarget;
     eventType: string;
@@ -131,6 +297,79 @@ interface GetTargetDataValue {
     parseAsJson: boolean;
 }
 
+interface GetEventPayload {
+    kind: string;
+}
+
+interface GetFormData {
+    elementId: string;
+}
+
+interface Modifiers {
+    alt: boolean;
+    ctrl: boolean;
+    meta: boolean;
+    shift: boolean;
+}
+
+interface MouseData {
+    clientX: number;
+    clientY: number;
+    pageX: number;
+    pageY: number;
+    offsetX: number;
+    offsetY: number;
+    button: string | null;
+    buttons: string[];
+    modifiers: Modifiers;
+    data: Record<string, string>;
+}
+
+interface PointerData extends MouseData {
+    pointerId: number;
+    pointerType: string;
+    isPrimary: boolean;
+    pressure: number;
+    width: number;
+    height: number;
+}
+
+interface WheelData extends MouseData {
+    deltaX: number;
+    deltaY: number;
+    deltaZ: number;
+    deltaMode: string;
+}
+
+interface TouchPoint {
+    identifier: number;
+    clientX: number;
+    clientY: number;
+    pageX: number;
+    pageY: number;
+}
+
+interface TouchData {
+    touches: TouchPoint[];
+    changedTouches: TouchPoint[];
+    modifiers: Modifiers;
+    data: Record<string, string>;
+}
+
+interface FocusData {
+    relatedId: string | null;
+    data: Record<string, string>;
+}
+
+interface ScrollData {
+    scrollTop: number;
+    scrollLeft: number;
+    scrollWidth: number;
+    scrollHeight: number;
+    clientWidth: number;
+    clientHeight: number;
+}
+
 interface LocalStorageEffect {
     type: string;
     config: LocalStorageGetItem | LocalStorageSetItem;
@@ -177,7 +416,13 @@ interface EffectfulMsg {
 
 interface Subscription {
     type: string;
-    config: RustInterval | RustEventListener;
+    config:
+        | RustInterval
+        | RustEventListener
+        | RustUrlChange
+        | RustWebSocket
+        | RustEventSource
+        | RustAnimationFrame;
 }
 
 interface RustInterval {
@@ -186,6 +431,66 @@ interface RustInterval {
     msg: SubscriptionMsg;
 }
 
+interface RustUrlChange {
+    id: string;
+    msg: SubscriptionMsg;
+}
+
+interface Location {
+    url: string;
+    state: any;
+}
+
+interface RustWebSocket {
+    id: string;
+    url: string;
+    backoff: Backoff;
+    msg: SubscriptionMsg;
+    stateMsgs: StateMsgs | null;
+}
+
+interface Backoff {
+    initialDelay: number;
+    maxDelay: number;
+    multiplier: number;
+    maxAttempts: number | null;
+}
+
+interface StateMsgs {
+    connecting: any;
+    open: any;
+    closed: any;
+    error: any;
+}
+
+interface RustEventSource {
+    id: string;
+    url: string;
+    options: EventSourceOptions;
+    msg: SubscriptionMsg;
+}
+
+interface EventSourceOptions {
+    eventType: string;
+    lastEventId: string | null;
+    withCredentials: boolean;
+}
+
+interface RustAnimationFrame {
+    id: string;
+    msg: SubscriptionMsg;
+}
+
+interface AnimationFrame {
+    timestamp: Posix;
+    delta: number;
+}
+
+interface UrlRequest {
+    type: string;
+    config: string;
+}
+
 interface RustEventListener {
     id: string;
     listenTarget: string;
@@ -193,11 +498,18 @@ interface RustEventListener {
     matchers: EventMatcher[];
     msg: SubscriptionMsg;
     propagation: EventPropagation;
+    rateLimit: RateLimit | null;
 }
 
 interface EventMatcher {
     type: string;
-    config: ExactSelectorMatcher | ClosestSelectorMatcher | MouseButtonMatcher | KeyboardKeyMatcher;
+    config:
+        | ExactSelectorMatcher
+        | ClosestSelectorMatcher
+        | MouseButtonMatcher
+        | KeyboardKeyMatcher
+        | Chord
+        | KeySequenceMatcher;
 }
 
 interface ExactSelectorMatcher {
@@ -212,6 +524,23 @@ interface KeyboardKeyMatcher {
     key: string;
     requiresCtrl: boolean;
     requiresMeta: boolean;
+    requiresShift: boolean;
+    requiresAlt: boolean;
+}
+
+interface KeySequenceMatcher {
+    chords: Chord[];
+    timeout: number;
+}
+
+interface Chord {
+    key: KeyPress;
+    modifiers: Modifiers;
+}
+
+interface KeyPress {
+    type: string;
+    config: string;
 }
 
 interface MouseButtonMatcher {
@@ -223,6 +552,11 @@ interface EventPropagation {
     preventDefault: boolean;
 }
 
+interface RateLimit {
+    type: string;
+    config: DebounceConfig;
+}
+
 interface DebounceConfig {
     delay: number;
     leading: boolean;
@@ -238,17 +572,39 @@ interface FileInfo {
 
 export type {
     Page,
+    StatefulPage,
+    ModelAndEffects,
+    Effects,
+    Patch,
+    ReplacePatch,
+    InsertPatch,
+    RemovePatch,
+    SetAttributePatch,
+    RemoveAttributePatch,
+    SetTextPatch,
     Model,
     Msg,
     Subscription,
     RustInterval,
     RustEventListener,
+    RustUrlChange,
+    Location,
+    UrlRequest,
+    RustWebSocket,
+    Backoff,
+    StateMsgs,
+    RustEventSource,
+    EventSourceOptions,
+    RateLimit,
     DebounceConfig,
     EventMatcher,
     ExactSelectorMatcher,
     ClosestSelectorMatcher,
     MouseButtonMatcher,
     KeyboardKeyMatcher,
+    KeySequenceMatcher,
+    Chord,
+    KeyPress,
     Effect,
     NavigationEffect,
     LocalStorageEffect,
@@ -260,11 +616,22 @@ export type {
     SubscriptionMsg,
     DomEffect,
     TimeEffect,
+    Zone,
     GetElementValue,
     GetRadioGroupValue,
     GetFiles,
     FileInfo,
     GetTargetDataValue,
+    GetEventPayload,
+    GetFormData,
+    Modifiers,
+    MouseData,
+    PointerData,
+    WheelData,
+    TouchPoint,
+    TouchData,
+    FocusData,
+    ScrollData,
     FocusElement,
     SelectInputText,
     DispatchEvent,
@@ -278,6 +645,22 @@ export type {
     WriteText,
     WriteTextResult,
     JsMsg,
+    PortMsg,
+    PortData,
     BrowserEffect,
     SetTimeoutConfig,
+    SetNamedTimeoutConfig,
+    ClearTimeoutConfig,
+    HttpRequest,
+    HttpBody,
+    HttpError,
+    HttpBadStatus,
+    HttpResult,
+    WebSocketEffect,
+    SendWebSocketData,
+    GetMessageData,
+    EventSourceEffect,
+    GetEventData,
+    RandomEffect,
+    GetRandomBytes,
 };
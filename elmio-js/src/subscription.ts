@@ -1,22 +1,37 @@
 import type { Browser } from "./browser";
+import { BrowserEventSources } from "./browser/event_source";
+import type { Location } from "./browser/location";
 import { Domain, type Logger, Verbosity } from "./logger";
 import type {
     EffectfulMsg,
     Msg,
+    RustAnimationFrame,
     RustEventListener,
+    RustEventSource,
     RustInterval,
+    RustUrlChange,
+    RustWebSocket,
     Subscription,
     SubscriptionMsg,
 } from "./rust/types";
+import { AnimationFrameManager } from "./subscription/animation_frame";
 import { EventListenerManager } from "./subscription/event_listener";
+import { EventSourceManager } from "./subscription/event_source";
 import { IntervalManager } from "./subscription/interval";
+import { UrlChangeManager } from "./subscription/url_change";
+import type { WebSocketManager } from "./subscription/websocket";
 
 export class SubscriptionManager {
     private readonly eventListenerManager: EventListenerManager;
     private readonly intervalManager: IntervalManager;
+    private readonly urlChangeManager: UrlChangeManager;
+    private readonly eventSourceManager: EventSourceManager;
+    private readonly animationFrameManager: AnimationFrameManager;
 
     constructor(
         private readonly browser: Browser,
+        private readonly location: Location,
+        private readonly webSocketManager: WebSocketManager,
         private readonly logger: Logger,
         private readonly onMsg: (msg: Msg) => void,
     ) {
@@ -29,6 +44,28 @@ export class SubscriptionManager {
         this.intervalManager = new IntervalManager(this.browser, this.logger, (msg) =>
             this.onSubscriptionMsg(msg, null),
         );
+
+        this.urlChangeManager = new UrlChangeManager(
+            this.browser,
+            this.location,
+            this.logger,
+            (msg, event) => this.onSubscriptionMsg(msg, event),
+        );
+
+        this.eventSourceManager = new EventSourceManager(
+            new BrowserEventSources(),
+            this.location,
+            this.logger,
+            (msg, event) => this.onSubscriptionMsg(msg, event),
+        );
+
+        this.animationFrameManager = new AnimationFrameManager(
+            this.browser,
+            this.logger,
+            (msg, event) => this.onSubscriptionMsg(msg, event),
+        );
+
+        this.webSocketManager.setMsgHandler((msg, event) => this.onSubscriptionMsg(msg, event));
     }
 
     public handle(subscriptions: Subscription[]) {
@@ -44,6 +81,14 @@ export class SubscriptionManager {
         this.eventListenerManager.setEventListeners(groupedSubscriptions.eventListeners);
 
         this.intervalManager.setIntervals(groupedSubscriptions.intervals);
+
+        this.urlChangeManager.setUrlChanges(groupedSubscriptions.urlChanges);
+
+        this.webSocketManager.setWebSockets(groupedSubscriptions.webSockets);
+
+        this.eventSourceManager.setEventSources(groupedSubscriptions.eventSources);
+
+        this.animationFrameManager.setAnimationFrames(groupedSubscriptions.animationFrames);
     }
 
     private onSubscriptionMsg(subMsg: SubscriptionMsg, event: Event | null) {
@@ -79,12 +124,20 @@ export class SubscriptionManager {
 interface GroupedSubscriptions {
     eventListeners: RustEventListener[];
     intervals: RustInterval[];
+    urlChanges: RustUrlChange[];
+    webSockets: RustWebSocket[];
+    eventSources: RustEventSource[];
+    animationFrames: RustAnimationFrame[];
 }
 
 function groupSubscriptions(subscriptions: Subscription[], logger: Logger): GroupedSubscriptions {
     const groupedSubscriptions: GroupedSubscriptions = {
         eventListeners: [],
         intervals: [],
+        urlChanges: [],
+        webSockets: [],
+        eventSources: [],
+        animationFrames: [],
     };
 
     for (const subscription of subscriptions) {
@@ -99,6 +152,28 @@ function groupSubscriptions(subscriptions: Subscription[], logger: Logger): Grou
                 break;
             }
 
+            case "urlChange": {
+                groupedSubscriptions.urlChanges.push(subscription.config as RustUrlChange);
+                break;
+            }
+
+            case "webSocket": {
+                groupedSubscriptions.webSockets.push(subscription.config as RustWebSocket);
+                break;
+            }
+
+            case "eventSource": {
+                groupedSubscriptions.eventSources.push(subscription.config as RustEventSource);
+                break;
+            }
+
+            case "animationFrame": {
+                groupedSubscriptions.animationFrames.push(
+                    subscription.config as RustAnimationFrame,
+                );
+                break;
+            }
+
             case "none":
                 break;
 
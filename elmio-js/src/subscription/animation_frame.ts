@@ -0,0 +1,84 @@
+import type { AbortFn, Browser } from "../browser";
+import { Domain, type Logger, Verbosity } from "../logger";
+import type { AnimationFrame, RustAnimationFrame, SubscriptionMsg } from "../rust/types";
+import { posixFromMilliseconds } from "../utils/time";
+
+interface State {
+    animationFrames: RustAnimationFrame[];
+    abort: AbortFn | null;
+    previousTimestamp: number | null;
+}
+
+export class AnimationFrameManager {
+    private readonly state: State = {
+        animationFrames: [],
+        abort: null,
+        previousTimestamp: null,
+    };
+
+    constructor(
+        private readonly browser: Browser,
+        private readonly logger: Logger,
+        private readonly onMsg: (msg: SubscriptionMsg, event: Event) => void,
+    ) {}
+
+    public setAnimationFrames(animationFrames: RustAnimationFrame[]) {
+        this.state.animationFrames = animationFrames;
+
+        if (animationFrames.length > 0 && this.state.abort === null) {
+            this.start();
+        } else if (animationFrames.length === 0 && this.state.abort !== null) {
+            this.stop();
+        }
+    }
+
+    private start() {
+        this.state.previousTimestamp = null;
+        this.requestFrame();
+
+        this.logger.debug({
+            domain: Domain.AnimationFrame,
+            verbosity: Verbosity.Verbose,
+            message: "Started animation frames",
+            context: {},
+        });
+    }
+
+    private stop() {
+        this.state.abort?.abort();
+        this.state.abort = null;
+
+        this.logger.debug({
+            domain: Domain.AnimationFrame,
+            verbosity: Verbosity.Verbose,
+            message: "Stopped animation frames",
+            context: {},
+        });
+    }
+
+    private requestFrame() {
+        this.state.abort = this.browser.requestAnimationFrame((timestamp) => {
+            this.handleFrame(timestamp);
+        });
+    }
+
+    private handleFrame(timestamp: number) {
+        const previousTimestamp = this.state.previousTimestamp ?? timestamp;
+        this.state.previousTimestamp = timestamp;
+
+        // Request the next frame first, so the messages of this frame can stop it.
+        this.requestFrame();
+
+        // The frame timestamp is relative to the time origin of the page.
+        const frame: AnimationFrame = {
+            timestamp: posixFromMilliseconds(Math.round(this.browser.timeOrigin() + timestamp)),
+            delta: timestamp - previousTimestamp,
+        };
+
+        const event = new CustomEvent("animationframe", { detail: frame });
+
+        for (const animationFrame of this.state.animationFrames) {
+            this.onMsg(animationFrame.msg, event);
+        }
+    }
+}
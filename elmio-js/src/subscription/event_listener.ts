@@ -1,11 +1,15 @@
 import { deepEqual } from "fast-equals";
 import { type AbortFn, type Browser, listenTargetFromString } from "../browser";
 import { Domain, type Logger, Verbosity } from "../logger";
+import { mouseButtonToString } from "../utils/mouse";
+import { RateLimiter } from "../utils/rate_limit";
 
 import type {
+    Chord,
     ClosestSelectorMatcher,
     EventMatcher,
     ExactSelectorMatcher,
+    KeySequenceMatcher,
     KeyboardKeyMatcher,
     MouseButtonMatcher,
     RustEventListener,
@@ -15,17 +19,26 @@ import type {
 export interface ActiveEventListener {
     abort: AbortFn;
     listener: RustEventListener;
+    rateLimiter: RateLimiter | null;
 }
 
 interface State {
     eventListeners: ActiveEventListener[];
 }
 
+interface KeySequenceProgress {
+    matched: number;
+    lastTimeStamp: number;
+}
+
 export class EventListenerManager {
     private readonly state: State = {
         eventListeners: [],
     };
 
+    // Keyed by matcher, which stays the same object while its listener is kept.
+    private readonly keySequences = new WeakMap<KeySequenceMatcher, KeySequenceProgress>();
+
     constructor(
         private readonly browser: Browser,
         private readonly logger: Logger,
@@ -61,11 +74,17 @@ export class EventListenerManager {
     private startEventListener(listener: RustEventListener): ActiveEventListener {
         const listenTarget = listenTargetFromString(listener.listenTarget);
 
+        const rateLimiter = listener.rateLimit
+            ? new RateLimiter(this.browser, listener.rateLimit, (event) => {
+                  this.onMsg(listener.msg, event);
+              })
+            : null;
+
         const abort = this.browser.addEventListener(
             listenTarget,
             listener.eventType,
             (event) => {
-                this.handleEvent(event, listener);
+                this.handleEvent(event, listener, rateLimiter);
             },
             {
                 capture: true,
@@ -87,6 +106,7 @@ export class EventListenerManager {
         return {
             abort,
             listener,
+            rateLimiter,
         };
     }
 
@@ -94,8 +114,10 @@ export class EventListenerManager {
         for (const {
             abort: { abort },
             listener,
+            rateLimiter,
         } of listeners) {
             abort();
+            rateLimiter?.cancel();
 
             this.logger.debug({
                 domain: Domain.EventListener,
@@ -110,7 +132,11 @@ export class EventListenerManager {
         }
     }
 
-    private handleEvent(event: Event, listener: RustEventListener): void {
+    private handleEvent(
+        event: Event,
+        listener: RustEventListener,
+        rateLimiter: RateLimiter | null,
+    ): void {
         const matchesEvent = listener.matchers.every((matcher) => {
             return this.matchEvent(matcher, event);
         });
@@ -151,10 +177,17 @@ export class EventListenerManager {
                 matchers: listener.matchers,
                 event,
                 msg: listener.msg,
+                rateLimit: listener.rateLimit,
             },
         });
 
-        this.onMsg(listener.msg, event);
+        // Matching and propagation happen for every event,
+        // only the msg dispatch is rate limited.
+        if (rateLimiter) {
+            rateLimiter.call(event);
+        } else {
+            this.onMsg(listener.msg, event);
+        }
     }
 
     private matchEvent(matcher: EventMatcher, event: Event): boolean {
@@ -171,6 +204,15 @@ export class EventListenerManager {
             case "keyboardKey":
                 return this.matchKeyboardKey(matcher.config as KeyboardKeyMatcher, event);
 
+            case "chord":
+                return this.matchKeyChord(matcher.config as Chord, event);
+
+            case "keySequence":
+                return this.matchKeySequence(matcher.config as KeySequenceMatcher, event);
+
+            case "linkClick":
+                return this.matchLinkClick(event);
+
             default:
                 this.logger.warn({
                     domain: Domain.EventListener,
@@ -209,6 +251,26 @@ export class EventListenerManager {
         return matcher.button === mouseButtonToString(e.button);
     }
 
+    private matchLinkClick(event: Event): boolean {
+        const e = event as MouseEvent;
+        if (!("button" in e) || e.button !== 0) {
+            return false;
+        }
+
+        if (e.ctrlKey || e.metaKey || e.shiftKey || e.altKey) {
+            return false;
+        }
+
+        const elem = event.target as Element;
+        const link = elem && "closest" in elem ? elem.closest("a[href]") : null;
+        if (!link || link.hasAttribute("download")) {
+            return false;
+        }
+
+        const target = link.getAttribute("target");
+        return !target || target === "_self";
+    }
+
     private matchKeyboardKey(matcher: KeyboardKeyMatcher, event: Event): boolean {
         const e = event as KeyboardEvent;
         if (!("code" in e)) {
@@ -223,11 +285,94 @@ export class EventListenerManager {
             return false;
         }
 
+        if (matcher.requiresShift && !e.shiftKey) {
+            return false;
+        }
+
+        if (matcher.requiresAlt && !e.altKey) {
+            return false;
+        }
+
         const key = matcher.key.toLowerCase();
         const code = e.code.toLowerCase();
 
         return code === key || key === "any";
     }
+
+    private matchKeyChord(chord: Chord, event: Event): boolean {
+        return event instanceof KeyboardEvent && matchChord(chord, event);
+    }
+
+    private matchKeySequence(matcher: KeySequenceMatcher, event: Event): boolean {
+        if (!(event instanceof KeyboardEvent) || MODIFIER_KEYS.includes(event.key)) {
+            return false;
+        }
+
+        const progress = this.keySequences.get(matcher) ?? { matched: 0, lastTimeStamp: 0 };
+        const expired = event.timeStamp - progress.lastTimeStamp > matcher.timeout;
+        const next = progress.matched > 0 && !expired ? progress.matched : 0;
+
+        let matched = 0;
+        if (matchChord(matcher.chords[next], event)) {
+            matched = next + 1;
+        } else if (matchChord(matcher.chords[0], event)) {
+            matched = 1;
+        }
+
+        const completed = matched === matcher.chords.length;
+
+        this.keySequences.set(matcher, {
+            matched: completed ? 0 : matched,
+            lastTimeStamp: event.timeStamp,
+        });
+
+        return completed;
+    }
+}
+
+const MODIFIER_KEYS = ["Alt", "AltGraph", "Control", "Meta", "Shift"];
+
+// Modifiers the chord does not require must be released.
+function matchChord(chord: Chord, event: KeyboardEvent): boolean {
+    const ignoreShift = chord.key.type === "key" && isShiftedSymbol(chord.key.config);
+
+    const modifiers: [boolean, boolean, boolean][] = [
+        [chord.modifiers.alt, event.altKey, false],
+        [chord.modifiers.ctrl, event.ctrlKey, false],
+        [chord.modifiers.meta, event.metaKey, false],
+        [chord.modifiers.shift, event.shiftKey, ignoreShift],
+    ];
+
+    const modifiersMatch = modifiers.every(([required, held, ignored]) => {
+        return required ? held : ignored || !held;
+    });
+
+    return modifiersMatch && matchKeyPress(chord, event);
+}
+
+function matchKeyPress({ key, modifiers }: Chord, event: KeyboardEvent): boolean {
+    if (key.type === "code") {
+        return event.code === key.config;
+    }
+
+    if (event.key.toLowerCase() === key.config.toLowerCase()) {
+        return true;
+    }
+
+    // Alt changes the character produced on macOS, e.g. Alt+K types "˚",
+    // so letters and digits fall back to the physical key.
+    const character = key.config.toUpperCase();
+    if (modifiers.alt && /^[A-Z0-9]$/.test(character)) {
+        return event.code === `Key${character}` || event.code === `Digit${character}`;
+    }
+
+    return false;
+}
+
+// Characters that are not letters, like "?", may need Shift to be typed,
+// so Shift is only checked when the chord requires it.
+function isShiftedSymbol(key: string): boolean {
+    return [...key].length === 1 && key !== " " && key.toLowerCase() === key.toUpperCase();
 }
 
 interface EventListenersDelta {
@@ -249,7 +394,7 @@ function prepareEventListenersDelta(
     for (const oldListener of oldListeners) {
         const newListener = newListenerById.get(oldListener.listener.id);
 
-        if (newListener && hasSameMsg(oldListener.listener, newListener)) {
+        if (newListener && isUnchanged(oldListener.listener, newListener)) {
             listenersToKeep.push(oldListener);
         } else {
             listenersToRemove.push(oldListener);
@@ -271,23 +416,6 @@ function prepareEventListenersDelta(
     };
 }
 
-function hasSameMsg(a: RustEventListener, b: RustEventListener): boolean {
-    return deepEqual(a.msg.config, b.msg.config);
-}
-
-function mouseButtonToString(n: number): string | null {
-    switch (n) {
-        case 0:
-            return "main";
-        case 1:
-            return "auxiliary";
-        case 2:
-            return "secondary";
-        case 3:
-            return "fourth";
-        case 4:
-            return "fifth";
-    }
-
-    return null;
+function isUnchanged(a: RustEventListener, b: RustEventListener): boolean {
+    return deepEqual(a.msg.config, b.msg.config) && deepEqual(a.rateLimit, b.rateLimit);
 }
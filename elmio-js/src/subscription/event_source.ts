@@ -0,0 +1,127 @@
+import type { EventSources } from "../browser/event_source";
+import type { Location } from "../browser/location";
+import { Domain, type Logger, Verbosity } from "../logger";
+import type { RustEventSource, SubscriptionMsg } from "../rust/types";
+
+interface Connection {
+    source: EventSource;
+    eventTypes: Set<string>;
+    eventSources: RustEventSource[];
+}
+
+interface State {
+    connections: Map<string, Connection>;
+}
+
+// Subscriptions to the same url share one connection, with a listener for
+// every event type subscribed to.
+export class EventSourceManager {
+    private readonly state: State = {
+        connections: new Map(),
+    };
+
+    constructor(
+        private readonly eventSources: EventSources,
+        private readonly location: Location,
+        private readonly logger: Logger,
+        private readonly onMsg: (msg: SubscriptionMsg, event: Event) => void,
+    ) {}
+
+    public setEventSources(newEventSources: RustEventSource[]) {
+        const byUrl = new Map<string, RustEventSource[]>();
+        for (const eventSource of newEventSources) {
+            byUrl.set(eventSource.url, [...(byUrl.get(eventSource.url) ?? []), eventSource]);
+        }
+
+        this.logger.debug({
+            domain: Domain.EventSource,
+            verbosity: Verbosity.Normal,
+            message: "Updating event sources",
+            context: {
+                urls: [...byUrl.keys()],
+                connected: [...this.state.connections.keys()],
+            },
+        });
+
+        for (const [url, connection] of this.state.connections) {
+            if (!byUrl.has(url)) {
+                this.close(url, connection);
+            }
+        }
+
+        for (const [url, eventSources] of byUrl) {
+            const connection = this.state.connections.get(url) ?? this.open(url, eventSources);
+            connection.eventSources = eventSources;
+
+            for (const { options } of eventSources) {
+                this.listen(connection, options.eventType);
+            }
+        }
+    }
+
+    private open(url: string, eventSources: RustEventSource[]): Connection {
+        const fullUrl = new URL(url, this.location.href());
+
+        // elmio-core rejects subscriptions sharing a url with different
+        // options, so the options of the first one apply to all of them.
+        // The server has to read the lastEventId parameter to resume.
+        const { lastEventId, withCredentials } = eventSources[0].options;
+        if (lastEventId !== null) {
+            fullUrl.searchParams.set("lastEventId", lastEventId);
+        }
+
+        const source = this.eventSources.open(fullUrl.href, withCredentials);
+
+        source.addEventListener("error", (event) => {
+            this.logger.warn({
+                domain: Domain.EventSource,
+                message: "Event source error",
+                context: { url, readyState: source.readyState, event },
+            });
+        });
+
+        const connection: Connection = {
+            source,
+            eventTypes: new Set(),
+            eventSources,
+        };
+
+        this.state.connections.set(url, connection);
+
+        this.logger.debug({
+            domain: Domain.EventSource,
+            verbosity: Verbosity.Verbose,
+            message: "Opened event source",
+            context: { url: fullUrl.href, withCredentials },
+        });
+
+        return connection;
+    }
+
+    private listen(connection: Connection, eventType: string) {
+        if (connection.eventTypes.has(eventType)) {
+            return;
+        }
+
+        connection.eventTypes.add(eventType);
+        connection.source.addEventListener(eventType, (event) => {
+            for (const eventSource of connection.eventSources) {
+                if (eventSource.options.eventType === eventType) {
+                    this.onMsg(eventSource.msg, event);
+                }
+            }
+        });
+    }
+
+    private close(url: string, connection: Connection) {
+        connection.source.close();
+        this.state.connections.delete(url);
+
+        this.logger.debug({
+            domain: Domain.EventSource,
+            verbosity: Verbosity.Verbose,
+            message: "Closed event source",
+            context: { url },
+        });
+    }
+}
@@ -0,0 +1,102 @@
+import { type AbortFn, type Browser, listenTargetFromString } from "../browser";
+import type { Location } from "../browser/location";
+import { Domain, type Logger, Verbosity } from "../logger";
+import type { RustUrlChange, SubscriptionMsg } from "../rust/types";
+
+interface State {
+    urlChanges: RustUrlChange[];
+    aborts: AbortFn[];
+    lastUrl: string | null;
+}
+
+export class UrlChangeManager {
+    private readonly state: State = {
+        urlChanges: [],
+        aborts: [],
+        lastUrl: null,
+    };
+
+    constructor(
+        private readonly browser: Browser,
+        private readonly location: Location,
+        private readonly logger: Logger,
+        private readonly onMsg: (msg: SubscriptionMsg, event: Event) => void,
+    ) {}
+
+    public setUrlChanges(urlChanges: RustUrlChange[]) {
+        this.logger.debug({
+            domain: Domain.Navigation,
+            verbosity: Verbosity.Normal,
+            message: "Updating url change subscriptions",
+            context: { urlChanges },
+        });
+
+        this.state.urlChanges = urlChanges;
+
+        if (urlChanges.length > 0 && this.state.aborts.length === 0) {
+            this.start();
+        } else if (urlChanges.length === 0 && this.state.aborts.length > 0) {
+            this.stop();
+        }
+    }
+
+    private start() {
+        const target = listenTargetFromString("window");
+        const config = { capture: false, passive: true };
+
+        this.state.lastUrl = this.location.href();
+        this.state.aborts = [
+            this.browser.addEventListener(
+                target,
+                "popstate",
+                (event) => this.handleEvent(event),
+                config,
+            ),
+            this.browser.addEventListener(
+                target,
+                "hashchange",
+                (event) => this.handleEvent(event),
+                config,
+            ),
+        ];
+
+        this.logger.debug({
+            domain: Domain.Navigation,
+            verbosity: Verbosity.Verbose,
+            message: "Started listening for url changes",
+            context: {},
+        });
+    }
+
+    private stop() {
+        for (const abort of this.state.aborts) {
+            abort.abort();
+        }
+
+        this.state.aborts = [];
+
+        this.logger.debug({
+            domain: Domain.Navigation,
+            verbosity: Verbosity.Verbose,
+            message: "Stopped listening for url changes",
+            context: {},
+        });
+    }
+
+    private handleEvent(event: Event) {
+        const url = this.location.href();
+
+        // A fragment navigation fires both popstate and hashchange, only the
+        // first one is reported. Popstate is always reported since going back
+        // after push_url can lead to the url we saw last.
+        if (event.type === "hashchange" && url === this.state.lastUrl) {
+            return;
+        }
+
+        this.state.lastUrl = url;
+
+        for (const urlChange of this.state.urlChanges) {
+            this.onMsg(urlChange.msg, event);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+import type { AbortFn, Browser } from "../browser";
+import type { Sockets } from "../browser/websocket";
+import { Domain, type Logger, Verbosity } from "../logger";
+import type { RustWebSocket, SubscriptionMsg } from "../rust/types";
+
+interface ActiveWebSocket {
+    config: RustWebSocket;
+    socket: WebSocket | null;
+    attempts: number;
+    reconnect: AbortFn | null;
+    pending: string[];
+    stopped: boolean;
+}
+
+interface State {
+    webSockets: Map<string, ActiveWebSocket>;
+}
+
+// Owns the sockets opened by websocket subscriptions, so the send effect
+// can reach them.
+export class WebSocketManager {
+    private readonly state: State = {
+        webSockets: new Map(),
+    };
+
+    private onMsg: (msg: SubscriptionMsg, event: Event | null) => void = () => {};
+
+    constructor(
+        private readonly sockets: Sockets,
+        private readonly browser: Browser,
+        private readonly logger: Logger,
+    ) {}
+
+    public setMsgHandler(onMsg: (msg: SubscriptionMsg, event: Event | null) => void) {
+        this.onMsg = onMsg;
+    }
+
+    public setWebSockets(newWebSockets: RustWebSocket[]) {
+        const newIds = newWebSockets.map((webSocket) => webSocket.id);
+        const removing = [...this.state.webSockets.keys()].filter((id) => !newIds.includes(id));
+        const adding = newIds.filter((id) => !this.state.webSockets.has(id));
+
+        this.logger.debug({
+            domain: Domain.WebSocket,
+            verbosity: Verbosity.Normal,
+            message: "Updating websockets",
+            context: { removing, adding },
+        });
+
+        for (const id of removing) {
+            const active = this.state.webSockets.get(id);
+            if (active) {
+                this.stop(active);
+            }
+            this.state.webSockets.delete(id);
+        }
+
+        for (const config of newWebSockets) {
+            const active = this.state.webSockets.get(config.id);
+
+            if (active) {
+                // Keep the connection, but deliver the latest messages
+                active.config = config;
+                continue;
+            }
+
+            const added: ActiveWebSocket = {
+                config,
+                socket: null,
+                attempts: 0,
+                reconnect: null,
+                pending: [],
+                stopped: false,
+            };
+
+            this.state.webSockets.set(config.id, added);
+            this.connect(added);
+        }
+    }
+
+    public send(url: string, data: string) {
+        const active = [...this.state.webSockets.values()].find(
+            (webSocket) => webSocket.config.url === url,
+        );
+
+        if (!active) {
+            this.logger.warn({
+                domain: Domain.WebSocket,
+                message: "No websocket subscribed to url",
+                context: { url },
+            });
+
+            return;
+        }
+
+        if (active.socket?.readyState === WebSocket.OPEN) {
+            active.socket.send(data);
+        } else {
+            active.pending.push(data);
+        }
+    }
+
+    private connect(active: ActiveWebSocket) {
+        const { url } = active.config;
+        const socket = this.sockets.open(url);
+        active.socket = socket;
+        active.reconnect = null;
+
+        this.onStateMsg(active, "connecting", null);
+
+        socket.addEventListener("open", (event) => {
+            active.attempts = 0;
+
+            for (const data of active.pending) {
+                socket.send(data);
+            }
+            active.pending = [];
+
+            this.onStateMsg(active, "open", event);
+        });
+
+        socket.addEventListener("message", (event) => {
+            this.onMsg(active.config.msg, event);
+        });
+
+        socket.addEventListener("error", (event) => {
+            this.onStateMsg(active, "error", event);
+        });
+
+        socket.addEventListener("close", (event) => {
+            if (active.stopped) {
+                return;
+            }
+
+            this.onStateMsg(active, "closed", event);
+            this.scheduleReconnect(active);
+        });
+
+        this.logger.debug({
+            domain: Domain.WebSocket,
+            verbosity: Verbosity.Verbose,
+            message: "Opening websocket",
+            context: { url, attempts: active.attempts },
+        });
+    }
+
+    private scheduleReconnect(active: ActiveWebSocket) {
+        const { initialDelay, maxDelay, multiplier, maxAttempts } = active.config.backoff;
+
+        if (maxAttempts !== null && active.attempts >= maxAttempts) {
+            this.logger.debug({
+                domain: Domain.WebSocket,
+                verbosity: Verbosity.Normal,
+                message: "Giving up reconnecting websocket",
+                context: { url: active.config.url, attempts: active.attempts },
+            });
+
+            return;
+        }
+
+        const delay = Math.min(initialDelay * multiplier ** active.attempts, maxDelay);
+        active.attempts += 1;
+
+        active.reconnect = this.browser.setTimeout(() => {
+            this.connect(active);
+        }, delay);
+    }
+
+    private stop(active: ActiveWebSocket) {
+        active.stopped = true;
+        active.reconnect?.abort();
+        active.socket?.close();
+
+        this.logger.debug({
+            domain: Domain.WebSocket,
+            verbosity: Verbosity.Verbose,
+            message: "Closed websocket",
+            context: { url: active.config.url },
+        });
+    }
+
+    private onStateMsg(
+        active: ActiveWebSocket,
+        state: "connecting" | "open" | "closed" | "error",
+        event: Event | null,
+    ) {
+        const stateMsgs = active.config.stateMsgs;
+        if (stateMsgs) {
+            this.onMsg({ type: "pure", config: stateMsgs[state] }, event);
+        }
+    }
+}
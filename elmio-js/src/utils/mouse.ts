@@ -0,0 +1,30 @@
+export function mouseButtonToString(n: number): string | null {
+    switch (n) {
+        case 0:
+            return "main";
+        case 1:
+            return "auxiliary";
+        case 2:
+            return "secondary";
+        case 3:
+            return "fourth";
+        case 4:
+            return "fifth";
+    }
+
+    return null;
+}
+
+// Converts the `MouseEvent.buttons` bitmask, where the secondary and
+// auxiliary bits are swapped compared to `MouseEvent.button`.
+export function mouseButtonsToStrings(buttons: number): string[] {
+    const bits: [number, string][] = [
+        [1, "main"],
+        [2, "secondary"],
+        [4, "auxiliary"],
+        [8, "fourth"],
+        [16, "fifth"],
+    ];
+
+    return bits.filter(([bit]) => (buttons & bit) !== 0).map(([, name]) => name);
+}
@@ -0,0 +1,92 @@
+import type { AbortFn, Browser } from "../browser";
+import type { DebounceConfig, RateLimit } from "../rust/types";
+
+interface State {
+    timer: AbortFn | null;
+    pendingEvent: Event | null;
+}
+
+export class RateLimiter {
+    private readonly state: State = {
+        timer: null,
+        pendingEvent: null,
+    };
+
+    constructor(
+        private readonly browser: Browser,
+        private readonly rateLimit: RateLimit,
+        private readonly dispatch: (event: Event) => void,
+    ) {}
+
+    public call(event: Event) {
+        switch (this.rateLimit.type) {
+            case "debounce":
+                return this.debounce(this.rateLimit.config, event);
+
+            case "throttle":
+                return this.throttle(this.rateLimit.config, event);
+
+            default:
+                this.dispatch(event);
+        }
+    }
+
+    public cancel() {
+        this.state.timer?.abort();
+        this.state.timer = null;
+        this.state.pendingEvent = null;
+    }
+
+    // Dispatches once the events have stopped for the configured delay,
+    // and optionally on the first event of a burst.
+    private debounce(config: DebounceConfig, event: Event) {
+        const isNewBurst = this.state.timer === null;
+
+        this.state.timer?.abort();
+        this.state.pendingEvent = isNewBurst && config.leading ? null : event;
+
+        if (isNewBurst && config.leading) {
+            this.dispatch(event);
+        }
+
+        this.state.timer = this.browser.setTimeout(() => {
+            const pendingEvent = this.state.pendingEvent;
+            this.state.timer = null;
+            this.state.pendingEvent = null;
+
+            if (pendingEvent && config.trailing) {
+                this.dispatch(pendingEvent);
+            }
+        }, config.delay);
+    }
+
+    // Dispatches at most once per delay, optionally at the start and
+    // with the last event at the end of each window.
+    private throttle(config: DebounceConfig, event: Event) {
+        if (this.state.timer !== null) {
+            this.state.pendingEvent = event;
+            return;
+        }
+
+        if (config.leading) {
+            this.dispatch(event);
+        } else {
+            this.state.pendingEvent = event;
+        }
+
+        this.startThrottleWindow(config);
+    }
+
+    private startThrottleWindow(config: DebounceConfig) {
+        this.state.timer = this.browser.setTimeout(() => {
+            const pendingEvent = this.state.pendingEvent;
+            this.state.timer = null;
+            this.state.pendingEvent = null;
+
+            if (pendingEvent && config.trailing) {
+                this.dispatch(pendingEvent);
+                this.startThrottleWindow(config);
+            }
+        }, config.delay);
+    }
+}
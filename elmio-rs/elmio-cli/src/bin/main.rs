@@ -3,7 +3,7 @@ use std::{
     process,
 };
 
-use clap::{Parser, Subcommand, command};
+use clap::{Parser, Subcommand};
 use elmio_cli::{
     builders::{
         backlog_builder::{self, BacklogBuilder},
@@ -13,6 +13,7 @@ use elmio_cli::{
     commands::{
         build::{Env, Runner},
         cleaner::{self, Cleaner},
+        gen_types,
         script_runner::{self, ScriptRunner},
         serve, watch,
     },
@@ -61,6 +62,10 @@ enum Commands {
         /// Specify a script to run after the build process completes.
         #[arg(long)]
         script: Option<String>,
+
+        /// Generate TypeScript types for the core project before the web build.
+        #[arg(long)]
+        gen_types: bool,
     },
 
     /// Watch for file changes and rebuild automatically.
@@ -69,6 +74,20 @@ enum Commands {
         /// Specify a script to run after each rebuild.
         #[arg(long)]
         script: Option<String>,
+
+        /// Generate TypeScript types for the core project before each web build.
+        #[arg(long)]
+        gen_types: bool,
+    },
+
+    /// Generate TypeScript types for the serde types of the core project. `build --gen-types`
+    /// and `watch --gen-types` run this before the web build, so that `npm run compile-ts`
+    /// checks against them.
+    GenTypes {
+        /// File path to write the declarations to (defaults to src/rust_types.d.ts in the
+        /// web project).
+        #[arg(long, value_name = "PATH")]
+        out: Option<PathBuf>,
     },
 
     /// Serve static files, routes, and headers for local development or testing.
@@ -147,6 +166,7 @@ fn main() {
             script,
             release,
             hash_assets,
+            gen_types,
         } => {
             let env = if release { Env::Release } else { Env::Dev };
             let current_dir = get_current_dir();
@@ -159,8 +179,7 @@ fn main() {
             let rust_builder =
                 RustBuilder::new(rust_builder::Config::from_project_info(&env, &project_info));
 
-            let web_builder =
-                WebBuilder::new(web_builder::Config::from_project_info(&env, &project_info));
+            let web_builder = WebBuilder::new(web_builder_config(&env, &project_info, gen_types));
 
             cleaner.run().expect("Cleaner failed");
 
@@ -207,7 +226,7 @@ fn main() {
             }
         }
 
-        Commands::Watch { script } => {
+        Commands::Watch { script, gen_types } => {
             let env = Env::Dev;
             let current_dir = get_current_dir();
             let project_info = ProjectInfo::from_dir(&current_dir).unwrap();
@@ -220,10 +239,8 @@ fn main() {
                 rust_builder::Config::from_project_info(&env, &project_info),
             );
 
-            let web_builder = web_builder::WebBuilder::new(web_builder::Config::from_project_info(
-                &env,
-                &project_info,
-            ));
+            let web_builder =
+                web_builder::WebBuilder::new(web_builder_config(&env, &project_info, gen_types));
 
             let post_build_runner = if let Some(script_name) = script {
                 let script_path = current_dir.join(script_name);
@@ -267,6 +284,26 @@ fn main() {
             watch::watch(watcher_config);
         }
 
+        Commands::GenTypes { out } => {
+            let current_dir = get_current_dir();
+            let project_info = ProjectInfo::from_dir(&current_dir).unwrap();
+            let config = gen_types::Config::from_project_info(&project_info, out);
+
+            match gen_types::run(&config) {
+                Ok(warnings) => {
+                    for warning in warnings {
+                        eprintln!("Warning: {}", warning);
+                    }
+
+                    println!("Types written to {}", config.out_path.display());
+                }
+                Err(err) => {
+                    eprintln!("Error: {}", err);
+                    process::exit(1);
+                }
+            }
+        }
+
         Commands::Serve {
             static_,
             routes,
@@ -316,6 +353,20 @@ fn hash_assets_helper(
     }
 }
 
+fn web_builder_config(
+    env: &Env,
+    project_info: &ProjectInfo,
+    gen_types: bool,
+) -> web_builder::Config {
+    let config = web_builder::Config::from_project_info(env, project_info);
+
+    if gen_types {
+        config.with_gen_types(project_info)
+    } else {
+        config
+    }
+}
+
 fn get_current_dir() -> PathBuf {
     std::env::current_dir().unwrap()
 }
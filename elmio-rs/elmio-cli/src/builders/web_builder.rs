@@ -3,7 +3,7 @@ use std::{fmt, path::PathBuf};
 use crate::{
     commands::{
         build::{Env, Runner},
-        exec,
+        exec, gen_types,
     },
     utils::project_info::ProjectInfo,
 };
@@ -12,6 +12,7 @@ use crate::{
 pub struct Config {
     pub env: Env,
     pub web_project_path: PathBuf,
+    pub gen_types: Option<gen_types::Config>,
 }
 
 impl Config {
@@ -19,12 +20,22 @@ impl Config {
         Self {
             env: env.clone(),
             web_project_path: project_info.web_project_path.clone(),
+            gen_types: None,
+        }
+    }
+
+    /// Runs `elmio gen-types` before every web build.
+    pub fn with_gen_types(self, project_info: &ProjectInfo) -> Self {
+        Self {
+            gen_types: Some(gen_types::Config::from_project_info(project_info, None)),
+            ..self
         }
     }
 }
 
 #[derive(Debug)]
 pub enum Error {
+    GenTypes(gen_types::Error),
     NpmInstall(exec::Error),
     NpmBuildDev(exec::Error),
     NpmBuildRelease(exec::Error),
@@ -33,6 +44,7 @@ pub enum Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match self {
+            Error::GenTypes(err) => write!(f, "'elmio gen-types' failed: {}", err),
             Error::NpmInstall(err) => write!(f, "'npm install' failed: {}", err),
             Error::NpmBuildDev(err) => write!(f, "'npm run build-dev' failed: {}", err),
             Error::NpmBuildRelease(err) => write!(f, "'npm run build-release' failed: {}", err),
@@ -51,6 +63,7 @@ impl WebBuilder {
     }
 
     fn build_dev(&self) -> Result<(), Error> {
+        self.gen_types()?;
         self.npm_install()?;
 
         exec::run(&exec::Config {
@@ -64,6 +77,7 @@ impl WebBuilder {
     }
 
     fn build_release(&self) -> Result<(), Error> {
+        self.gen_types()?;
         self.npm_install()?;
 
         exec::run(&exec::Config {
@@ -76,6 +90,22 @@ impl WebBuilder {
         Ok(())
     }
 
+    // Declares the Rust types before `npm run compile-ts` checks the
+    // TypeScript glue against them.
+    fn gen_types(&self) -> Result<(), Error> {
+        let Some(config) = &self.config.gen_types else {
+            return Ok(());
+        };
+
+        let warnings = gen_types::run(config).map_err(Error::GenTypes)?;
+
+        for warning in warnings {
+            eprintln!("Warning: {}", warning);
+        }
+
+        Ok(())
+    }
+
     fn npm_install(&self) -> Result<(), Error> {
         exec::run(&exec::Config {
             work_dir: self.config.web_project_path.clone(),
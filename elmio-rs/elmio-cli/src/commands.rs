@@ -1,6 +1,7 @@
 pub mod build;
 pub mod cleaner;
 pub mod exec;
+pub mod gen_types;
 pub mod script_runner;
 pub mod serve;
 pub mod watch;
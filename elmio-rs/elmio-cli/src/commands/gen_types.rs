@@ -0,0 +1,120 @@
+use std::{fmt, fs, io, path::PathBuf};
+
+use serde::Deserialize;
+
+use crate::{commands::exec, typescript, utils::project_info::ProjectInfo};
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub project_name: String,
+    pub core_project_path: PathBuf,
+    pub out_path: PathBuf,
+}
+
+impl Config {
+    pub fn from_project_info(project_info: &ProjectInfo, out_path: Option<PathBuf>) -> Self {
+        Self {
+            project_name: project_info.project_name.clone(),
+            core_project_path: project_info.core_project_path.clone(),
+            out_path: out_path
+                .unwrap_or_else(|| project_info.web_project_path_src().join("rust_types.d.ts")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    CargoMetadata(exec::Error),
+    ParseCargoMetadata(serde_json::Error),
+    ElmioCoreNotFound,
+    Generate(typescript::Error),
+    CreateOutDir(io::Error),
+    WriteTypes(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Error::CargoMetadata(err) => write!(f, "cargo metadata failed: {}", err),
+
+            Error::ParseCargoMetadata(err) => {
+                write!(f, "Failed to parse cargo metadata: {}", err)
+            }
+
+            Error::ElmioCoreNotFound => {
+                write!(f, "The core project does not depend on elmio-core")
+            }
+
+            Error::Generate(err) => write!(f, "Failed to generate types: {}", err),
+
+            Error::CreateOutDir(err) => write!(f, "Failed to create the types dir: {}", err),
+
+            Error::WriteTypes(err) => write!(f, "Failed to write types: {}", err),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoMetadata {
+    packages: Vec<Package>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Package {
+    name: String,
+    manifest_path: PathBuf,
+}
+
+/// Writes TypeScript declarations for the serde types of the core project,
+/// e.g. each page's `Model` and `Msg`, to be checked by `tsc`. Returns the
+/// warnings about types that could only be declared as `unknown`.
+pub fn run(config: &Config) -> Result<Vec<String>, Error> {
+    let elmio_core_path = elmio_core_path(config)?;
+
+    let project = typescript::Crate {
+        name: format!("{}_core", config.project_name),
+        src_path: config.core_project_path.join("src"),
+    };
+
+    let elmio_core = typescript::Crate {
+        name: "elmio_core".to_string(),
+        src_path: elmio_core_path.join("src"),
+    };
+
+    let declarations = typescript::generate(&project, &elmio_core).map_err(Error::Generate)?;
+
+    if let Some(out_dir) = config.out_path.parent() {
+        fs::create_dir_all(out_dir).map_err(Error::CreateOutDir)?;
+    }
+
+    // Unchanged declarations are not written again, so that `elmio watch`
+    // doesn't rebuild because of the build's own output.
+    let current = fs::read_to_string(&config.out_path).ok();
+
+    if current.as_ref() != Some(&declarations.typescript) {
+        fs::write(&config.out_path, declarations.typescript).map_err(Error::WriteTypes)?;
+    }
+
+    Ok(declarations.warnings)
+}
+
+// Finds the elmio-core sources used by the core project, whether it comes
+// from crates.io, git or a local path.
+fn elmio_core_path(config: &Config) -> Result<PathBuf, Error> {
+    let metadata = exec::run(&exec::Config {
+        work_dir: config.core_project_path.clone(),
+        cmd: "cargo".into(),
+        args: exec::to_args(&["metadata", "--format-version", "1"]),
+    })
+    .map_err(Error::CargoMetadata)?;
+
+    let metadata: CargoMetadata =
+        serde_json::from_str(&metadata).map_err(Error::ParseCargoMetadata)?;
+
+    metadata
+        .packages
+        .into_iter()
+        .find(|package| package.name == "elmio-core")
+        .and_then(|package| package.manifest_path.parent().map(PathBuf::from))
+        .ok_or(Error::ElmioCoreNotFound)
+}
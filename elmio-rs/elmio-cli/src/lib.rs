@@ -1,4 +1,5 @@
 pub mod builders;
 pub mod commands;
 pub mod project;
+pub mod typescript;
 pub mod utils;
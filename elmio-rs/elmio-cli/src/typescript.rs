@@ -0,0 +1,1028 @@
+pub mod serde_attrs;
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use syn::{
+    Attribute, Data, DataEnum, DeriveInput, Fields, GenericArgument, GenericParam, Item,
+    PathArguments, Token, Type, UseTree, ext::IdentExt, punctuated::Punctuated,
+};
+use walkdir::WalkDir;
+
+use serde_attrs::{Container, Field, RenameRule, Variant};
+
+const HEADER: &str = "// Generated by `elmio gen-types`, do not edit.\n";
+
+#[derive(Debug)]
+pub enum Error {
+    ReadSource(PathBuf, io::Error),
+    ParseSource(PathBuf, syn::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Error::ReadSource(path, err) => {
+                write!(f, "Failed to read {}: {}", path.display(), err)
+            }
+
+            Error::ParseSource(path, err) => {
+                write!(f, "Failed to parse {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Crate {
+    pub name: String,
+    pub src_path: PathBuf,
+}
+
+/// The generated declarations, along with the types that could only be
+/// declared as `unknown`.
+#[derive(Debug, Clone)]
+pub struct Declarations {
+    pub typescript: String,
+    pub warnings: Vec<String>,
+}
+
+/// Generates TypeScript declarations for the serde representation of every
+/// type deriving `Serialize` or `Deserialize` in `project`, along with the
+/// `Effect` and `Subscription` wire format of `elmio_core`.
+///
+/// Types are declared in a namespace named after their module, e.g.
+/// `home_page.Msg`, and `elmio_core` types in the `elmio_core` namespace.
+///
+/// Enums deriving `IncomingPort` or `OutgoingPort` are declared as the
+/// `{ type, data }` messages of the port instead.
+///
+/// Types implementing `Serialize` or `Deserialize` by hand are declared as
+/// `unknown` with a warning, since their representation can't be read from
+/// the source. Use `#[serde(into = "...", from = "...")]` to declare them.
+pub fn generate(project: &Crate, elmio_core: &Crate) -> Result<Declarations, Error> {
+    let mut registry = Registry::new(&project.name);
+    registry.load(project)?;
+    registry.load(elmio_core)?;
+    registry.add_manual_impls();
+
+    let mut roots: Vec<TypeKey> = registry
+        .types
+        .keys()
+        .filter(|key| key[0] == project.name)
+        .cloned()
+        .collect();
+
+    roots.push(elmio_key(&["browser", "effect", "Effect"]));
+    roots.push(elmio_key(&["browser", "subscription", "Subscription"]));
+
+    Ok(registry.render(roots))
+}
+
+fn elmio_key(path: &[&str]) -> TypeKey {
+    std::iter::once("elmio_core")
+        .chain(path.iter().copied())
+        .map(String::from)
+        .collect()
+}
+
+// A module path or an item path, starting with the crate name.
+type ModulePath = Vec<String>;
+
+// The module path of a type followed by its name.
+type TypeKey = Vec<String>;
+
+#[derive(Default)]
+struct Scope {
+    children: HashSet<String>,
+    imports: HashMap<String, Vec<String>>,
+    globs: Vec<Vec<String>>,
+}
+
+struct TypeDef {
+    module: ModulePath,
+    input: DeriveInput,
+    // Serialized by a hand-written impl instead of a derive.
+    manual: bool,
+    // Sent as port messages, see `elmio_macro::IncomingPort`.
+    port: bool,
+}
+
+struct Registry {
+    root: String,
+    scopes: HashMap<ModulePath, Scope>,
+    types: BTreeMap<TypeKey, TypeDef>,
+    // Types without a serde derive, and the serde impls written for them.
+    plain_types: HashMap<TypeKey, TypeDef>,
+    manual_impls: Vec<(ModulePath, syn::Path)>,
+}
+
+impl Registry {
+    fn new(root: &str) -> Registry {
+        Registry {
+            root: root.to_string(),
+            scopes: HashMap::new(),
+            types: BTreeMap::new(),
+            plain_types: HashMap::new(),
+            manual_impls: Vec::new(),
+        }
+    }
+
+    fn load(&mut self, krate: &Crate) -> Result<(), Error> {
+        let files = WalkDir::new(&krate.src_path)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "rs"));
+
+        for entry in files {
+            let path = entry.path();
+            let Some(module) = module_path(&krate.name, &krate.src_path, path) else {
+                continue;
+            };
+
+            let source =
+                fs::read_to_string(path).map_err(|err| Error::ReadSource(path.into(), err))?;
+
+            let file =
+                syn::parse_file(&source).map_err(|err| Error::ParseSource(path.into(), err))?;
+
+            self.add_items(&module, file.items);
+        }
+
+        Ok(())
+    }
+
+    fn add_items(&mut self, module: &ModulePath, items: Vec<Item>) {
+        self.scopes.entry(module.clone()).or_default();
+
+        for item in items {
+            match item {
+                Item::Use(item) => self.add_use(module, Vec::new(), &item.tree),
+
+                Item::Mod(item) if !is_cfg_test(&item.attrs) => {
+                    let name = item.ident.unraw().to_string();
+                    self.scope(module).children.insert(name.clone());
+
+                    if let Some((_, items)) = item.content {
+                        let child = [module.clone(), vec![name]].concat();
+                        self.add_items(&child, items);
+                    }
+                }
+
+                Item::Struct(item) => self.add_type(module, DeriveInput::from(item)),
+
+                Item::Enum(item) => self.add_type(module, DeriveInput::from(item)),
+
+                Item::Impl(item) if item.trait_.as_ref().is_some_and(is_serde_impl) => {
+                    if let Type::Path(self_ty) = item.self_ty.as_ref() {
+                        self.manual_impls
+                            .push((module.clone(), self_ty.path.clone()));
+                    }
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    fn add_use(&mut self, module: &ModulePath, prefix: Vec<String>, tree: &UseTree) {
+        match tree {
+            UseTree::Path(path) => {
+                let prefix = [prefix, vec![path.ident.to_string()]].concat();
+                self.add_use(module, prefix, &path.tree);
+            }
+
+            UseTree::Name(name) if name.ident == "self" => {
+                if let Some(last) = prefix.last() {
+                    let last = last.clone();
+                    self.scope(module).imports.insert(last, prefix);
+                }
+            }
+
+            UseTree::Name(name) => {
+                let ident = name.ident.to_string();
+                let path = [prefix, vec![ident.clone()]].concat();
+                self.scope(module).imports.insert(ident, path);
+            }
+
+            UseTree::Rename(rename) => {
+                let path = [prefix, vec![rename.ident.to_string()]].concat();
+                let alias = rename.rename.to_string();
+                self.scope(module).imports.insert(alias, path);
+            }
+
+            UseTree::Glob(_) => self.scope(module).globs.push(prefix),
+
+            UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.add_use(module, prefix.clone(), tree);
+                }
+            }
+        }
+    }
+
+    fn add_type(&mut self, module: &ModulePath, input: DeriveInput) {
+        let key = [module.clone(), vec![input.ident.unraw().to_string()]].concat();
+        let derived = derives(&input.attrs, &["Serialize", "Deserialize"]);
+        let port = derives(&input.attrs, &["IncomingPort", "OutgoingPort"]);
+
+        let def = TypeDef {
+            module: module.clone(),
+            input,
+            manual: false,
+            port,
+        };
+
+        if derived || port {
+            self.types.insert(key, def);
+        } else {
+            self.plain_types.insert(key, def);
+        }
+    }
+
+    // Registers the types serialized by hand, once all modules are loaded so
+    // that the paths of the impls can be resolved.
+    fn add_manual_impls(&mut self) {
+        for (module, path) in std::mem::take(&mut self.manual_impls) {
+            let segments: Vec<String> = path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.unraw().to_string())
+                .collect();
+
+            let candidates = [
+                Some([module.clone(), segments.clone()].concat()),
+                self.absolute(&module, &segments, 0),
+            ];
+
+            for key in candidates.into_iter().flatten() {
+                if let Some(def) = self.plain_types.remove(&key) {
+                    self.types.insert(
+                        key,
+                        TypeDef {
+                            manual: true,
+                            ..def
+                        },
+                    );
+                    break;
+                }
+            }
+        }
+    }
+
+    fn scope(&mut self, module: &ModulePath) -> &mut Scope {
+        self.scopes.entry(module.clone()).or_default()
+    }
+
+    // Turns a path written in `module` into a path starting with a crate name.
+    fn absolute(
+        &self,
+        module: &ModulePath,
+        segments: &[String],
+        depth: usize,
+    ) -> Option<Vec<String>> {
+        let (first, rest) = segments.split_first()?;
+        let scope = self.scopes.get(module);
+
+        let base = match first.as_str() {
+            "crate" => vec![module[0].clone()],
+            "self" => module.clone(),
+            "super" => {
+                let parent = module.get(..module.len().checked_sub(1)?)?.to_vec();
+
+                match rest.first() {
+                    _ if parent.is_empty() => return None,
+                    Some(next) if next == "super" => return self.absolute(&parent, rest, depth),
+                    _ => parent,
+                }
+            }
+            _ if scope.is_some_and(|scope| scope.children.contains(first)) => {
+                [module.clone(), vec![first.clone()]].concat()
+            }
+            _ => match scope.and_then(|scope| scope.imports.get(first)) {
+                Some(import) if depth < 8 => self.absolute(module, import, depth + 1)?,
+                _ => vec![first.clone()],
+            },
+        };
+
+        Some([base, rest.to_vec()].concat())
+    }
+
+    fn resolve(&self, module: &ModulePath, path: &syn::Path) -> Option<&TypeKey> {
+        let segments: Vec<String> = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.unraw().to_string())
+            .collect();
+
+        let mut candidates = Vec::new();
+
+        if let [name] = segments.as_slice() {
+            candidates.push([module.clone(), vec![name.clone()]].concat());
+
+            if let Some(scope) = self.scopes.get(module) {
+                for glob in &scope.globs {
+                    if let Some(glob) = self.absolute(module, glob, 0) {
+                        candidates.push([glob, vec![name.clone()]].concat());
+                    }
+                }
+            }
+        }
+
+        candidates.extend(self.absolute(module, &segments, 0));
+
+        candidates
+            .iter()
+            .find_map(|candidate| self.types.get_key_value(candidate))
+            .map(|(key, _)| key)
+    }
+
+    // Finds types re-exported from another module of a loaded crate by name,
+    // as long as the name is unique within the crate.
+    fn resolve_reexport(&self, module: &ModulePath, path: &syn::Path) -> Option<&TypeKey> {
+        let segments: Vec<String> = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.unraw().to_string())
+            .collect();
+
+        let absolute = self.absolute(module, &segments, 0)?;
+        let krate = absolute.first()?;
+        let name = absolute.last()?;
+
+        let mut matches = self
+            .types
+            .keys()
+            .filter(|key| key.first() == Some(krate) && key.last() == Some(name));
+
+        match (matches.next(), matches.next()) {
+            (Some(key), None) => Some(key),
+            _ => None,
+        }
+    }
+
+    fn qualified_name(&self, key: &TypeKey) -> String {
+        if key[0] == self.root {
+            key[1..].join(".")
+        } else {
+            key.join(".")
+        }
+    }
+
+    fn render(&self, roots: Vec<TypeKey>) -> Declarations {
+        let mut pending: Vec<TypeKey> = roots;
+        let mut rendered: BTreeMap<ModulePath, Vec<String>> = BTreeMap::new();
+        let mut seen: HashSet<TypeKey> = HashSet::new();
+        let mut warnings = Vec::new();
+
+        while let Some(key) = pending.pop() {
+            let Some(def) = self.types.get(&key) else {
+                continue;
+            };
+
+            if !seen.insert(key.clone()) {
+                continue;
+            }
+
+            if def.manual {
+                warnings.push(format!(
+                    "{} implements serde by hand and is declared as unknown",
+                    key.join("::")
+                ));
+            }
+
+            let renderer = Renderer::new(self, def);
+            let declaration = renderer.declaration();
+
+            rendered
+                .entry(def.module.clone())
+                .or_default()
+                .push(declaration);
+
+            pending.extend(renderer.references.into_inner());
+        }
+
+        let mut output = HEADER.to_string();
+
+        // Project types first, then elmio_core types.
+        let mut modules: Vec<_> = rendered.into_iter().collect();
+        modules.sort_by_key(|(module, _)| (module[0] != self.root, module.clone()));
+
+        for (module, mut declarations) in modules {
+            declarations.sort();
+            output.push('\n');
+
+            let namespace = if module[0] == self.root {
+                module[1..].join(".")
+            } else {
+                module.join(".")
+            };
+
+            if namespace.is_empty() {
+                output.push_str(&declarations.join("\n"));
+                continue;
+            }
+
+            output.push_str(&format!("export namespace {} {{\n", namespace));
+
+            let body = declarations.join("\n");
+            for line in body.lines() {
+                if line.is_empty() {
+                    output.push('\n');
+                } else {
+                    output.push_str(&format!("    {}\n", line));
+                }
+            }
+
+            output.push_str("}\n");
+        }
+
+        warnings.sort();
+
+        Declarations {
+            typescript: output,
+            warnings,
+        }
+    }
+}
+
+struct Renderer<'a> {
+    registry: &'a Registry,
+    def: &'a TypeDef,
+    generics: Vec<String>,
+    references: RefCell<BTreeSet<TypeKey>>,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(registry: &'a Registry, def: &'a TypeDef) -> Renderer<'a> {
+        let generics = def
+            .input
+            .generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(param) => Some(param.ident.to_string()),
+                _ => None,
+            })
+            .collect();
+
+        Renderer {
+            registry,
+            def,
+            generics,
+            references: RefCell::new(BTreeSet::new()),
+        }
+    }
+
+    fn declaration(&self) -> String {
+        let input = &self.def.input;
+        let container = Container::from_attrs(&input.attrs);
+
+        let name = if self.generics.is_empty() {
+            input.ident.unraw().to_string()
+        } else {
+            format!("{}<{}>", input.ident.unraw(), self.generics.join(", "))
+        };
+
+        let body = match (&container.proxy, &input.data) {
+            _ if self.def.manual => "unknown".to_string(),
+
+            (_, Data::Enum(data)) if self.def.port => self.port_body(data),
+
+            (Some(proxy), _) => self.typescript_type(proxy),
+
+            (None, Data::Enum(data)) => {
+                let members: Vec<String> = data
+                    .variants
+                    .iter()
+                    .filter_map(|variant| self.variant(&container, variant))
+                    .collect();
+
+                if members.is_empty() {
+                    "never".to_string()
+                } else {
+                    members
+                        .iter()
+                        .map(|member| format!("\n    | {}", member))
+                        .collect()
+                }
+            }
+
+            (None, Data::Struct(data)) => self.struct_body(&container, &data.fields),
+
+            (None, Data::Union(_)) => "unknown".to_string(),
+        };
+
+        format!("export type {} ={}{};\n", name, separator(&body), body)
+    }
+
+    // Port messages are `{ type, data }` objects, named after the variant in
+    // camelCase, with camelCase keys for the fields of struct variants.
+    fn port_body(&self, data: &DataEnum) -> String {
+        let members: String = data
+            .variants
+            .iter()
+            .map(|variant| {
+                let name = RenameRule::Camel.apply_to_variant(&variant.ident.unraw().to_string());
+
+                let data = match &variant.fields {
+                    Fields::Unit => "null".to_string(),
+                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        self.typescript_type(&fields.unnamed[0].ty)
+                    }
+                    Fields::Unnamed(fields) => {
+                        let types: Vec<String> = fields
+                            .unnamed
+                            .iter()
+                            .map(|field| self.typescript_type(&field.ty))
+                            .collect();
+
+                        format!("[{}]", types.join(", "))
+                    }
+                    Fields::Named(fields) => {
+                        let entries: Vec<String> = fields
+                            .named
+                            .iter()
+                            .filter_map(|field| {
+                                let ident = field.ident.as_ref()?.unraw().to_string();
+                                let key = RenameRule::Camel.apply_to_field(&ident);
+                                Some(format!(
+                                    "{}: {}",
+                                    property(&key),
+                                    self.typescript_type(&field.ty)
+                                ))
+                            })
+                            .collect();
+
+                        inline_object(&entries)
+                    }
+                };
+
+                format!(
+                    "\n    | {{ type: {}; data: {} }}",
+                    string_literal(&name),
+                    data
+                )
+            })
+            .collect();
+
+        if members.is_empty() {
+            "never".to_string()
+        } else {
+            members
+        }
+    }
+
+    fn struct_body(&self, container: &Container, fields: &Fields) -> String {
+        let name = container
+            .rename
+            .clone()
+            .unwrap_or_else(|| self.def.input.ident.unraw().to_string());
+
+        match fields {
+            Fields::Named(_) if container.transparent => self.single_field(fields),
+
+            Fields::Named(fields) => {
+                let tag = container
+                    .tag
+                    .as_ref()
+                    .map(|tag| (tag.clone(), string_literal(&name)));
+
+                let (entries, flattened) =
+                    self.named_fields(container.rename_all, tag, fields.named.iter());
+
+                let object = if entries.is_empty() {
+                    "{}".to_string()
+                } else {
+                    let lines: String = entries
+                        .iter()
+                        .map(|entry| format!("\n    {};", entry))
+                        .collect();
+
+                    format!("{{{}\n}}", lines)
+                };
+
+                intersection(object, flattened)
+            }
+
+            Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => self.single_field(fields),
+
+            Fields::Unnamed(fields) => self.tuple(fields.unnamed.iter()),
+
+            Fields::Unit => "null".to_string(),
+        }
+    }
+
+    fn single_field(&self, fields: &Fields) -> String {
+        match fields.iter().next() {
+            Some(field) => self.field_type(field),
+            None => "null".to_string(),
+        }
+    }
+
+    // Renders a variant the way serde tags it, or `None` when skipped.
+    fn variant(&self, container: &Container, variant: &syn::Variant) -> Option<String> {
+        let attrs = Variant::from_attrs(&variant.attrs);
+
+        if attrs.skip {
+            return None;
+        }
+
+        let name = attrs.rename.clone().unwrap_or_else(|| {
+            let ident = variant.ident.unraw().to_string();
+
+            match container.rename_all {
+                Some(rule) => rule.apply_to_variant(&ident),
+                None => ident,
+            }
+        });
+
+        let rename_fields = attrs.rename_all.or(container.rename_all_fields);
+        let tag = string_literal(&name);
+
+        let content = if attrs.custom {
+            Some("unknown".to_string())
+        } else {
+            match &variant.fields {
+                Fields::Unit => None,
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    Some(self.field_type(&fields.unnamed[0]))
+                }
+                Fields::Unnamed(fields) => Some(self.tuple(fields.unnamed.iter())),
+                Fields::Named(fields) => {
+                    let (entries, flattened) =
+                        self.named_fields(rename_fields, None, fields.named.iter());
+                    Some(intersection(inline_object(&entries), flattened))
+                }
+            }
+        };
+
+        if container.untagged || attrs.untagged {
+            return Some(content.unwrap_or_else(|| "null".to_string()));
+        }
+
+        let member = match (&container.tag, &container.content, content) {
+            (None, _, None) => tag,
+
+            (None, _, Some(content)) => format!("{{ {}: {} }}", property(&name), content),
+
+            (Some(tag_name), _, None) => format!("{{ {}: {} }}", property(tag_name), tag),
+
+            (Some(tag_name), Some(content_name), Some(content)) => format!(
+                "{{ {}: {}; {}: {} }}",
+                property(tag_name),
+                tag,
+                property(content_name),
+                content
+            ),
+
+            // Internally tagged struct variants get the tag as a field.
+            (Some(tag_name), None, Some(_)) if matches!(variant.fields, Fields::Named(_)) => {
+                let Fields::Named(fields) = &variant.fields else {
+                    unreachable!()
+                };
+
+                let (entries, flattened) = self.named_fields(
+                    rename_fields,
+                    Some((tag_name.clone(), tag)),
+                    fields.named.iter(),
+                );
+
+                intersection(inline_object(&entries), flattened)
+            }
+
+            (Some(tag_name), None, Some(content)) => format!(
+                "{{ {}: {} }} & {}",
+                property(tag_name),
+                tag,
+                wrap_union(content)
+            ),
+        };
+
+        Some(member)
+    }
+
+    // Returns the object entries and the types of flattened fields.
+    fn named_fields<'f, I>(
+        &self,
+        rename_all: Option<RenameRule>,
+        tag: Option<(String, String)>,
+        fields: I,
+    ) -> (Vec<String>, Vec<String>)
+    where
+        I: Iterator<Item = &'f syn::Field>,
+    {
+        let mut entries: Vec<String> = tag
+            .map(|(name, value)| format!("{}: {}", property(&name), value))
+            .into_iter()
+            .collect();
+
+        let mut flattened = Vec::new();
+
+        for field in fields {
+            let attrs = Field::from_attrs(&field.attrs);
+
+            if attrs.skip {
+                continue;
+            }
+
+            if attrs.flatten {
+                flattened.push(self.field_type(field));
+                continue;
+            }
+
+            let name = attrs.rename.clone().unwrap_or_else(|| {
+                let ident = field
+                    .ident
+                    .as_ref()
+                    .map(|ident| ident.unraw().to_string())
+                    .unwrap_or_default();
+
+                match rename_all {
+                    Some(rule) => rule.apply_to_field(&ident),
+                    None => ident,
+                }
+            });
+
+            let optional = if attrs.optional { "?" } else { "" };
+
+            entries.push(format!(
+                "{}{}: {}",
+                property(&name),
+                optional,
+                self.field_type(field)
+            ));
+        }
+
+        (entries, flattened)
+    }
+
+    fn tuple<'f, I>(&self, fields: I) -> String
+    where
+        I: Iterator<Item = &'f syn::Field>,
+    {
+        let types: Vec<String> = fields.map(|field| self.field_type(field)).collect();
+        format!("[{}]", types.join(", "))
+    }
+
+    fn field_type(&self, field: &syn::Field) -> String {
+        if Field::from_attrs(&field.attrs).custom {
+            "unknown".to_string()
+        } else {
+            self.typescript_type(&field.ty)
+        }
+    }
+
+    // Types without a TypeScript counterpart are declared as `unknown`.
+    fn typescript_type(&self, ty: &Type) -> String {
+        match ty {
+            Type::Reference(reference) => self.typescript_type(&reference.elem),
+
+            Type::Paren(paren) => self.typescript_type(&paren.elem),
+
+            Type::Group(group) => self.typescript_type(&group.elem),
+
+            Type::Tuple(tuple) if tuple.elems.is_empty() => "null".to_string(),
+
+            Type::Tuple(tuple) => {
+                let types: Vec<String> = tuple
+                    .elems
+                    .iter()
+                    .map(|ty| self.typescript_type(ty))
+                    .collect();
+
+                format!("[{}]", types.join(", "))
+            }
+
+            Type::Slice(slice) => format!("{}[]", wrap_union(self.typescript_type(&slice.elem))),
+
+            Type::Array(array) => format!("{}[]", wrap_union(self.typescript_type(&array.elem))),
+
+            Type::Path(type_path) if type_path.qself.is_none() => self.path_type(&type_path.path),
+
+            _ => "unknown".to_string(),
+        }
+    }
+
+    fn path_type(&self, path: &syn::Path) -> String {
+        let Some(segment) = path.segments.last() else {
+            return "unknown".to_string();
+        };
+
+        let ident = segment.ident.unraw().to_string();
+
+        let args: Vec<&Type> = match &segment.arguments {
+            PathArguments::AngleBracketed(args) => args
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        if path.segments.len() == 1 && self.generics.contains(&ident) {
+            return ident;
+        }
+
+        // Checked before resolving, since `Capture` is declared in elmio_core
+        // with serde written by hand and would be `unknown`.
+        if matches!(ident.as_str(), "Capture" | "Duration")
+            && let Some(builtin) = self.builtin(&ident, &args)
+        {
+            return builtin;
+        }
+
+        let module = &self.def.module;
+
+        if let Some(key) = self.registry.resolve(module, path) {
+            return self.reference(key, &args);
+        }
+
+        if let Some(builtin) = self.builtin(&ident, &args) {
+            return builtin;
+        }
+
+        match self.registry.resolve_reexport(module, path) {
+            Some(key) => self.reference(key, &args),
+            None => "unknown".to_string(),
+        }
+    }
+
+    fn builtin(&self, ident: &str, args: &[&Type]) -> Option<String> {
+        let ty = match (ident, args) {
+            ("String" | "str" | "char" | "Url" | "Uuid", _) => "string".to_string(),
+            ("bool", _) => "boolean".to_string(),
+            (
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64"
+                | "i128" | "isize" | "f32" | "f64",
+                _,
+            ) => "number".to_string(),
+            ("Option", [inner]) => format!("{} | null", self.typescript_type(inner)),
+            ("Vec" | "VecDeque" | "HashSet" | "BTreeSet", [inner]) => {
+                format!("{}[]", wrap_union(self.typescript_type(inner)))
+            }
+            ("HashMap" | "BTreeMap", [_, value]) => {
+                format!("Record<string, {}>", self.typescript_type(value))
+            }
+            ("Result", [ok, err]) => format!(
+                "{{ Ok: {} }} | {{ Err: {} }}",
+                self.typescript_type(ok),
+                self.typescript_type(err)
+            ),
+            ("Box" | "Rc" | "Arc" | "Cow" | "RefCell" | "Cell", [inner]) => {
+                self.typescript_type(inner)
+            }
+            // Captures are replaced with the captured value before the
+            // message is sent back to Rust.
+            ("Capture", [inner]) => self.typescript_type(inner),
+            ("Duration", []) => "{ secs: number; nanos: number }".to_string(),
+            ("PhantomData", _) => "null".to_string(),
+            _ => return None,
+        };
+
+        Some(ty)
+    }
+
+    fn reference(&self, key: &TypeKey, args: &[&Type]) -> String {
+        self.references.borrow_mut().insert(key.clone());
+
+        let name = self.registry.qualified_name(key);
+        let params = self
+            .registry
+            .types
+            .get(key)
+            .map(|def| type_param_count(&def.input))
+            .unwrap_or_default();
+
+        if params == 0 {
+            return name;
+        }
+
+        // Missing arguments, e.g. from elided defaults, become `unknown`.
+        let args: Vec<String> = (0..params)
+            .map(|index| match args.get(index) {
+                Some(arg) => self.typescript_type(arg),
+                None => "unknown".to_string(),
+            })
+            .collect();
+
+        format!("{}<{}>", name, args.join(", "))
+    }
+}
+
+// Maps `src/lib.rs` to the crate root, `src/a/b.rs` and `src/a/b/mod.rs` to
+// `a::b`. Binaries have their own crate, so they are skipped.
+fn module_path(crate_name: &str, src_path: &Path, path: &Path) -> Option<ModulePath> {
+    let relative = path.strip_prefix(src_path).ok()?.with_extension("");
+    let mut segments: Vec<String> = relative
+        .iter()
+        .map(|segment| segment.to_string_lossy().to_string())
+        .collect();
+
+    if segments.first().is_some_and(|first| first == "bin") || segments == ["main"] {
+        return None;
+    }
+
+    if matches!(segments.last().map(String::as_str), Some("lib" | "mod")) {
+        segments.pop();
+    }
+
+    Some([vec![crate_name.to_string()], segments].concat())
+}
+
+fn derives(attrs: &[Attribute], traits: &[&str]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|segment| traits.iter().any(|name| segment.ident == name))
+        })
+}
+
+fn is_serde_impl((_, path, _): &(Option<Token![!]>, syn::Path, Token![for])) -> bool {
+    path.segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Serialize" || segment.ident == "Deserialize")
+}
+
+fn is_cfg_test(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<syn::Ident>()
+                .is_ok_and(|ident| ident == "test")
+    })
+}
+
+fn type_param_count(input: &DeriveInput) -> usize {
+    input
+        .generics
+        .params
+        .iter()
+        .filter(|param| matches!(param, GenericParam::Type(_)))
+        .count()
+}
+
+fn separator(body: &str) -> &'static str {
+    if body.starts_with('\n') { "" } else { " " }
+}
+
+fn inline_object(entries: &[String]) -> String {
+    if entries.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {} }}", entries.join("; "))
+    }
+}
+
+fn intersection(object: String, flattened: Vec<String>) -> String {
+    if flattened.is_empty() {
+        return object;
+    }
+
+    std::iter::once(object)
+        .chain(flattened.into_iter().map(wrap_union))
+        .collect::<Vec<_>>()
+        .join(" & ")
+}
+
+fn wrap_union(ty: String) -> String {
+    if ty.contains(" | ") || ty.contains(" & ") {
+        format!("({})", ty)
+    } else {
+        ty
+    }
+}
+
+fn property(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+    if is_identifier {
+        name.to_string()
+    } else {
+        string_literal(name)
+    }
+}
+
+fn string_literal(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
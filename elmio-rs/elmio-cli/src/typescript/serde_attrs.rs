@@ -0,0 +1,280 @@
+use syn::{Attribute, LitStr, Token, Type, meta::ParseNestedMeta, token};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_str(rule: &str) -> Option<RenameRule> {
+        match rule {
+            "lowercase" => Some(RenameRule::Lower),
+            "UPPERCASE" => Some(RenameRule::Upper),
+            "PascalCase" => Some(RenameRule::Pascal),
+            "camelCase" => Some(RenameRule::Camel),
+            "snake_case" => Some(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(RenameRule::ScreamingSnake),
+            "kebab-case" => Some(RenameRule::Kebab),
+            "SCREAMING-KEBAB-CASE" => Some(RenameRule::ScreamingKebab),
+            _ => None,
+        }
+    }
+
+    // Same rules as serde, which expects variants in PascalCase.
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            RenameRule::Pascal => variant.to_string(),
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Camel => {
+                let mut chars = variant.chars();
+
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            RenameRule::Snake => {
+                let mut snake = String::new();
+
+                for (index, c) in variant.char_indices() {
+                    if index > 0 && c.is_uppercase() {
+                        snake.push('_');
+                    }
+
+                    snake.push(c.to_ascii_lowercase());
+                }
+
+                snake
+            }
+            RenameRule::ScreamingSnake => RenameRule::Snake
+                .apply_to_variant(variant)
+                .to_ascii_uppercase(),
+            RenameRule::Kebab => RenameRule::Snake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+            RenameRule::ScreamingKebab => RenameRule::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+
+    // Same rules as serde, which expects fields in snake_case.
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_string(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+
+                for c in field.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(c);
+                    }
+                }
+
+                pascal
+            }
+            RenameRule::Camel => {
+                let pascal = RenameRule::Pascal.apply_to_field(field);
+                RenameRule::Camel.apply_to_variant(&pascal)
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Container {
+    pub rename: Option<String>,
+    pub rename_all: Option<RenameRule>,
+    pub rename_all_fields: Option<RenameRule>,
+    pub tag: Option<String>,
+    pub content: Option<String>,
+    pub untagged: bool,
+    pub transparent: bool,
+    // The type the value is serialized as, from `into` or `from`.
+    pub proxy: Option<Type>,
+}
+
+impl Container {
+    pub fn from_attrs(attrs: &[Attribute]) -> Container {
+        let mut container = Container::default();
+        let mut from = None;
+
+        parse_serde_attrs(attrs, |meta| {
+            if meta.path.is_ident("rename") {
+                container.rename = Some(renamed(&meta)?);
+            } else if meta.path.is_ident("rename_all") {
+                container.rename_all = rename_rule(&meta)?;
+            } else if meta.path.is_ident("rename_all_fields") {
+                container.rename_all_fields = rename_rule(&meta)?;
+            } else if meta.path.is_ident("tag") {
+                container.tag = Some(string_value(&meta)?);
+            } else if meta.path.is_ident("content") {
+                container.content = Some(string_value(&meta)?);
+            } else if meta.path.is_ident("untagged") {
+                container.untagged = true;
+            } else if meta.path.is_ident("transparent") {
+                container.transparent = true;
+            } else if meta.path.is_ident("into") {
+                container.proxy = Some(type_value(&meta)?);
+            } else if meta.path.is_ident("from") || meta.path.is_ident("try_from") {
+                from = Some(type_value(&meta)?);
+            } else {
+                skip(&meta)?;
+            }
+
+            Ok(())
+        });
+
+        if container.proxy.is_none() {
+            container.proxy = from;
+        }
+
+        container
+    }
+}
+
+#[derive(Default)]
+pub struct Variant {
+    pub rename: Option<String>,
+    pub rename_all: Option<RenameRule>,
+    pub skip: bool,
+    pub untagged: bool,
+    pub custom: bool,
+}
+
+impl Variant {
+    pub fn from_attrs(attrs: &[Attribute]) -> Variant {
+        let mut variant = Variant::default();
+
+        parse_serde_attrs(attrs, |meta| {
+            if meta.path.is_ident("rename") {
+                variant.rename = Some(renamed(&meta)?);
+            } else if meta.path.is_ident("rename_all") {
+                variant.rename_all = rename_rule(&meta)?;
+            } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                variant.skip = true;
+            } else if meta.path.is_ident("untagged") {
+                variant.untagged = true;
+            } else if meta.path.is_ident("with") || meta.path.is_ident("serialize_with") {
+                variant.custom = true;
+                skip(&meta)?;
+            } else {
+                skip(&meta)?;
+            }
+
+            Ok(())
+        });
+
+        variant
+    }
+}
+
+#[derive(Default)]
+pub struct Field {
+    pub rename: Option<String>,
+    pub skip: bool,
+    pub flatten: bool,
+    pub optional: bool,
+    pub custom: bool,
+}
+
+impl Field {
+    pub fn from_attrs(attrs: &[Attribute]) -> Field {
+        let mut field = Field::default();
+
+        parse_serde_attrs(attrs, |meta| {
+            if meta.path.is_ident("rename") {
+                field.rename = Some(renamed(&meta)?);
+            } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                field.skip = true;
+            } else if meta.path.is_ident("flatten") {
+                field.flatten = true;
+            } else if meta.path.is_ident("skip_serializing_if") {
+                field.optional = true;
+                skip(&meta)?;
+            } else if meta.path.is_ident("with") || meta.path.is_ident("serialize_with") {
+                field.custom = true;
+                skip(&meta)?;
+            } else {
+                skip(&meta)?;
+            }
+
+            Ok(())
+        });
+
+        field
+    }
+}
+
+// Attributes serde would reject are ignored, the compiler reports them.
+fn parse_serde_attrs<F>(attrs: &[Attribute], mut f: F)
+where
+    F: FnMut(ParseNestedMeta) -> syn::Result<()>,
+{
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let _ = attr.parse_nested_meta(&mut f);
+    }
+}
+
+fn string_value(meta: &ParseNestedMeta) -> syn::Result<String> {
+    let value: LitStr = meta.value()?.parse()?;
+    Ok(value.value())
+}
+
+fn type_value(meta: &ParseNestedMeta) -> syn::Result<Type> {
+    let value: LitStr = meta.value()?.parse()?;
+    value.parse()
+}
+
+// Reads `name = "x"` or `name(serialize = "x", deserialize = "y")`, where
+// only the serialized name is kept.
+fn renamed(meta: &ParseNestedMeta) -> syn::Result<String> {
+    if !meta.input.peek(token::Paren) {
+        return string_value(meta);
+    }
+
+    let mut name = None;
+
+    meta.parse_nested_meta(|nested| {
+        if nested.path.is_ident("serialize") {
+            name = Some(string_value(&nested)?);
+        } else {
+            skip(&nested)?;
+        }
+
+        Ok(())
+    })?;
+
+    name.ok_or_else(|| meta.error("missing serialize name"))
+}
+
+fn rename_rule(meta: &ParseNestedMeta) -> syn::Result<Option<RenameRule>> {
+    renamed(meta).map(|rule| RenameRule::from_str(&rule))
+}
+
+fn skip(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        let _: syn::Expr = meta.value()?.parse()?;
+    } else if meta.input.peek(token::Paren) {
+        meta.parse_nested_meta(|nested| skip(&nested))?;
+    }
+
+    Ok(())
+}
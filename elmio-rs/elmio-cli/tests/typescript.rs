@@ -0,0 +1,333 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use elmio_cli::typescript::{self, Crate, Declarations};
+
+fn generate(source: &str) -> Declarations {
+    let dir = tempfile::tempdir().unwrap();
+    let src_path = dir.path().join("src");
+    fs::create_dir_all(&src_path).unwrap();
+    fs::write(src_path.join("lib.rs"), source).unwrap();
+
+    let project = Crate {
+        name: "app_core".to_string(),
+        src_path,
+    };
+
+    typescript::generate(&project, &elmio_core(dir.path())).unwrap()
+}
+
+// An elmio_core without sources, so that only the project types are declared.
+fn elmio_core(dir: &Path) -> Crate {
+    Crate {
+        name: "elmio_core".to_string(),
+        src_path: dir.join("elmio_core"),
+    }
+}
+
+// Loads the real elmio_core sources, which declare types like `Capture`
+// with serde written by hand.
+fn generate_with_elmio_core(source: &str) -> Declarations {
+    let dir = tempfile::tempdir().unwrap();
+    let src_path = dir.path().join("src");
+    fs::create_dir_all(&src_path).unwrap();
+    fs::write(src_path.join("lib.rs"), source).unwrap();
+
+    let project = Crate {
+        name: "app_core".to_string(),
+        src_path,
+    };
+
+    let elmio_core = Crate {
+        name: "elmio_core".to_string(),
+        src_path: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../elmio-core/src"),
+    };
+
+    typescript::generate(&project, &elmio_core).unwrap()
+}
+
+fn declarations(source: &str) -> String {
+    generate(source)
+        .typescript
+        .trim_start_matches("// Generated by `elmio gen-types`, do not edit.\n")
+        .to_string()
+}
+
+#[test]
+fn rename_all() {
+    let source = r#"
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct Model {
+            pub user_name: String,
+            #[serde(rename = "ID")]
+            pub id: u32,
+            #[serde(skip)]
+            pub cache: Vec<u8>,
+            pub last_seen: Option<u64>,
+        }
+
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "kebab-case")]
+        pub enum Theme {
+            DarkMode,
+            LightMode,
+        }
+    "#;
+
+    assert_eq!(
+        declarations(source),
+        r#"
+export type Model = {
+    userName: string;
+    ID: number;
+    lastSeen: number | null;
+};
+
+export type Theme =
+    | "dark-mode"
+    | "light-mode";
+"#
+    );
+}
+
+#[test]
+fn tag_and_content() {
+    let source = r#"
+        #[derive(serde::Serialize)]
+        #[serde(tag = "type", content = "config")]
+        #[serde(rename_all = "camelCase")]
+        pub enum Msg {
+            Reset,
+            SetCount(i32),
+            #[serde(rename_all = "camelCase")]
+            Move { from_index: usize, to_index: usize },
+        }
+
+        #[derive(serde::Serialize)]
+        #[serde(tag = "kind")]
+        pub enum Shape {
+            Circle { radius: f64 },
+            Empty,
+        }
+    "#;
+
+    assert_eq!(
+        declarations(source),
+        r#"
+export type Msg =
+    | { type: "reset" }
+    | { type: "setCount"; config: number }
+    | { type: "move"; config: { fromIndex: number; toIndex: number } };
+
+export type Shape =
+    | { kind: "Circle"; radius: number }
+    | { kind: "Empty" };
+"#
+    );
+}
+
+#[test]
+fn externally_tagged_and_untagged() {
+    let source = r#"
+        #[derive(serde::Serialize)]
+        pub enum Event {
+            Click,
+            Key(String),
+            Resize(u32, u32),
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        pub enum Id {
+            Number(u64),
+            Name(String),
+            Missing,
+        }
+    "#;
+
+    assert_eq!(
+        declarations(source),
+        r#"
+export type Event =
+    | "Click"
+    | { Key: string }
+    | { Resize: [number, number] };
+
+export type Id =
+    | number
+    | string
+    | null;
+"#
+    );
+}
+
+#[test]
+fn generics() {
+    let source = r#"
+        #[derive(serde::Serialize)]
+        pub struct Page<T> {
+            pub items: Vec<T>,
+            pub total: usize,
+        }
+
+        #[derive(serde::Serialize)]
+        pub struct Model {
+            pub users: Page<User>,
+            pub tags: Page<Option<String>>,
+        }
+
+        #[derive(serde::Serialize)]
+        pub struct User {
+            pub name: String,
+        }
+    "#;
+
+    assert_eq!(
+        declarations(source),
+        r#"
+export type Model = {
+    users: Page<User>;
+    tags: Page<string | null>;
+};
+
+export type Page<T> = {
+    items: T[];
+    total: number;
+};
+
+export type User = {
+    name: string;
+};
+"#
+    );
+}
+
+#[test]
+fn hand_written_impls_are_unknown_with_a_warning() {
+    let source = r#"
+        pub struct Color(u8, u8, u8);
+
+        impl serde::Serialize for Color {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str("black")
+            }
+        }
+
+        #[derive(serde::Serialize)]
+        pub struct Model {
+            pub color: Color,
+        }
+    "#;
+
+    let generated = generate(source);
+
+    assert!(
+        generated
+            .typescript
+            .contains("export type Color = unknown;")
+    );
+    assert!(generated.typescript.contains("color: Color;"));
+    assert_eq!(
+        generated.warnings,
+        vec!["app_core::Color implements serde by hand and is declared as unknown"]
+    );
+}
+
+#[test]
+fn ports() {
+    let source = r#"
+        #[derive(elmio_macro::IncomingPort)]
+        pub enum FromJs {
+            Logout,
+            SetName(String),
+            Move(u32, u32),
+            Post { author_name: String, theme: Theme },
+        }
+
+        #[derive(elmio_macro::OutgoingPort)]
+        pub enum ToJs {}
+
+        #[derive(serde::Serialize)]
+        #[serde(rename_all = "kebab-case")]
+        pub enum Theme {
+            DarkMode,
+        }
+    "#;
+
+    assert_eq!(
+        declarations(source),
+        r#"
+export type FromJs =
+    | { type: "logout"; data: null }
+    | { type: "setName"; data: string }
+    | { type: "move"; data: [number, number] }
+    | { type: "post"; data: { authorName: string; theme: Theme } };
+
+export type Theme =
+    | "dark-mode";
+
+export type ToJs = never;
+"#
+    );
+}
+
+#[test]
+fn elmio_core_declarations() {
+    let generated = generate_with_elmio_core("");
+
+    assert!(
+        generated
+            .typescript
+            .contains("export namespace elmio_core.browser.effect {")
+    );
+    assert!(generated.typescript.contains("export type Effect<Msg> ="));
+    assert!(
+        generated
+            .typescript
+            .contains("export type Subscription<Msg> =")
+    );
+    assert!(
+        generated
+            .typescript
+            .contains(r#"| { type: "key"; config: string }"#)
+    );
+    assert!(generated.warnings.is_empty());
+}
+
+#[test]
+fn captured_messages_use_the_captured_type() {
+    let source = r#"
+        use elmio_core::browser::effect::http::HttpError;
+        use elmio_core::browser::value::Capture;
+        use std::time::Duration;
+
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(tag = "type", content = "config")]
+        #[serde(rename_all = "camelCase")]
+        pub enum Msg {
+            NameChanged(Capture<String>),
+            GotUser(Capture<Result<String, HttpError>>),
+            Wait(Duration),
+        }
+    "#;
+
+    let generated = generate_with_elmio_core(source);
+
+    assert!(
+        generated
+            .typescript
+            .contains(r#"| { type: "nameChanged"; config: string }"#)
+    );
+    assert!(generated.typescript.contains(
+        r#"| { type: "gotUser"; config: { Ok: string } | { Err: elmio_core.browser.effect.http.HttpError } }"#
+    ));
+    assert!(
+        generated
+            .typescript
+            .contains(r#"| { type: "wait"; config: { secs: number; nanos: number } }"#)
+    );
+    assert!(!generated.typescript.contains("Capture"));
+    assert!(generated.warnings.is_empty());
+}
@@ -8,6 +8,10 @@ pub mod selector;
 pub mod subscription;
 pub mod value;
 
+use std::time::Duration;
+
+/// When a rate limited listener dispatches: on the `leading` edge of a
+/// burst of events, on the `trailing` edge after `delay`, or both.
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DebounceConfig {
@@ -16,6 +20,25 @@ pub struct DebounceConfig {
     trailing: bool,
 }
 
+impl DebounceConfig {
+    /// Dispatches on the trailing edge only.
+    pub fn new(delay: Duration) -> DebounceConfig {
+        DebounceConfig {
+            delay: delay.as_millis() as u32,
+            leading: false,
+            trailing: true,
+        }
+    }
+
+    pub fn leading(self, leading: bool) -> DebounceConfig {
+        DebounceConfig { leading, ..self }
+    }
+
+    pub fn trailing(self, trailing: bool) -> DebounceConfig {
+        DebounceConfig { trailing, ..self }
+    }
+}
+
 #[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WindowSize {
@@ -3,20 +3,28 @@ pub mod clipboard;
 pub mod console;
 pub mod dom;
 pub mod effectful_msg;
+pub mod event_source;
+pub mod http;
 pub mod local_storage;
 pub mod navigation;
+pub mod random;
 pub mod session_storage;
 pub mod time;
+pub mod websocket;
 
 use crate::browser::effect::browser::Browser;
 use crate::browser::effect::clipboard::Clipboard;
 use crate::browser::effect::console::Console;
 use crate::browser::effect::dom::Dom;
 use crate::browser::effect::effectful_msg::EffectfulMsg;
+use crate::browser::effect::event_source::EventSource;
+use crate::browser::effect::http::Request;
 use crate::browser::effect::local_storage::LocalStorage;
 use crate::browser::effect::navigation::Navigation;
+use crate::browser::effect::random::Random;
 use crate::browser::effect::session_storage::SessionStorage;
 use crate::browser::effect::time::Time;
+use crate::browser::effect::websocket::WebSocket;
 use serde_json::json;
 
 #[derive(Clone, serde::Serialize)]
@@ -33,6 +41,10 @@ pub enum Effect<Msg> {
     Console(Console),
     Clipboard(Clipboard),
     Browser(Browser),
+    Http(Request),
+    WebSocket(WebSocket),
+    EventSource(EventSource),
+    Random(Random),
     Custom(serde_json::Value),
     Batch(Vec<Effect<Msg>>),
 }
@@ -45,6 +57,46 @@ impl<Msg> Effect<Msg> {
             _ => vec![self],
         }
     }
+
+    /// Converts every message carried by the effect, e.g. to run the
+    /// effects of a child page from a parent page.
+    pub fn map<ParentMsg, ToMsg>(self, to_msg: ToMsg) -> Effect<ParentMsg>
+    where
+        ToMsg: Fn(Msg) -> ParentMsg,
+    {
+        self.map_with(&to_msg)
+    }
+
+    pub(crate) fn map_with<ParentMsg>(
+        self,
+        to_msg: &dyn Fn(Msg) -> ParentMsg,
+    ) -> Effect<ParentMsg> {
+        match self {
+            Effect::None => Effect::None,
+            Effect::EffectfulMsg(effectful_msg) => {
+                Effect::EffectfulMsg(Box::new(effectful_msg.map_with(to_msg)))
+            }
+            Effect::Dom(dom) => Effect::Dom(dom),
+            Effect::Time(time) => Effect::Time(time),
+            Effect::Navigation(navigation) => Effect::Navigation(navigation),
+            Effect::LocalStorage(local_storage) => Effect::LocalStorage(local_storage),
+            Effect::SessionStorage(session_storage) => Effect::SessionStorage(session_storage),
+            Effect::Console(console) => Effect::Console(console),
+            Effect::Clipboard(clipboard) => Effect::Clipboard(clipboard),
+            Effect::Browser(browser) => Effect::Browser(browser),
+            Effect::Http(request) => Effect::Http(request),
+            Effect::WebSocket(websocket) => Effect::WebSocket(websocket),
+            Effect::EventSource(event_source) => Effect::EventSource(event_source),
+            Effect::Random(random) => Effect::Random(random),
+            Effect::Custom(custom) => Effect::Custom(custom),
+            Effect::Batch(effects) => Effect::Batch(
+                effects
+                    .into_iter()
+                    .map(|effect| effect.map_with(to_msg))
+                    .collect(),
+            ),
+        }
+    }
 }
 
 pub fn none<Msg>() -> Effect<Msg> {
@@ -9,6 +9,12 @@ use crate::browser::effect::effectful_msg::effectful_msg;
 pub enum Browser {
     #[serde(rename_all = "camelCase")]
     SetTimeout { duration: u64 },
+
+    #[serde(rename_all = "camelCase")]
+    SetNamedTimeout { key: String, duration: u64 },
+
+    #[serde(rename_all = "camelCase")]
+    ClearTimeout { key: String },
 }
 
 pub fn set_timeout<Msg>(duration: Duration, msg: Msg) -> Effect<Msg> {
@@ -18,3 +24,23 @@ pub fn set_timeout<Msg>(duration: Duration, msg: Msg) -> Effect<Msg> {
 
     effectful_msg(msg, effect)
 }
+
+/// Like `set_timeout`, but the timer can be cancelled with `clear_timeout`.
+///
+/// Setting a timer with the key of a pending timer replaces it, so the
+/// message of the replaced timer is never sent.
+pub fn set_named_timeout<Msg>(key: &str, duration: Duration, msg: Msg) -> Effect<Msg> {
+    let effect = Effect::Browser(Browser::SetNamedTimeout {
+        key: key.to_string(),
+        duration: duration.as_millis() as u64,
+    });
+
+    effectful_msg(msg, effect)
+}
+
+/// Cancels the pending timer with the given key, if any.
+pub fn clear_timeout<Msg>(key: &str) -> Effect<Msg> {
+    Effect::Browser(Browser::ClearTimeout {
+        key: key.to_string(),
+    })
+}
@@ -1,5 +1,6 @@
 use crate::browser::dom_id::DomId;
 use crate::browser::effect::Effect;
+use crate::browser::event::EventPayloadKind;
 use crate::browser::event::EventTarget;
 use crate::browser::selector::Selector;
 
@@ -34,7 +35,14 @@ pub enum Dom {
     GetFiles {
         element_id: String,
     },
+    #[serde(rename_all = "camelCase")]
+    GetFormData {
+        element_id: String,
+    },
     GetWindowSize,
+    GetEventPayload {
+        kind: EventPayloadKind,
+    },
     #[serde(rename_all = "camelCase")]
     DispatchEvent {
         event_target: EventTarget,
@@ -105,6 +113,25 @@ where
     })
 }
 
+/// Reads the fields of a `<form>` into a JSON object keyed by field name,
+/// to be deserialized into a struct. Names with dots, like `address.city`,
+/// create nested objects.
+///
+/// Text fields, textareas and single selects are read as strings, number
+/// and range inputs as numbers or `null` when empty, radio groups as the
+/// checked value or `null`, multi-selects as arrays of the selected values
+/// and file inputs as arrays of `FileInfo`. Checkboxes with a `value`
+/// attribute are collected into an array of the checked values, other
+/// checkboxes are read as booleans. Disabled fields and buttons are skipped.
+pub fn get_form_data<Msg, Id>(id: Id) -> Effect<Msg>
+where
+    Id: DomId,
+{
+    Effect::Dom(Dom::GetFormData {
+        element_id: id.to_string(),
+    })
+}
+
 pub fn get_target_data_string_value<Msg>(name: &str) -> Effect<Msg> {
     Effect::Dom(Dom::GetTargetDataValue {
         name: name.to_string(),
@@ -151,3 +178,9 @@ pub fn dispatch_element_event<Msg>(id: impl DomId, event_type: &str) -> Effect<M
         cancelable: false,
     })
 }
+
+/// Reads the typed payload of the event that triggered the message, e.g.
+/// `MouseData` for `EventPayloadKind::Mouse`.
+pub fn get_event_payload<Msg>(kind: EventPayloadKind) -> Effect<Msg> {
+    Effect::Dom(Dom::GetEventPayload { kind })
+}
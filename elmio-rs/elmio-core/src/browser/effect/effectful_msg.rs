@@ -7,6 +7,18 @@ pub struct EffectfulMsg<Msg> {
     pub effect: Effect<Msg>,
 }
 
+impl<Msg> EffectfulMsg<Msg> {
+    pub(crate) fn map_with<ParentMsg>(
+        self,
+        to_msg: &dyn Fn(Msg) -> ParentMsg,
+    ) -> EffectfulMsg<ParentMsg> {
+        EffectfulMsg {
+            msg: to_msg(self.msg),
+            effect: self.effect.map_with(to_msg),
+        }
+    }
+}
+
 pub fn effectful_msg<Msg>(msg: Msg, effect: Effect<Msg>) -> Effect<Msg> {
     Effect::EffectfulMsg(Box::new(EffectfulMsg { msg, effect }))
 }
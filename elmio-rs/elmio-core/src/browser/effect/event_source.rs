@@ -0,0 +1,35 @@
+use crate::browser::effect::Effect;
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "config")]
+#[serde(rename_all = "camelCase")]
+pub enum EventSource {
+    /// Reads the data of the server event that triggered the message, or a
+    /// `ServerEvent` when `with_metadata` is set.
+    #[serde(rename_all = "camelCase")]
+    GetEventData {
+        parse_as_json: bool,
+        with_metadata: bool,
+    },
+}
+
+pub fn get_event_string_data<Msg>() -> Effect<Msg> {
+    Effect::EventSource(EventSource::GetEventData {
+        parse_as_json: false,
+        with_metadata: false,
+    })
+}
+
+pub fn get_event_json_data<Msg>() -> Effect<Msg> {
+    Effect::EventSource(EventSource::GetEventData {
+        parse_as_json: true,
+        with_metadata: false,
+    })
+}
+
+pub fn get_server_event<Msg>() -> Effect<Msg> {
+    Effect::EventSource(EventSource::GetEventData {
+        parse_as_json: true,
+        with_metadata: true,
+    })
+}
@@ -0,0 +1,229 @@
+use crate::browser::effect::Effect;
+use crate::browser::effect::effectful_msg::effectful_msg;
+use crate::browser::value;
+use crate::browser::value::Capture;
+use crate::browser::value::Value;
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Request {
+    method: Method,
+    url: String,
+    headers: Vec<(String, String)>,
+    query: Vec<(String, String)>,
+    body: Option<Body>,
+    timeout: Option<u64>,
+    response_type: ResponseType,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+    Options,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "config")]
+#[serde(rename_all = "camelCase")]
+pub enum Body {
+    Json(Value),
+    Text(String),
+    Form(Vec<(String, String)>),
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ResponseType {
+    Json,
+    Text,
+}
+
+/// Why a request did not produce a value.
+///
+/// `Decode` reports a body that is not JSON, or JSON that doesn't match the
+/// type expected by the page.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "config")]
+#[serde(rename_all = "camelCase")]
+pub enum HttpError {
+    Network(String),
+    Timeout,
+    #[serde(rename_all = "camelCase")]
+    BadStatus {
+        status: u16,
+        body: String,
+    },
+    Decode(String),
+}
+
+/// The result of a request sent with `Request::send`. A body that doesn't
+/// match `T` is delivered as `HttpError::Decode`, instead of failing to
+/// decode the whole message.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(into = "Result<T, HttpError>", try_from = "serde_json::Value")]
+#[serde(bound(
+    serialize = "T: Clone + serde::Serialize",
+    deserialize = "T: DeserializeOwned"
+))]
+pub struct HttpResult<T>(Result<T, HttpError>);
+
+impl<T> HttpResult<T> {
+    pub fn as_result(&self) -> &Result<T, HttpError> {
+        &self.0
+    }
+
+    pub fn into_result(self) -> Result<T, HttpError> {
+        self.0
+    }
+}
+
+impl<T> From<Result<T, HttpError>> for HttpResult<T> {
+    fn from(result: Result<T, HttpError>) -> Self {
+        HttpResult(result)
+    }
+}
+
+impl<T> From<HttpResult<T>> for Result<T, HttpError> {
+    fn from(result: HttpResult<T>) -> Self {
+        result.0
+    }
+}
+
+impl<T: DeserializeOwned> TryFrom<serde_json::Value> for HttpResult<T> {
+    type Error = String;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, String> {
+        let result: Result<serde_json::Value, HttpError> =
+            serde_json::from_value(value).map_err(|err| err.to_string())?;
+
+        let result = result.and_then(|body| {
+            serde_json::from_value(body).map_err(|err| HttpError::Decode(err.to_string()))
+        });
+
+        Ok(HttpResult(result))
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HttpError::Network(err) => write!(f, "Network error: {}", err),
+            HttpError::Timeout => write!(f, "Request timed out"),
+            HttpError::BadStatus { status, .. } => write!(f, "Bad status: {}", status),
+            HttpError::Decode(err) => write!(f, "Failed to decode response: {}", err),
+        }
+    }
+}
+
+pub fn get(url: &str) -> Request {
+    request(Method::Get, url)
+}
+
+pub fn post(url: &str) -> Request {
+    request(Method::Post, url)
+}
+
+pub fn put(url: &str) -> Request {
+    request(Method::Put, url)
+}
+
+pub fn patch(url: &str) -> Request {
+    request(Method::Patch, url)
+}
+
+pub fn delete(url: &str) -> Request {
+    request(Method::Delete, url)
+}
+
+pub fn request(method: Method, url: &str) -> Request {
+    Request {
+        method,
+        url: url.to_string(),
+        headers: vec![],
+        query: vec![],
+        body: None,
+        timeout: None,
+        response_type: ResponseType::Json,
+    }
+}
+
+impl Request {
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn query(mut self, name: &str, value: impl ToString) -> Self {
+        self.query.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn json_body<T>(mut self, body: &T) -> Self
+    where
+        T: serde::Serialize,
+    {
+        self.body = Some(Body::Json(value::to_value(body)));
+        self
+    }
+
+    pub fn text_body(mut self, body: &str) -> Self {
+        self.body = Some(Body::Text(body.to_string()));
+        self
+    }
+
+    pub fn form_body(mut self, fields: &[(&str, &str)]) -> Self {
+        let fields = fields
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        self.body = Some(Body::Form(fields));
+        self
+    }
+
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = Some(duration.as_millis() as u64);
+        self
+    }
+
+    /// Sends the request and decodes the response body as JSON.
+    pub fn send<Msg, ToMsg, T>(self, to_msg: ToMsg) -> Effect<Msg>
+    where
+        ToMsg: Fn(Capture<HttpResult<T>>) -> Msg,
+        T: DeserializeOwned,
+    {
+        let msg = to_msg(Capture::new(HttpResult(Err(HttpError::Timeout))));
+        self.send_as(ResponseType::Json, msg)
+    }
+
+    /// Sends the request and captures the response body as text.
+    pub fn send_text<Msg, ToMsg>(self, to_msg: ToMsg) -> Effect<Msg>
+    where
+        ToMsg: Fn(Capture<Result<String, HttpError>>) -> Msg,
+    {
+        let msg = to_msg(Capture::new(Err(HttpError::Timeout)));
+        self.send_as(ResponseType::Text, msg)
+    }
+
+    fn send_as<Msg>(mut self, response_type: ResponseType, msg: Msg) -> Effect<Msg> {
+        self.response_type = response_type;
+        effectful_msg(msg, Effect::Http(self))
+    }
+}
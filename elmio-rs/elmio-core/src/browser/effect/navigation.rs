@@ -7,6 +7,8 @@ pub enum Navigation {
     PushUrl(String),
     ReplaceUrl(String),
     SetLocation(String),
+    GetLocation,
+    GetUrlRequest,
 }
 
 pub fn push_url<Msg>(url: &str) -> Effect<Msg> {
@@ -20,3 +22,13 @@ pub fn replace_url<Msg>(url: &str) -> Effect<Msg> {
 pub fn set_location<Msg>(url: &str) -> Effect<Msg> {
     Effect::Navigation(Navigation::SetLocation(url.to_string()))
 }
+
+/// Resolves to a `Location` with the current URL and history state.
+pub fn get_location<Msg>() -> Effect<Msg> {
+    Effect::Navigation(Navigation::GetLocation)
+}
+
+/// Resolves to a `UrlRequest` for the link closest to the event target.
+pub fn get_url_request<Msg>() -> Effect<Msg> {
+    Effect::Navigation(Navigation::GetUrlRequest)
+}
@@ -0,0 +1,31 @@
+use crate::browser::effect::Effect;
+use crate::browser::effect::effectful_msg::effectful_msg;
+use crate::browser::value::Capture;
+use crate::random::Seed;
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "config")]
+#[serde(rename_all = "camelCase")]
+pub enum Random {
+    GetSeed,
+    GetBytes { length: u32 },
+}
+
+/// Gets a seed from `crypto.getRandomValues`, to be stepped with
+/// `random::step` in `update`.
+pub fn new_seed<Msg, ToMsg>(to_msg: ToMsg) -> Effect<Msg>
+where
+    ToMsg: Fn(Capture<Seed>) -> Msg,
+{
+    let msg = to_msg(Default::default());
+    effectful_msg(msg, Effect::Random(Random::GetSeed))
+}
+
+/// Gets `length` cryptographically secure random bytes.
+pub fn get_bytes<Msg, ToMsg>(length: u32, to_msg: ToMsg) -> Effect<Msg>
+where
+    ToMsg: Fn(Capture<Vec<u8>>) -> Msg,
+{
+    let msg = to_msg(Default::default());
+    effectful_msg(msg, Effect::Random(Random::GetBytes { length }))
+}
@@ -5,8 +5,20 @@ use crate::browser::effect::Effect;
 #[serde(rename_all = "camelCase")]
 pub enum Time {
     CurrentTime,
+    /// Gets the `Zone` of the user.
+    Here,
+    /// Reads the `Frame` of the animation frame that triggered the message.
+    GetAnimationFrame,
 }
 
 pub fn current_time<Msg>() -> Effect<Msg> {
     Effect::Time(Time::CurrentTime)
 }
+
+pub fn here<Msg>() -> Effect<Msg> {
+    Effect::Time(Time::Here)
+}
+
+pub fn get_animation_frame<Msg>() -> Effect<Msg> {
+    Effect::Time(Time::GetAnimationFrame)
+}
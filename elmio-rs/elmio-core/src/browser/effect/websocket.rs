@@ -0,0 +1,40 @@
+use crate::browser::effect::Effect;
+use crate::browser::value;
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "config")]
+#[serde(rename_all = "camelCase")]
+pub enum WebSocket {
+    #[serde(rename_all = "camelCase")]
+    Send { url: String, data: String },
+    #[serde(rename_all = "camelCase")]
+    GetMessageData { parse_as_json: bool },
+}
+
+/// Sends `data` on the socket opened by `subscription::websocket::listen`
+/// for `url`. Data sent while the socket is connecting is queued.
+pub fn send<Msg>(url: &str, data: &str) -> Effect<Msg> {
+    Effect::WebSocket(WebSocket::Send {
+        url: url.to_string(),
+        data: data.to_string(),
+    })
+}
+
+pub fn send_json<Msg, T>(url: &str, data: &T) -> Effect<Msg>
+where
+    T: serde::Serialize,
+{
+    send(url, &value::to_value(data).to_string())
+}
+
+pub fn get_message_string_data<Msg>() -> Effect<Msg> {
+    Effect::WebSocket(WebSocket::GetMessageData {
+        parse_as_json: false,
+    })
+}
+
+pub fn get_message_json_data<Msg>() -> Effect<Msg> {
+    Effect::WebSocket(WebSocket::GetMessageData {
+        parse_as_json: true,
+    })
+}
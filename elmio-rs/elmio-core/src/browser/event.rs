@@ -1,3 +1,6 @@
+use crate::browser::mouse::Button;
+use std::collections::BTreeMap;
+
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "type", content = "config")]
 #[serde(rename_all = "camelCase")]
@@ -9,3 +12,147 @@ pub enum EventTarget {
         element_id: String,
     },
 }
+
+/// The payload read from the event that triggered a listener, see
+/// `dom::get_event_payload`.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EventPayloadKind {
+    Mouse,
+    Pointer,
+    Wheel,
+    Touch,
+    Focus,
+    Scroll,
+}
+
+/// Modifier keys held down when the event fired.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Modifiers {
+    pub alt: bool,
+    pub ctrl: bool,
+    pub meta: bool,
+    pub shift: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MouseData {
+    /// Position relative to the viewport.
+    pub client_x: f64,
+    pub client_y: f64,
+
+    /// Position relative to the document, including the scroll offset.
+    pub page_x: f64,
+    pub page_y: f64,
+
+    /// Position relative to the padding edge of the target element.
+    pub offset_x: f64,
+    pub offset_y: f64,
+
+    /// The button that changed state, `None` for moves and unknown buttons.
+    pub button: Option<Button>,
+
+    /// The buttons held down when the event fired.
+    pub buttons: Vec<Button>,
+
+    pub modifiers: Modifiers,
+
+    /// The `data-*` attributes of the target and its ancestors, keyed
+    /// without the `data-` prefix. The nearest element wins.
+    pub data: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PointerType {
+    #[default]
+    Mouse,
+    Pen,
+    Touch,
+    Unknown,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PointerData {
+    #[serde(flatten)]
+    pub mouse: MouseData,
+    pub pointer_id: i32,
+    pub pointer_type: PointerType,
+    pub is_primary: bool,
+
+    /// Normalized pressure, from 0 to 1.
+    pub pressure: f64,
+
+    /// Size of the contact geometry, in CSS pixels.
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DeltaMode {
+    #[default]
+    Pixel,
+    Line,
+    Page,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WheelData {
+    #[serde(flatten)]
+    pub mouse: MouseData,
+    pub delta_x: f64,
+    pub delta_y: f64,
+    pub delta_z: f64,
+    pub delta_mode: DeltaMode,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TouchPoint {
+    pub identifier: i32,
+    pub client_x: f64,
+    pub client_y: f64,
+    pub page_x: f64,
+    pub page_y: f64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TouchData {
+    /// Every touch point currently on the surface.
+    pub touches: Vec<TouchPoint>,
+
+    /// The touch points that changed in this event, e.g. the lifted finger
+    /// of a `touchend`.
+    pub changed_touches: Vec<TouchPoint>,
+
+    pub modifiers: Modifiers,
+    pub data: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusData {
+    /// The id of the element losing focus on `focus`, or gaining it on
+    /// `blur`.
+    pub related_id: Option<String>,
+    pub data: BTreeMap<String, String>,
+}
+
+/// Scroll position of the target element, or of the document for window
+/// scroll listeners.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrollData {
+    pub scroll_top: f64,
+    pub scroll_left: f64,
+    pub scroll_width: f64,
+    pub scroll_height: f64,
+    pub client_width: f64,
+    pub client_height: f64,
+}
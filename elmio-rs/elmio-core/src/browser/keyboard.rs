@@ -1,9 +1,54 @@
+use crate::browser::event::Modifiers;
 use serde::Deserialize;
 use serde::Serialize;
 use std::fmt;
 use std::str::FromStr;
+use std::time::Duration;
 
-#[derive(Clone)]
+// Defines an enum of W3C values named after the values themselves, with
+// conversions from and to the value strings.
+macro_rules! w3c_values {
+    ($(#[$attr:meta])* $vis:vis enum $name:ident { $($variant:ident),* $(,)? }) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        $vis enum $name {
+            $($variant),*
+        }
+
+        impl $name {
+            pub const ALL: &'static [$name] = &[$($name::$variant),*];
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => stringify!($variant)),*
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(stringify!($variant) => Ok($name::$variant),)*
+                    _ => Err(format!("Unknown {} '{}'", stringify!($name), s)),
+                }
+            }
+        }
+    };
+}
+
+/// A key matched by `on_keydown` and `on_keyup`, compared case insensitively
+/// with `KeyboardEvent.code`, e.g. `Key::Key("KeyA".to_string())`. Use a
+/// `Chord` to match the character a key produces instead.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", from = "String")]
 pub enum Key {
     Any,
     Escape,
@@ -11,22 +56,132 @@ pub enum Key {
     Key(String),
 }
 
-impl Serialize for Key {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::ser::Serializer,
-    {
-        serializer.serialize_str(&self.to_string())
+w3c_values! {
+    /// The non-printable values of `KeyboardEvent.key`, from the
+    /// "UI Events KeyboardEvent key Values" specification.
+    pub enum NamedKey {
+        Unidentified,
+        // Modifier keys.
+        Alt, AltGraph, CapsLock, Control, Fn, FnLock, Hyper, Meta, NumLock,
+        ScrollLock, Shift, Super, Symbol, SymbolLock,
+        // Whitespace keys.
+        Enter, Tab,
+        // Navigation keys.
+        ArrowDown, ArrowLeft, ArrowRight, ArrowUp, End, Home, PageDown, PageUp,
+        // Editing keys.
+        Backspace, Clear, Copy, CrSel, Cut, Delete, EraseEof, ExSel, Insert,
+        Paste, Redo, Undo,
+        // UI keys.
+        Accept, Again, Attn, Cancel, ContextMenu, Escape, Execute, Find, Finish,
+        Help, Pause, Play, Props, Select, ZoomIn, ZoomOut,
+        // Device keys.
+        BrightnessDown, BrightnessUp, Eject, LogOff, Power, PowerOff,
+        PrintScreen, Hibernate, Standby, WakeUp,
+        // IME and composition keys.
+        AllCandidates, Alphanumeric, CodeInput, Compose, Convert, Dead,
+        FinalMode, GroupFirst, GroupLast, GroupNext, GroupPrevious, ModeChange,
+        NextCandidate, NonConvert, PreviousCandidate, Process, SingleCandidate,
+        HangulMode, HanjaMode, JunjaMode, Eisu, Hankaku, Hiragana,
+        HiraganaKatakana, KanaMode, KanjiMode, Katakana, Romaji, Zenkaku,
+        ZenkakuHankaku,
+        // Function keys.
+        F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12, F13, F14, F15, F16,
+        F17, F18, F19, F20, F21, F22, F23, F24, Soft1, Soft2, Soft3, Soft4,
+        // Phone keys.
+        AppSwitch, Call, Camera, CameraFocus, EndCall, GoBack, GoHome,
+        HeadsetHook, LastNumberRedial, Notification, MannerMode, VoiceDial,
+        // Multimedia keys.
+        ChannelDown, ChannelUp, MediaFastForward, MediaPause, MediaPlay,
+        MediaPlayPause, MediaRecord, MediaRewind, MediaStop, MediaTrackNext,
+        MediaTrackPrevious,
+        // Audio keys.
+        AudioBalanceLeft, AudioBalanceRight, AudioBassBoostDown,
+        AudioBassBoostToggle, AudioBassBoostUp, AudioFaderFront, AudioFaderRear,
+        AudioSurroundModeNext, AudioTrebleDown, AudioTrebleUp, AudioVolumeDown,
+        AudioVolumeUp, AudioVolumeMute, MicrophoneToggle, MicrophoneVolumeDown,
+        MicrophoneVolumeUp, MicrophoneVolumeMute,
+        // Application keys.
+        LaunchApplication1, LaunchApplication2, LaunchCalendar, LaunchContacts,
+        LaunchMail, LaunchMediaPlayer, LaunchMusicPlayer, LaunchPhone,
+        LaunchScreenSaver, LaunchSpreadsheet, LaunchWebBrowser, LaunchWebCam,
+        LaunchWordProcessor,
+        // Browser keys.
+        BrowserBack, BrowserFavorites, BrowserForward, BrowserHome,
+        BrowserRefresh, BrowserSearch, BrowserStop,
+        // Document keys.
+        Close, New, Open, Print, Save, SpellCheck, MailForward, MailReply,
+        MailSend,
+    }
+}
+
+w3c_values! {
+    /// A value of `KeyboardEvent.code`: the physical key, named after what
+    /// it produces on a US keyboard, regardless of the layout in use.
+    pub enum Code {
+        Unidentified,
+        // Writing system keys.
+        Backquote, Backslash, BracketLeft, BracketRight, Comma, Digit0, Digit1,
+        Digit2, Digit3, Digit4, Digit5, Digit6, Digit7, Digit8, Digit9, Equal,
+        IntlBackslash, IntlRo, IntlYen, KeyA, KeyB, KeyC, KeyD, KeyE, KeyF,
+        KeyG, KeyH, KeyI, KeyJ, KeyK, KeyL, KeyM, KeyN, KeyO, KeyP, KeyQ, KeyR,
+        KeyS, KeyT, KeyU, KeyV, KeyW, KeyX, KeyY, KeyZ, Minus, Period, Quote,
+        Semicolon, Slash,
+        // Functional keys.
+        AltLeft, AltRight, Backspace, CapsLock, ContextMenu, ControlLeft,
+        ControlRight, Enter, MetaLeft, MetaRight, ShiftLeft, ShiftRight, Space,
+        Tab, Convert, KanaMode, Lang1, Lang2, Lang3, Lang4, Lang5, NonConvert,
+        // Control pad.
+        Delete, End, Help, Home, Insert, PageDown, PageUp,
+        // Arrow pad.
+        ArrowDown, ArrowLeft, ArrowRight, ArrowUp,
+        // Numpad.
+        NumLock, Numpad0, Numpad1, Numpad2, Numpad3, Numpad4, Numpad5, Numpad6,
+        Numpad7, Numpad8, Numpad9, NumpadAdd, NumpadBackspace, NumpadClear,
+        NumpadClearEntry, NumpadComma, NumpadDecimal, NumpadDivide, NumpadEnter,
+        NumpadEqual, NumpadHash, NumpadMemoryAdd, NumpadMemoryClear,
+        NumpadMemoryRecall, NumpadMemoryStore, NumpadMemorySubtract,
+        NumpadMultiply, NumpadParenLeft, NumpadParenRight, NumpadStar,
+        NumpadSubtract,
+        // Function section.
+        Escape, F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12, F13, F14,
+        F15, F16, F17, F18, F19, F20, F21, F22, F23, F24, Fn, FnLock,
+        PrintScreen, ScrollLock, Pause,
+        // Media keys.
+        BrowserBack, BrowserFavorites, BrowserForward, BrowserHome,
+        BrowserRefresh, BrowserSearch, BrowserStop, Eject, LaunchApp1,
+        LaunchApp2, LaunchMail, MediaPlayPause, MediaSelect, MediaStop,
+        MediaTrackNext, MediaTrackPrevious, Power, Sleep, AudioVolumeDown,
+        AudioVolumeMute, AudioVolumeUp, WakeUp,
+        // Legacy and non-standard keys.
+        Hyper, Super, Turbo, Abort, Resume, Suspend, Again, Copy, Cut, Find,
+        Open, Paste, Props, Select, Undo, Hiragana, Katakana,
+    }
+}
+
+impl Key {
+    /// Whether the `KeyboardEvent.code` value `code` matches.
+    pub fn matches(&self, code: &str) -> bool {
+        match self {
+            Key::Any => true,
+            _ => self.to_string().to_lowercase() == code.to_lowercase(),
+        }
+    }
+}
+
+impl From<Key> for String {
+    fn from(key: Key) -> String {
+        key.to_string()
     }
 }
 
-impl<'de> Deserialize<'de> for Key {
-    fn deserialize<D>(deserializer: D) -> Result<Key, D::Error>
-    where
-        D: serde::de::Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        Key::from_str(&s).map_err(serde::de::Error::custom)
+impl From<String> for Key {
+    fn from(s: String) -> Key {
+        match s.as_str() {
+            "any" => Key::Any,
+            "escape" => Key::Escape,
+            "enter" => Key::Enter,
+            _ => Key::Key(s),
+        }
     }
 }
 
@@ -54,11 +209,302 @@ impl FromStr for Key {
     type Err = ParseKeyError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "any" => Key::Any,
-            "escape" => Key::Escape,
-            "enter" => Key::Enter,
-            _ => Key::Key(s.to_string()),
-        })
+        Ok(Key::from(s.to_string()))
+    }
+}
+
+/// The key a chord is made of, matched by the produced key or by the
+/// physical key.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "KeyPressValue", try_from = "KeyPressValue")]
+pub enum KeyPress {
+    /// A printable value of `KeyboardEvent.key`, e.g. `"k"` or `" "` for
+    /// the space bar. The case is ignored, since Shift is a modifier.
+    Character(String),
+    /// A non-printable value of `KeyboardEvent.key`.
+    Named(NamedKey),
+    /// A value of `KeyboardEvent.code`.
+    Code(Code),
+}
+
+impl KeyPress {
+    /// Whether a key event with the given `KeyboardEvent.key` and `code`
+    /// presses the key.
+    pub fn matches(&self, key: &str, code: &str) -> bool {
+        match self {
+            KeyPress::Character(c) => c.to_lowercase() == key.to_lowercase(),
+            KeyPress::Named(named) => named.as_str() == key,
+            KeyPress::Code(expected) => expected.as_str() == code,
+        }
+    }
+
+    // Characters that are not letters, like `?`, may need Shift to be
+    // typed, so a chord only checks Shift for them when it requires it.
+    pub(crate) fn is_shifted_symbol(&self) -> bool {
+        match self {
+            KeyPress::Character(c) => c.to_lowercase() == c.to_uppercase() && c != " ",
+            _ => false,
+        }
+    }
+}
+
+// The wire format of `KeyPress`, with both kinds of `KeyboardEvent.key`
+// values sent as `key`.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "config")]
+#[serde(rename_all = "camelCase")]
+enum KeyPressValue {
+    Key(String),
+    Code(String),
+}
+
+impl From<KeyPress> for KeyPressValue {
+    fn from(key: KeyPress) -> KeyPressValue {
+        match key {
+            KeyPress::Character(c) => KeyPressValue::Key(c),
+            KeyPress::Named(named) => KeyPressValue::Key(named.to_string()),
+            KeyPress::Code(code) => KeyPressValue::Code(code.to_string()),
+        }
+    }
+}
+
+impl TryFrom<KeyPressValue> for KeyPress {
+    type Error = String;
+
+    fn try_from(value: KeyPressValue) -> Result<KeyPress, String> {
+        match value {
+            KeyPressValue::Key(key) if key.is_empty() => Err("Empty key".to_string()),
+            KeyPressValue::Key(key) => Ok(match key.parse() {
+                Ok(named) => KeyPress::Named(named),
+                Err(_) => KeyPress::Character(key),
+            }),
+            KeyPressValue::Code(code) => code.parse().map(KeyPress::Code),
+        }
+    }
+}
+
+/// A key pressed while holding exactly `modifiers`, e.g. `Ctrl+Shift+K`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Chord {
+    pub key: KeyPress,
+    pub modifiers: Modifiers,
+}
+
+impl Chord {
+    pub fn new(key: KeyPress) -> Chord {
+        Chord {
+            key,
+            modifiers: Modifiers::default(),
+        }
+    }
+
+    pub fn character(c: char) -> Chord {
+        Chord::new(KeyPress::Character(c.to_string()))
+    }
+
+    pub fn named(key: NamedKey) -> Chord {
+        Chord::new(KeyPress::Named(key))
+    }
+
+    pub fn code(code: Code) -> Chord {
+        Chord::new(KeyPress::Code(code))
+    }
+
+    pub fn alt(self) -> Chord {
+        self.with_modifiers(|modifiers| modifiers.alt = true)
+    }
+
+    pub fn ctrl(self) -> Chord {
+        self.with_modifiers(|modifiers| modifiers.ctrl = true)
+    }
+
+    pub fn meta(self) -> Chord {
+        self.with_modifiers(|modifiers| modifiers.meta = true)
+    }
+
+    pub fn shift(self) -> Chord {
+        self.with_modifiers(|modifiers| modifiers.shift = true)
+    }
+
+    /// Parses a chord like `Ctrl+Shift+K`, `Alt+ArrowUp` or `?`.
+    ///
+    /// Modifier names are case insensitive: `Ctrl` or `Control`, `Alt` or
+    /// `Option`, `Shift`, and `Meta`, `Cmd` or `Command`. Keys are single
+    /// characters, `Space`, `Plus` or `KeyboardEvent.key` names like
+    /// `Escape`.
+    pub fn parse(chord: &str) -> Result<Chord, String> {
+        // `Ctrl++` ends with the plus key itself.
+        let (chord, plus) = match chord.strip_suffix("++") {
+            Some(rest) => (rest, true),
+            None if chord == "+" => ("", true),
+            None => (chord, false),
+        };
+
+        let mut parts: Vec<&str> = if chord.is_empty() {
+            Vec::new()
+        } else {
+            chord.split('+').collect()
+        };
+
+        let key = if plus {
+            KeyPress::Character("+".to_string())
+        } else {
+            let key = parts
+                .pop()
+                .ok_or_else(|| format!("Invalid chord '{}': missing key", chord))?;
+
+            parse_key(key).ok_or_else(|| format!("Invalid chord '{}': unknown key", chord))?
+        };
+
+        let mut modifiers = Modifiers::default();
+
+        for part in parts {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => modifiers.ctrl = true,
+                "alt" | "option" => modifiers.alt = true,
+                "shift" => modifiers.shift = true,
+                "meta" | "cmd" | "command" => modifiers.meta = true,
+                _ => {
+                    return Err(format!(
+                        "Invalid chord '{}': unknown modifier '{}'",
+                        chord, part
+                    ));
+                }
+            }
+        }
+
+        Ok(Chord { key, modifiers })
+    }
+
+    /// Whether a key event with the given `KeyboardEvent.key` and `code`,
+    /// fired while holding `held`, presses the chord. Modifiers the chord
+    /// does not require must be released.
+    pub fn matches(&self, key: &str, code: &str, held: &Modifiers) -> bool {
+        let ignore_shift = self.key.is_shifted_symbol();
+
+        let modifier_matches = |required: bool, held: bool, ignored: bool| {
+            if required { held } else { ignored || !held }
+        };
+
+        self.key.matches(key, code)
+            && modifier_matches(self.modifiers.alt, held.alt, false)
+            && modifier_matches(self.modifiers.ctrl, held.ctrl, false)
+            && modifier_matches(self.modifiers.meta, held.meta, false)
+            && modifier_matches(self.modifiers.shift, held.shift, ignore_shift)
+    }
+
+    fn with_modifiers(mut self, f: impl FnOnce(&mut Modifiers)) -> Chord {
+        f(&mut self.modifiers);
+        self
+    }
+}
+
+impl fmt::Display for Chord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let modifiers = [
+            (self.modifiers.ctrl, "Ctrl"),
+            (self.modifiers.alt, "Alt"),
+            (self.modifiers.shift, "Shift"),
+            (self.modifiers.meta, "Meta"),
+        ];
+
+        for (_, name) in modifiers.iter().filter(|(held, _)| *held) {
+            write!(f, "{}+", name)?;
+        }
+
+        match &self.key {
+            KeyPress::Character(c) if c == " " => write!(f, "Space"),
+            KeyPress::Character(c) if c == "+" => write!(f, "Plus"),
+            KeyPress::Character(c) => write!(f, "{}", c.to_uppercase()),
+            KeyPress::Named(key) => write!(f, "{}", key),
+            KeyPress::Code(code) => write!(f, "{}", code),
+        }
     }
 }
+
+/// One or more chords pressed in order, e.g. `g g`, each within `timeout`
+/// of the previous one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shortcut {
+    pub chords: Vec<Chord>,
+    pub timeout: Duration,
+}
+
+impl Shortcut {
+    pub fn new(chord: Chord) -> Shortcut {
+        Shortcut {
+            chords: vec![chord],
+            timeout: Duration::from_secs(1),
+        }
+    }
+
+    pub fn then(mut self, chord: Chord) -> Shortcut {
+        self.chords.push(chord);
+        self
+    }
+
+    pub fn timeout(self, timeout: Duration) -> Shortcut {
+        Shortcut { timeout, ..self }
+    }
+
+    /// Parses space separated chords, e.g. `Ctrl+K Ctrl+S` or `g g`.
+    pub fn parse(shortcut: &str) -> Result<Shortcut, String> {
+        let chords = shortcut
+            .split_whitespace()
+            .map(Chord::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut chords = chords.into_iter();
+        let first = chords
+            .next()
+            .ok_or_else(|| "Invalid shortcut: no chords".to_string())?;
+
+        Ok(chords.fold(Shortcut::new(first), Shortcut::then))
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let chords: Vec<String> = self.chords.iter().map(|chord| chord.to_string()).collect();
+        write!(f, "{}", chords.join(" "))
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Shortcut::parse(s)
+    }
+}
+
+// Single characters are lowercased, since Shift is written as a modifier.
+fn parse_key(key: &str) -> Option<KeyPress> {
+    let mut chars = key.chars();
+
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyPress::Character(c.to_lowercase().to_string()));
+    }
+
+    let key = match key.to_ascii_lowercase().as_str() {
+        "space" => KeyPress::Character(" ".to_string()),
+        "plus" => KeyPress::Character("+".to_string()),
+        "esc" => KeyPress::Named(NamedKey::Escape),
+        "return" => KeyPress::Named(NamedKey::Enter),
+        "up" => KeyPress::Named(NamedKey::ArrowUp),
+        "down" => KeyPress::Named(NamedKey::ArrowDown),
+        "left" => KeyPress::Named(NamedKey::ArrowLeft),
+        "right" => KeyPress::Named(NamedKey::ArrowRight),
+        "del" => KeyPress::Named(NamedKey::Delete),
+        lowercase => {
+            let named = NamedKey::ALL
+                .iter()
+                .find(|named| named.as_str().to_ascii_lowercase() == lowercase)?;
+
+            KeyPress::Named(*named)
+        }
+    };
+
+    Some(key)
+}
@@ -1,28 +1,743 @@
 use std::fmt;
 
-#[derive(Clone, serde::Serialize, serde::Deserialize)]
-pub struct Selector(String);
+/// A CSS selector list, built from typed parts so identifiers and values are
+/// always escaped.
+///
+/// Selectors are sent to JavaScript as strings, and can be parsed back with
+/// `Selector::parse` to validate hand-written selectors.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Selector(pub(crate) Vec<Complex>);
+
+// Compounds from left to right, each paired with the combinator that links
+// it to the compound on its left.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Complex(pub(crate) Vec<(Combinator, Compound)>);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Combinator {
+    None,
+    Descendant,
+    Child,
+    NextSibling,
+    SubsequentSibling,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Compound {
+    pub(crate) tag: Option<String>,
+    pub(crate) conditions: Vec<Condition>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Condition {
+    Id(String),
+    Class(String),
+    Attribute {
+        name: String,
+        operator: Option<(AttributeOperator, String)>,
+        ignore_case: bool,
+    },
+    PseudoClass(PseudoClass),
+    Not(Selector),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AttributeOperator {
+    /// `[name="value"]`
+    Equals,
+    /// `[name~="value"]`, one of the whitespace separated words.
+    Includes,
+    /// `[name|="value"]`, exactly `value` or starting with `value-`.
+    DashMatch,
+    /// `[name^="value"]`
+    Prefix,
+    /// `[name$="value"]`
+    Suffix,
+    /// `[name*="value"]`
+    Substring,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PseudoClass {
+    /// `:checked`, checked inputs and selected options.
+    Checked,
+    /// `:disabled`, form controls with the `disabled` attribute.
+    Disabled,
+    /// `:enabled`, form controls without the `disabled` attribute.
+    Enabled,
+    /// `:required`
+    Required,
+    /// `:empty`, elements without children.
+    Empty,
+    /// `:first-child`
+    FirstChild,
+    /// `:last-child`
+    LastChild,
+    /// `:only-child`
+    OnlyChild,
+    /// `:first-of-type`
+    FirstOfType,
+    /// `:last-of-type`
+    LastOfType,
+}
 
 impl Selector {
+    pub fn parse(selector: &str) -> Result<Selector, String> {
+        let mut parser = Parser {
+            input: selector,
+            pos: 0,
+        };
+
+        parser
+            .parse_selector()
+            .map_err(|err| format!("Invalid selector '{}': {}", selector, err))
+    }
+
+    /// Matches every element, `*`.
+    pub fn any() -> Selector {
+        Selector::compound(Compound::default())
+    }
+
     pub fn id(id: &str) -> Selector {
-        Selector(format!("#{}", id))
+        Selector::any().with_id(id)
+    }
+
+    pub fn class(name: &str) -> Selector {
+        Selector::any().with_class(name)
+    }
+
+    pub fn tag(name: &str) -> Selector {
+        Selector::compound(Compound {
+            tag: Some(name.to_ascii_lowercase()),
+            conditions: Vec::new(),
+        })
+    }
+
+    pub fn attribute(name: &str) -> Selector {
+        Selector::any().with_attribute(name)
+    }
+
+    pub fn attribute_value(name: &str, operator: AttributeOperator, value: &str) -> Selector {
+        Selector::any().with_attribute_value(name, operator, value)
+    }
+
+    /// Matches the elements matching any of `selectors`. An empty list
+    /// matches no element, `:not(*)`.
+    pub fn list(selectors: Vec<Selector>) -> Selector {
+        if selectors.is_empty() {
+            return Selector::any().not(Selector::any());
+        }
+
+        Selector(
+            selectors
+                .into_iter()
+                .flat_map(|selector| selector.0)
+                .collect(),
+        )
     }
 
     pub fn radio_group(name: &str) -> Selector {
-        Selector(format!("input[type=radio][name={}]", name))
+        Selector::tag("input")
+            .with_attribute_value("type", AttributeOperator::Equals, "radio")
+            .with_attribute_value("name", AttributeOperator::Equals, name)
+    }
+
+    pub fn links(id: &str) -> Selector {
+        Selector::id(id).descendant(Selector::tag("a").with_attribute("href"))
     }
 
     pub fn data(name: &str) -> Selector {
-        Selector(format!("[data-{}]", name))
+        Selector::attribute(&format!("data-{}", name))
     }
 
     pub fn data_value(key: &str, value: &str) -> Selector {
-        Selector(format!("[data-{}=\"{}\"]", key, value))
+        Selector::attribute_value(&format!("data-{}", key), AttributeOperator::Equals, value)
+    }
+
+    pub fn with_id(self, id: &str) -> Selector {
+        self.with_condition(Condition::Id(id.to_string()))
+    }
+
+    pub fn with_class(self, name: &str) -> Selector {
+        self.with_condition(Condition::Class(name.to_string()))
+    }
+
+    pub fn with_attribute(self, name: &str) -> Selector {
+        self.with_condition(Condition::Attribute {
+            name: name.to_string(),
+            operator: None,
+            ignore_case: false,
+        })
+    }
+
+    pub fn with_attribute_value(
+        self,
+        name: &str,
+        operator: AttributeOperator,
+        value: &str,
+    ) -> Selector {
+        self.with_condition(Condition::Attribute {
+            name: name.to_string(),
+            operator: Some((operator, value.to_string())),
+            ignore_case: false,
+        })
+    }
+
+    /// Like `with_attribute_value`, comparing the value case-insensitively,
+    /// e.g. `[type="email" i]`.
+    pub fn with_attribute_value_ignore_case(
+        self,
+        name: &str,
+        operator: AttributeOperator,
+        value: &str,
+    ) -> Selector {
+        self.with_condition(Condition::Attribute {
+            name: name.to_string(),
+            operator: Some((operator, value.to_string())),
+            ignore_case: true,
+        })
+    }
+
+    pub fn with_pseudo_class(self, pseudo_class: PseudoClass) -> Selector {
+        self.with_condition(Condition::PseudoClass(pseudo_class))
+    }
+
+    /// Excludes the elements matching `selector`, e.g. `li:not(.done)`.
+    pub fn not(self, selector: Selector) -> Selector {
+        self.with_condition(Condition::Not(selector))
+    }
+
+    /// Matches `selector` inside the elements matching `self`, e.g. `ul li`.
+    pub fn descendant(self, selector: Selector) -> Selector {
+        self.combine(Combinator::Descendant, selector)
+    }
+
+    /// Matches `selector` directly inside the elements matching `self`,
+    /// e.g. `ul > li`.
+    pub fn child(self, selector: Selector) -> Selector {
+        self.combine(Combinator::Child, selector)
+    }
+
+    /// Matches `selector` right after the elements matching `self`, e.g.
+    /// `h2 + p`.
+    pub fn next_sibling(self, selector: Selector) -> Selector {
+        self.combine(Combinator::NextSibling, selector)
+    }
+
+    /// Matches `selector` anywhere after the elements matching `self` within
+    /// the same parent, e.g. `h2 ~ p`.
+    pub fn subsequent_sibling(self, selector: Selector) -> Selector {
+        self.combine(Combinator::SubsequentSibling, selector)
+    }
+
+    /// Matches the elements matching `self` or `selector`.
+    pub fn or(self, selector: Selector) -> Selector {
+        Selector::list(vec![self, selector])
+    }
+
+    fn compound(compound: Compound) -> Selector {
+        Selector(vec![Complex(vec![(Combinator::None, compound)])])
+    }
+
+    // Adds the condition to the subject, i.e. the last compound, of every
+    // selector in the list.
+    fn with_condition(mut self, condition: Condition) -> Selector {
+        for complex in &mut self.0 {
+            if let Some((_, compound)) = complex.0.last_mut() {
+                compound.conditions.push(condition.clone());
+            }
+        }
+
+        self
+    }
+
+    fn combine(self, combinator: Combinator, selector: Selector) -> Selector {
+        let combined = self
+            .0
+            .iter()
+            .flat_map(|left| {
+                selector.0.iter().map(move |right| {
+                    let mut parts = left.0.clone();
+
+                    for (index, (right_combinator, compound)) in right.0.iter().enumerate() {
+                        let combinator = if index == 0 {
+                            combinator
+                        } else {
+                            *right_combinator
+                        };
+
+                        parts.push((combinator, compound.clone()));
+                    }
+
+                    Complex(parts)
+                })
+            })
+            .collect();
+
+        Selector(combined)
     }
 }
 
 impl fmt::Display for Selector {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        for (index, complex) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "{}", complex)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (combinator, compound) in &self.0 {
+            match combinator {
+                Combinator::None => {}
+                Combinator::Descendant => f.write_str(" ")?,
+                Combinator::Child => f.write_str(" > ")?,
+                Combinator::NextSibling => f.write_str(" + ")?,
+                Combinator::SubsequentSibling => f.write_str(" ~ ")?,
+            }
+
+            write!(f, "{}", compound)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Compound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.tag {
+            Some(tag) => f.write_str(&escape_identifier(tag))?,
+            None if self.conditions.is_empty() => f.write_str("*")?,
+            None => {}
+        }
+
+        for condition in &self.conditions {
+            match condition {
+                Condition::Id(id) => write!(f, "#{}", escape_identifier(id))?,
+
+                Condition::Class(class) => write!(f, ".{}", escape_identifier(class))?,
+
+                Condition::Attribute {
+                    name,
+                    operator: None,
+                    ..
+                } => write!(f, "[{}]", escape_identifier(name))?,
+
+                Condition::Attribute {
+                    name,
+                    operator: Some((operator, value)),
+                    ignore_case,
+                } => write!(
+                    f,
+                    "[{}{}{}{}]",
+                    escape_identifier(name),
+                    operator.as_str(),
+                    escape_string(value),
+                    if *ignore_case { " i" } else { "" }
+                )?,
+
+                Condition::PseudoClass(pseudo_class) => write!(f, ":{}", pseudo_class.as_str())?,
+
+                Condition::Not(selector) => write!(f, ":not({})", selector)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl AttributeOperator {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AttributeOperator::Equals => "=",
+            AttributeOperator::Includes => "~=",
+            AttributeOperator::DashMatch => "|=",
+            AttributeOperator::Prefix => "^=",
+            AttributeOperator::Suffix => "$=",
+            AttributeOperator::Substring => "*=",
+        }
+    }
+}
+
+impl PseudoClass {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PseudoClass::Checked => "checked",
+            PseudoClass::Disabled => "disabled",
+            PseudoClass::Enabled => "enabled",
+            PseudoClass::Required => "required",
+            PseudoClass::Empty => "empty",
+            PseudoClass::FirstChild => "first-child",
+            PseudoClass::LastChild => "last-child",
+            PseudoClass::OnlyChild => "only-child",
+            PseudoClass::FirstOfType => "first-of-type",
+            PseudoClass::LastOfType => "last-of-type",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<PseudoClass> {
+        let pseudo_class = match name.to_ascii_lowercase().as_str() {
+            "checked" => PseudoClass::Checked,
+            "disabled" => PseudoClass::Disabled,
+            "enabled" => PseudoClass::Enabled,
+            "required" => PseudoClass::Required,
+            "empty" => PseudoClass::Empty,
+            "first-child" => PseudoClass::FirstChild,
+            "last-child" => PseudoClass::LastChild,
+            "only-child" => PseudoClass::OnlyChild,
+            "first-of-type" => PseudoClass::FirstOfType,
+            "last-of-type" => PseudoClass::LastOfType,
+            _ => return None,
+        };
+
+        Some(pseudo_class)
+    }
+}
+
+impl From<Selector> for String {
+    fn from(selector: Selector) -> Self {
+        selector.to_string()
+    }
+}
+
+impl TryFrom<String> for Selector {
+    type Error = String;
+
+    fn try_from(selector: String) -> Result<Self, Self::Error> {
+        Selector::parse(&selector)
+    }
+}
+
+/// Escapes an identifier like `CSS.escape`, so `1st item` becomes
+/// `\31 st\ item`.
+pub fn escape_identifier(ident: &str) -> String {
+    let mut escaped = String::with_capacity(ident.len());
+    let chars: Vec<char> = ident.chars().collect();
+
+    for (index, c) in chars.iter().copied().enumerate() {
+        let starts_with_digit = index == 0 || (index == 1 && chars[0] == '-');
+
+        match c {
+            '\0' => escaped.push('\u{fffd}'),
+
+            '\u{1}'..='\u{1f}' | '\u{7f}' => escaped.push_str(&format!("\\{:x} ", c as u32)),
+
+            '0'..='9' if starts_with_digit => escaped.push_str(&format!("\\{:x} ", c as u32)),
+
+            '-' if chars.len() == 1 => escaped.push_str("\\-"),
+
+            c if c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii() => {
+                escaped.push(c)
+            }
+
+            c => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+        }
+    }
+
+    escaped
+}
+
+/// Quotes a value for an attribute selector, escaping quotes, backslashes
+/// and control characters.
+pub fn escape_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for c in value.chars() {
+        match c {
+            '\0' => escaped.push('\u{fffd}'),
+            '"' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\u{1}'..='\u{1f}' | '\u{7f}' => escaped.push_str(&format!("\\{:x} ", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+        self.pos > start
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.bump() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(format!("expected '{}', found '{}'", expected, c)),
+            None => Err(format!("expected '{}', found end of input", expected)),
+        }
+    }
+
+    fn parse_selector(&mut self) -> Result<Selector, String> {
+        let list = self.parse_list()?;
+
+        match self.peek() {
+            None => Ok(list),
+            Some(c) => Err(format!("unexpected '{}'", c)),
+        }
+    }
+
+    fn parse_list(&mut self) -> Result<Selector, String> {
+        let mut list = vec![self.parse_complex()?];
+
+        while self.peek() == Some(',') {
+            self.bump();
+            list.push(self.parse_complex()?);
+        }
+
+        Ok(Selector(list))
+    }
+
+    fn parse_complex(&mut self) -> Result<Complex, String> {
+        self.skip_whitespace();
+        let mut parts = vec![(Combinator::None, self.parse_compound()?)];
+
+        loop {
+            let had_whitespace = self.skip_whitespace();
+
+            let combinator = match self.peek() {
+                Some(c @ ('>' | '+' | '~')) => {
+                    self.bump();
+                    self.skip_whitespace();
+
+                    match c {
+                        '>' => Combinator::Child,
+                        '+' => Combinator::NextSibling,
+                        _ => Combinator::SubsequentSibling,
+                    }
+                }
+                Some(',' | ')') | None => break,
+                Some(_) if had_whitespace => Combinator::Descendant,
+                Some(c) => return Err(format!("unexpected '{}'", c)),
+            };
+
+            parts.push((combinator, self.parse_compound()?));
+        }
+
+        Ok(Complex(parts))
+    }
+
+    fn parse_compound(&mut self) -> Result<Compound, String> {
+        let mut compound = Compound::default();
+        let mut universal = false;
+
+        match self.peek() {
+            Some('*') => {
+                self.bump();
+                universal = true;
+            }
+            Some(c) if is_ident_start(c) => {
+                compound.tag = Some(self.parse_ident()?.to_ascii_lowercase());
+            }
+            _ => {}
+        }
+
+        loop {
+            match self.peek() {
+                Some('#') => {
+                    self.bump();
+                    compound.conditions.push(Condition::Id(self.parse_ident()?));
+                }
+                Some('.') => {
+                    self.bump();
+                    compound
+                        .conditions
+                        .push(Condition::Class(self.parse_ident()?));
+                }
+                Some('[') => {
+                    self.bump();
+                    compound.conditions.push(self.parse_attribute()?);
+                }
+                Some(':') => {
+                    self.bump();
+                    compound.conditions.push(self.parse_pseudo_class()?);
+                }
+                _ => break,
+            }
+        }
+
+        if !universal && compound.tag.is_none() && compound.conditions.is_empty() {
+            return match self.peek() {
+                Some(c) => Err(format!("unexpected '{}'", c)),
+                None => Err("unexpected end of input".to_string()),
+            };
+        }
+
+        Ok(compound)
+    }
+
+    fn parse_pseudo_class(&mut self) -> Result<Condition, String> {
+        let name = self.parse_ident()?;
+
+        if !name.eq_ignore_ascii_case("not") {
+            return PseudoClass::from_name(&name)
+                .map(Condition::PseudoClass)
+                .ok_or_else(|| format!("unsupported pseudo-class ':{}'", name));
+        }
+
+        self.expect('(')?;
+        let selector = self.parse_list()?;
+        self.skip_whitespace();
+        self.expect(')')?;
+
+        Ok(Condition::Not(selector))
+    }
+
+    fn parse_attribute(&mut self) -> Result<Condition, String> {
+        self.skip_whitespace();
+        let name = self.parse_ident()?;
+        self.skip_whitespace();
+
+        let operator = match self.bump() {
+            Some(']') => {
+                return Ok(Condition::Attribute {
+                    name,
+                    operator: None,
+                    ignore_case: false,
+                });
+            }
+            Some('=') => AttributeOperator::Equals,
+            Some(c @ ('~' | '|' | '^' | '$' | '*')) => {
+                self.expect('=')?;
+                match c {
+                    '~' => AttributeOperator::Includes,
+                    '|' => AttributeOperator::DashMatch,
+                    '^' => AttributeOperator::Prefix,
+                    '$' => AttributeOperator::Suffix,
+                    _ => AttributeOperator::Substring,
+                }
+            }
+            Some(c) => return Err(format!("unexpected '{}' in attribute selector", c)),
+            None => return Err("unterminated attribute selector".to_string()),
+        };
+
+        self.skip_whitespace();
+        let value = match self.peek() {
+            Some(quote @ ('"' | '\'')) => self.parse_string(quote)?,
+            _ => self.parse_ident()?,
+        };
+        self.skip_whitespace();
+
+        let ignore_case = match self.peek() {
+            Some(c) if is_ident_start(c) => match self.parse_ident()?.as_str() {
+                "i" | "I" => true,
+                "s" | "S" => false,
+                flag => return Err(format!("unsupported attribute selector flag '{}'", flag)),
+            },
+            _ => false,
+        };
+
+        self.skip_whitespace();
+        self.expect(']')?;
+
+        Ok(Condition::Attribute {
+            name,
+            operator: Some((operator, value)),
+            ignore_case,
+        })
+    }
+
+    fn parse_ident(&mut self) -> Result<String, String> {
+        let mut ident = String::new();
+
+        while let Some(c) = self.peek() {
+            if c == '\\' {
+                self.bump();
+                ident.push(self.parse_escape()?);
+            } else if is_ident_char(c) {
+                self.bump();
+                ident.push(c);
+            } else {
+                break;
+            }
+        }
+
+        if ident.is_empty() {
+            return match self.peek() {
+                Some(c) => Err(format!("expected identifier, found '{}'", c)),
+                None => Err("expected identifier, found end of input".to_string()),
+            };
+        }
+
+        Ok(ident)
+    }
+
+    fn parse_string(&mut self, quote: char) -> Result<String, String> {
+        self.bump();
+        let mut value = String::new();
+
+        loop {
+            match self.bump() {
+                Some('\\') => value.push(self.parse_escape()?),
+                Some(c) if c == quote => return Ok(value),
+                Some(c) => value.push(c),
+                None => return Err("unterminated string".to_string()),
+            }
+        }
     }
+
+    fn parse_escape(&mut self) -> Result<char, String> {
+        let start = self.pos;
+        while self.pos - start < 6 && self.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+            self.bump();
+        }
+
+        if self.pos == start {
+            return self.bump().ok_or_else(|| "unterminated escape".to_string());
+        }
+
+        let code =
+            u32::from_str_radix(&self.input[start..self.pos], 16).map_err(|err| err.to_string())?;
+
+        // A single whitespace character terminates a hex escape.
+        if self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+
+        Ok(char::from_u32(code)
+            .filter(|c| *c != '\0')
+            .unwrap_or('\u{fffd}'))
+    }
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '-' || c == '\\' || !c.is_ascii()
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-' || !c.is_ascii()
 }
@@ -1,9 +1,20 @@
+pub mod animation_frame;
 pub mod event_listener;
+pub mod event_source;
 pub mod interval;
+pub mod navigation;
+pub mod websocket;
 
+use crate::browser::DebounceConfig;
 use crate::browser::effect::Effect;
+use crate::browser::subscription::animation_frame::AnimationFrame;
 use crate::browser::subscription::event_listener::EventListener;
+use crate::browser::subscription::event_listener::RateLimit;
+use crate::browser::subscription::event_source::EventSource;
 use crate::browser::subscription::interval::Interval;
+use crate::browser::subscription::navigation::UrlChange;
+use crate::browser::subscription::websocket::WebSocket;
+use crate::component::Scope;
 
 #[derive(Clone, serde::Serialize)]
 #[serde(tag = "type", content = "config")]
@@ -12,6 +23,10 @@ pub enum Subscription<Msg> {
     None,
     EventListener(EventListener<Msg>),
     Interval(Interval<Msg>),
+    UrlChange(UrlChange<Msg>),
+    WebSocket(WebSocket<Msg>),
+    EventSource(EventSource<Msg>),
+    AnimationFrame(AnimationFrame<Msg>),
     Batch(Vec<Subscription<Msg>>),
 }
 
@@ -25,6 +40,121 @@ impl<Msg> Subscription<Msg> {
             _ => vec![self],
         }
     }
+
+    /// Debounces every event listener in the subscription, e.g. to send a
+    /// search request only once the user stops typing.
+    ///
+    /// Only event listeners are rate limited. Other subscriptions, like an
+    /// interval or a websocket, are returned unchanged.
+    pub fn debounce(self, config: DebounceConfig) -> Subscription<Msg> {
+        self.with_rate_limit(&RateLimit::Debounce(config))
+    }
+
+    /// Throttles every event listener in the subscription. Like with
+    /// `debounce`, other subscriptions are returned unchanged.
+    pub fn throttle(self, config: DebounceConfig) -> Subscription<Msg> {
+        self.with_rate_limit(&RateLimit::Throttle(config))
+    }
+
+    fn with_rate_limit(self, rate_limit: &RateLimit) -> Subscription<Msg> {
+        match self {
+            Subscription::EventListener(listener) => Subscription::EventListener(EventListener {
+                rate_limit: Some(rate_limit.clone()),
+                ..listener
+            }),
+            Subscription::Batch(subscriptions) => Subscription::Batch(
+                subscriptions
+                    .into_iter()
+                    .map(|subscription| subscription.with_rate_limit(rate_limit))
+                    .collect(),
+            ),
+            // These have no events for the JS runtime to rate limit.
+            Subscription::None
+            | Subscription::Interval(_)
+            | Subscription::UrlChange(_)
+            | Subscription::WebSocket(_)
+            | Subscription::EventSource(_)
+            | Subscription::AnimationFrame(_) => self,
+        }
+    }
+
+    /// Prefixes the id of every subscription with the scope, so that the
+    /// subscriptions of two instances of a component don't replace each
+    /// other. Selectors are left as is, they already use the scoped ids.
+    pub fn scope(self, scope: &Scope) -> Subscription<Msg> {
+        let prefix = scope.prefix();
+        let scoped = |id: String| format!("{}-{}", prefix, id);
+
+        match self {
+            Subscription::None => Subscription::None,
+            Subscription::EventListener(listener) => Subscription::EventListener(EventListener {
+                id: scoped(listener.id),
+                ..listener
+            }),
+            Subscription::Interval(interval) => Subscription::Interval(interval.scope(prefix)),
+            Subscription::UrlChange(url_change) => Subscription::UrlChange(UrlChange {
+                id: scoped(url_change.id),
+                ..url_change
+            }),
+            Subscription::WebSocket(websocket) => Subscription::WebSocket(WebSocket {
+                id: scoped(websocket.id),
+                ..websocket
+            }),
+            Subscription::EventSource(event_source) => Subscription::EventSource(EventSource {
+                id: scoped(event_source.id),
+                ..event_source
+            }),
+            Subscription::AnimationFrame(animation_frame) => {
+                Subscription::AnimationFrame(AnimationFrame {
+                    id: scoped(animation_frame.id),
+                    ..animation_frame
+                })
+            }
+            Subscription::Batch(subscriptions) => Subscription::Batch(
+                subscriptions
+                    .into_iter()
+                    .map(|subscription| subscription.scope(scope))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Converts every message carried by the subscription, e.g. to listen
+    /// to the subscriptions of a child page from a parent page.
+    pub fn map<ParentMsg, ToMsg>(self, to_msg: ToMsg) -> Subscription<ParentMsg>
+    where
+        ToMsg: Fn(Msg) -> ParentMsg,
+    {
+        self.map_with(&to_msg)
+    }
+
+    fn map_with<ParentMsg>(self, to_msg: &dyn Fn(Msg) -> ParentMsg) -> Subscription<ParentMsg> {
+        match self {
+            Subscription::None => Subscription::None,
+            Subscription::EventListener(listener) => {
+                Subscription::EventListener(listener.map_with(to_msg))
+            }
+            Subscription::Interval(interval) => Subscription::Interval(interval.map_with(to_msg)),
+            Subscription::UrlChange(url_change) => {
+                Subscription::UrlChange(url_change.map_with(to_msg))
+            }
+            Subscription::WebSocket(websocket) => {
+                Subscription::WebSocket(websocket.map_with(to_msg))
+            }
+            Subscription::EventSource(event_source) => {
+                Subscription::EventSource(event_source.map_with(to_msg))
+            }
+            Subscription::AnimationFrame(animation_frame) => {
+                Subscription::AnimationFrame(animation_frame.map_with(to_msg))
+            }
+            Subscription::Batch(subscriptions) => Subscription::Batch(
+                subscriptions
+                    .into_iter()
+                    .map(|subscription| subscription.map_with(to_msg))
+                    .collect(),
+            ),
+        }
+    }
 }
 
 pub fn none<Msg>() -> Subscription<Msg> {
@@ -58,4 +188,17 @@ impl<Msg> SubscriptionMsg<Msg> {
             effect,
         }
     }
+
+    pub(crate) fn map_with<ParentMsg>(
+        self,
+        to_msg: &dyn Fn(Msg) -> ParentMsg,
+    ) -> SubscriptionMsg<ParentMsg> {
+        match self {
+            SubscriptionMsg::Pure(msg) => SubscriptionMsg::Pure(to_msg(msg)),
+            SubscriptionMsg::Effectful { msg, effect } => SubscriptionMsg::Effectful {
+                msg: to_msg(msg),
+                effect: effect.map_with(to_msg),
+            },
+        }
+    }
 }
@@ -0,0 +1,80 @@
+use crate::browser::effect::time;
+use crate::browser::subscription::Subscription;
+use crate::browser::subscription::SubscriptionMsg;
+use crate::browser::value::Capture;
+use crate::time::Posix;
+use std::time::Duration;
+
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimationFrame<Msg> {
+    pub id: String,
+    pub msg: SubscriptionMsg<Msg>,
+}
+
+impl<Msg> AnimationFrame<Msg> {
+    pub(crate) fn map_with<ParentMsg>(
+        self,
+        to_msg: &dyn Fn(Msg) -> ParentMsg,
+    ) -> AnimationFrame<ParentMsg> {
+        AnimationFrame {
+            id: self.id,
+            msg: self.msg.map_with(to_msg),
+        }
+    }
+}
+
+/// A frame painted by the browser.
+#[derive(Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Frame {
+    /// When the frame started.
+    pub timestamp: Posix,
+
+    /// Time elapsed since the previous frame, zero on the first frame.
+    #[serde(with = "millis")]
+    pub delta: Duration,
+}
+
+impl Default for Frame {
+    fn default() -> Self {
+        Frame {
+            timestamp: Posix::from_millis(0),
+            delta: Duration::ZERO,
+        }
+    }
+}
+
+/// Fires before every repaint, using `requestAnimationFrame`.
+///
+/// Browsers pause animation frames in background tabs, so the first frame
+/// after a tab becomes visible again has a large `delta`.
+pub fn animation_frame<Msg, ToMsg>(to_msg: ToMsg) -> Subscription<Msg>
+where
+    ToMsg: Fn(Capture<Frame>) -> Msg,
+{
+    Subscription::AnimationFrame(AnimationFrame {
+        id: "animation-frame".to_string(),
+        msg: SubscriptionMsg::effectful(to_msg, time::get_animation_frame()),
+    })
+}
+
+// Durations are sent as fractional milliseconds, like `DOMHighResTimeStamp`.
+mod millis {
+    use std::time::Duration;
+
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let millis: f64 = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Duration::from_secs_f64(millis.max(0.0) / 1000.0))
+    }
+}
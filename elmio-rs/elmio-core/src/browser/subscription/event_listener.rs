@@ -1,7 +1,18 @@
+use crate::browser::DebounceConfig;
 use crate::browser::dom_id::DomId;
 use crate::browser::effect::Effect;
 use crate::browser::effect::dom;
+use crate::browser::event::EventPayloadKind;
+use crate::browser::event::FocusData;
+use crate::browser::event::Modifiers;
+use crate::browser::event::MouseData;
+use crate::browser::event::PointerData;
+use crate::browser::event::ScrollData;
+use crate::browser::event::TouchData;
+use crate::browser::event::WheelData;
+use crate::browser::keyboard::Chord;
 use crate::browser::keyboard::Key;
+use crate::browser::keyboard::Shortcut;
 use crate::browser::mouse::Button;
 use crate::browser::selector::Selector;
 use crate::browser::subscription::Subscription;
@@ -9,7 +20,7 @@ use crate::browser::subscription::SubscriptionMsg;
 use crate::browser::value::Capture;
 use std::fmt;
 
-#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ListenTarget {
     Window,
@@ -29,11 +40,27 @@ pub enum EventMatcher {
     MouseButton {
         button: Button,
     },
+    // A main button click without modifier keys, on a link the browser
+    // would follow in the current tab.
+    LinkClick,
+    // Compares the key with `KeyboardEvent.code`, other modifiers than the
+    // required ones may be held.
     #[serde(rename_all = "camelCase")]
     KeyboardKey {
         key: Key,
         requires_ctrl: bool,
         requires_meta: bool,
+        requires_shift: bool,
+        requires_alt: bool,
+    },
+    // Matches a chord, modifiers it does not require must be released.
+    Chord(Chord),
+    // Matches the event completing the chords pressed in order, each within
+    // `timeout` milliseconds of the previous one. Modifier key presses in
+    // between are ignored.
+    KeySequence {
+        chords: Vec<Chord>,
+        timeout: u32,
     },
 }
 
@@ -42,10 +69,37 @@ pub enum ModifierKey {
     None,
     Ctrl,
     Meta,
+    Shift,
+    Alt,
     Multiple(Vec<ModifierKey>),
 }
 
 impl ModifierKey {
+    pub fn requires_shift(&self) -> bool {
+        match self {
+            ModifierKey::Shift => true,
+            ModifierKey::Multiple(keys) => keys.iter().any(Self::requires_shift),
+            _ => false,
+        }
+    }
+
+    pub fn requires_alt(&self) -> bool {
+        match self {
+            ModifierKey::Alt => true,
+            ModifierKey::Multiple(keys) => keys.iter().any(Self::requires_alt),
+            _ => false,
+        }
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        Modifiers {
+            alt: self.requires_alt(),
+            ctrl: self.requires_ctrl(),
+            meta: self.requires_meta(),
+            shift: self.requires_shift(),
+        }
+    }
+
     pub fn requires_ctrl(&self) -> bool {
         match self {
             ModifierKey::Ctrl => true,
@@ -69,6 +123,8 @@ impl fmt::Display for ModifierKey {
             ModifierKey::None => write!(f, "no-modifier"),
             ModifierKey::Ctrl => write!(f, "ctrl"),
             ModifierKey::Meta => write!(f, "meta"),
+            ModifierKey::Shift => write!(f, "shift"),
+            ModifierKey::Alt => write!(f, "alt"),
             ModifierKey::Multiple(keys) => {
                 let keys: Vec<String> = keys.iter().map(|key| key.to_string()).collect();
                 write!(f, "{}", keys.join("-"))
@@ -86,6 +142,24 @@ pub struct EventListener<Msg> {
     pub matchers: Vec<EventMatcher>,
     pub msg: SubscriptionMsg<Msg>,
     pub propagation: EventPropagation,
+    pub rate_limit: Option<RateLimit>,
+}
+
+impl<Msg> EventListener<Msg> {
+    pub(crate) fn map_with<ParentMsg>(
+        self,
+        to_msg: &dyn Fn(Msg) -> ParentMsg,
+    ) -> EventListener<ParentMsg> {
+        EventListener {
+            id: self.id,
+            listen_target: self.listen_target,
+            event_type: self.event_type,
+            matchers: self.matchers,
+            msg: self.msg.map_with(to_msg),
+            propagation: self.propagation,
+            rate_limit: self.rate_limit,
+        }
+    }
 }
 
 pub fn on_click<Id, Msg>(id: Id, msg: Msg) -> Subscription<Msg>
@@ -104,6 +178,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -123,6 +198,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -145,6 +221,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -167,6 +244,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -191,6 +269,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -211,6 +290,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -232,6 +312,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -252,6 +333,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -274,6 +356,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -294,6 +377,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -315,6 +399,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -334,6 +419,30 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
+    })
+}
+
+/// Reads the whole form into `T` on submit, see `dom::get_form_data`.
+pub fn on_submit_form<Id, Msg, ToMsg, T>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<T>) -> Msg,
+    T: Default + serde::de::DeserializeOwned,
+{
+    Subscription::EventListener(EventListener {
+        id: id.to_string(),
+        listen_target: ListenTarget::Document,
+        matchers: vec![EventMatcher::ExactSelector {
+            selector: id.selector(),
+        }],
+        event_type: EventType::Submit,
+        msg: SubscriptionMsg::effectful(to_msg, dom::get_form_data(id)),
+        propagation: EventPropagation {
+            stop_propagation: true,
+            prevent_default: true,
+        },
+        rate_limit: None,
     })
 }
 
@@ -354,6 +463,7 @@ where
             stop_propagation: true,
             prevent_default: true,
         },
+        rate_limit: None,
     })
 }
 
@@ -366,15 +476,21 @@ pub fn on_keyup<Msg>(key: Key, msg: Msg) -> Subscription<Msg> {
             key,
             requires_ctrl: false,
             requires_meta: false,
+            requires_shift: false,
+            requires_alt: false,
         }],
         msg: SubscriptionMsg::pure(msg),
         propagation: EventPropagation {
             stop_propagation: false,
             prevent_default: false,
         },
+        rate_limit: None,
     })
 }
 
+/// Fires when `key` is pressed while holding `modifier`, whether or not
+/// other modifiers are held too. Use `on_shortcut` to require that they are
+/// released.
 pub fn on_keydown<Msg>(key: Key, modifier: ModifierKey, msg: Msg) -> Subscription<Msg> {
     Subscription::EventListener(EventListener {
         id: format!("keyboard-keydown-{}-{}", key, modifier),
@@ -384,12 +500,15 @@ pub fn on_keydown<Msg>(key: Key, modifier: ModifierKey, msg: Msg) -> Subscriptio
             key,
             requires_ctrl: modifier.requires_ctrl(),
             requires_meta: modifier.requires_meta(),
+            requires_shift: modifier.requires_shift(),
+            requires_alt: modifier.requires_alt(),
         }],
         msg: SubscriptionMsg::pure(msg),
         propagation: EventPropagation {
             stop_propagation: false,
             prevent_default: false,
         },
+        rate_limit: None,
     })
 }
 
@@ -407,9 +526,36 @@ pub fn on_keydown_with_options<Msg>(
             key,
             requires_ctrl: modifier.requires_ctrl(),
             requires_meta: modifier.requires_meta(),
+            requires_shift: modifier.requires_shift(),
+            requires_alt: modifier.requires_alt(),
         }],
         msg: SubscriptionMsg::pure(msg),
         propagation,
+        rate_limit: None,
+    })
+}
+
+/// Fires on a keyboard shortcut, like `Ctrl+Shift+K` or the sequence
+/// `g g`. Modifiers the shortcut does not name must be released, and the
+/// default action of the browser, e.g. saving the page on `Ctrl+S`, is
+/// prevented.
+pub fn on_shortcut<Msg>(shortcut: Shortcut, msg: Msg) -> Subscription<Msg> {
+    let matcher = match shortcut.chords.as_slice() {
+        [chord] => EventMatcher::Chord(chord.clone()),
+        chords => EventMatcher::KeySequence {
+            chords: chords.to_vec(),
+            timeout: shortcut.timeout.as_millis() as u32,
+        },
+    };
+
+    Subscription::EventListener(EventListener {
+        id: format!("shortcut-{}", shortcut),
+        listen_target: ListenTarget::Document,
+        event_type: EventType::Keydown,
+        matchers: vec![matcher],
+        msg: SubscriptionMsg::pure(msg),
+        propagation: PREVENT,
+        rate_limit: None,
     })
 }
 
@@ -428,9 +574,352 @@ where
             stop_propagation: false,
             prevent_default: false,
         },
+        rate_limit: None,
+    })
+}
+
+pub fn on_double_click<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<MouseData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Dblclick,
+        EventPayloadKind::Mouse,
+        CLAIM,
+        to_msg,
+    )
+}
+
+/// Replaces the browser context menu of the element, e.g. to open a custom
+/// menu at `MouseData::client_x` and `client_y`.
+pub fn on_context_menu<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<MouseData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Contextmenu,
+        EventPayloadKind::Mouse,
+        CLAIM,
+        to_msg,
+    )
+}
+
+pub fn on_mouse_up<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<MouseData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Mouseup,
+        EventPayloadKind::Mouse,
+        OBSERVE,
+        to_msg,
+    )
+}
+
+pub fn on_mouse_move<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<MouseData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Mousemove,
+        EventPayloadKind::Mouse,
+        OBSERVE,
+        to_msg,
+    )
+}
+
+/// Fires when the pointer enters the element, not when it moves between
+/// its children, e.g. to show a tooltip.
+pub fn on_mouse_enter<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<MouseData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Mouseenter,
+        EventPayloadKind::Mouse,
+        OBSERVE,
+        to_msg,
+    )
+}
+
+pub fn on_mouse_leave<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<MouseData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Mouseleave,
+        EventPayloadKind::Mouse,
+        OBSERVE,
+        to_msg,
+    )
+}
+
+pub fn on_pointer_down<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<PointerData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Pointerdown,
+        EventPayloadKind::Pointer,
+        OBSERVE,
+        to_msg,
+    )
+}
+
+pub fn on_pointer_up<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<PointerData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Pointerup,
+        EventPayloadKind::Pointer,
+        OBSERVE,
+        to_msg,
+    )
+}
+
+pub fn on_pointer_move<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<PointerData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Pointermove,
+        EventPayloadKind::Pointer,
+        OBSERVE,
+        to_msg,
+    )
+}
+
+/// Fires when the browser takes over the pointer, e.g. to scroll the page,
+/// and no `pointerup` follows.
+pub fn on_pointer_cancel<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<PointerData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Pointercancel,
+        EventPayloadKind::Pointer,
+        OBSERVE,
+        to_msg,
+    )
+}
+
+/// Touch listeners prevent the default behavior, so the page can handle
+/// gestures on the element without the browser scrolling or zooming.
+pub fn on_touch_start<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<TouchData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Touchstart,
+        EventPayloadKind::Touch,
+        PREVENT,
+        to_msg,
+    )
+}
+
+pub fn on_touch_move<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<TouchData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Touchmove,
+        EventPayloadKind::Touch,
+        PREVENT,
+        to_msg,
+    )
+}
+
+pub fn on_touch_end<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<TouchData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Touchend,
+        EventPayloadKind::Touch,
+        PREVENT,
+        to_msg,
+    )
+}
+
+pub fn on_touch_cancel<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<TouchData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Touchcancel,
+        EventPayloadKind::Touch,
+        PREVENT,
+        to_msg,
+    )
+}
+
+/// Prevents the default behavior, so the element can zoom or pan on the
+/// wheel instead of scrolling the page.
+pub fn on_wheel<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<WheelData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Wheel,
+        EventPayloadKind::Wheel,
+        PREVENT,
+        to_msg,
+    )
+}
+
+pub fn on_focus<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<FocusData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Focus,
+        EventPayloadKind::Focus,
+        OBSERVE,
+        to_msg,
+    )
+}
+
+pub fn on_blur<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<FocusData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Blur,
+        EventPayloadKind::Focus,
+        OBSERVE,
+        to_msg,
+    )
+}
+
+/// Fires when the element scrolls its own content.
+pub fn on_scroll<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<ScrollData>) -> Msg,
+{
+    element_listener(
+        id,
+        EventType::Scroll,
+        EventPayloadKind::Scroll,
+        OBSERVE,
+        to_msg,
+    )
+}
+
+pub fn on_window_scroll<Msg, ToMsg>(to_msg: ToMsg) -> Subscription<Msg>
+where
+    ToMsg: Fn(Capture<ScrollData>) -> Msg,
+{
+    Subscription::EventListener(EventListener {
+        id: "window-scroll".to_string(),
+        listen_target: ListenTarget::Window,
+        event_type: EventType::Scroll,
+        matchers: vec![],
+        msg: SubscriptionMsg::effectful(to_msg, dom::get_event_payload(EventPayloadKind::Scroll)),
+        propagation: OBSERVE,
+        rate_limit: None,
+    })
+}
+
+// Handled like a click: the page owns the event.
+const CLAIM: EventPropagation = EventPropagation {
+    stop_propagation: true,
+    prevent_default: true,
+};
+
+// Only prevents the default behavior, other listeners still run.
+const PREVENT: EventPropagation = EventPropagation {
+    stop_propagation: false,
+    prevent_default: true,
+};
+
+// Passive, e.g. for hover and focus tracking.
+const OBSERVE: EventPropagation = EventPropagation {
+    stop_propagation: false,
+    prevent_default: false,
+};
+
+// Listens for `event_type` on the element and captures the event payload.
+// Events that bubble also match from the children of the element, while
+// `focus`, `blur`, `scroll` and the enter and leave events only fire on the
+// element itself.
+fn element_listener<Id, Msg, ToMsg, T>(
+    id: Id,
+    event_type: EventType,
+    kind: EventPayloadKind,
+    propagation: EventPropagation,
+    to_msg: ToMsg,
+) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<T>) -> Msg,
+    T: Default,
+{
+    let selector = id.selector();
+    let matcher = if event_type.bubbles() {
+        EventMatcher::ClosestSelector { selector }
+    } else {
+        EventMatcher::ExactSelector { selector }
+    };
+
+    Subscription::EventListener(EventListener {
+        id: format!("{}-{}", id, event_type),
+        listen_target: ListenTarget::Document,
+        event_type,
+        matchers: vec![matcher],
+        msg: SubscriptionMsg::effectful(to_msg, dom::get_event_payload(kind)),
+        propagation,
+        rate_limit: None,
     })
 }
 
+/// Limits how often a listener dispatches its message. Propagation is
+/// still handled for every matching event.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "config")]
+#[serde(rename_all = "camelCase")]
+pub enum RateLimit {
+    /// Waits until events stop arriving for the configured delay.
+    Debounce(DebounceConfig),
+    /// Dispatches at most once per configured delay.
+    Throttle(DebounceConfig),
+}
+
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EventPropagation {
@@ -438,7 +927,7 @@ pub struct EventPropagation {
     pub prevent_default: bool,
 }
 
-#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum EventType {
     Click,
@@ -449,4 +938,72 @@ pub enum EventType {
     Keyup,
     Keydown,
     Resize,
+    Dblclick,
+    Contextmenu,
+    Mouseup,
+    Mousemove,
+    Mouseenter,
+    Mouseleave,
+    Pointerdown,
+    Pointerup,
+    Pointermove,
+    Pointercancel,
+    Touchstart,
+    Touchmove,
+    Touchend,
+    Touchcancel,
+    Wheel,
+    Focus,
+    Blur,
+    Scroll,
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            EventType::Click => "click",
+            EventType::Mousedown => "mousedown",
+            EventType::Input => "input",
+            EventType::Change => "change",
+            EventType::Submit => "submit",
+            EventType::Keyup => "keyup",
+            EventType::Keydown => "keydown",
+            EventType::Resize => "resize",
+            EventType::Dblclick => "dblclick",
+            EventType::Contextmenu => "contextmenu",
+            EventType::Mouseup => "mouseup",
+            EventType::Mousemove => "mousemove",
+            EventType::Mouseenter => "mouseenter",
+            EventType::Mouseleave => "mouseleave",
+            EventType::Pointerdown => "pointerdown",
+            EventType::Pointerup => "pointerup",
+            EventType::Pointermove => "pointermove",
+            EventType::Pointercancel => "pointercancel",
+            EventType::Touchstart => "touchstart",
+            EventType::Touchmove => "touchmove",
+            EventType::Touchend => "touchend",
+            EventType::Touchcancel => "touchcancel",
+            EventType::Wheel => "wheel",
+            EventType::Focus => "focus",
+            EventType::Blur => "blur",
+            EventType::Scroll => "scroll",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl EventType {
+    /// Whether the event bubbles up from the target to its ancestors.
+    pub fn bubbles(&self) -> bool {
+        !matches!(
+            self,
+            EventType::Mouseenter
+                | EventType::Mouseleave
+                | EventType::Focus
+                | EventType::Blur
+                | EventType::Scroll
+                | EventType::Resize
+        )
+    }
 }
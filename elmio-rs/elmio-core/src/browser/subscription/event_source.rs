@@ -0,0 +1,176 @@
+use crate::browser::effect::event_source as event_source_effect;
+use crate::browser::subscription::Subscription;
+use crate::browser::subscription::SubscriptionMsg;
+use crate::browser::value::Capture;
+
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventSource<Msg> {
+    pub id: String,
+    pub url: String,
+    pub options: Options,
+    pub msg: SubscriptionMsg<Msg>,
+}
+
+impl<Msg> EventSource<Msg> {
+    pub(crate) fn map_with<ParentMsg>(
+        self,
+        to_msg: &dyn Fn(Msg) -> ParentMsg,
+    ) -> EventSource<ParentMsg> {
+        EventSource {
+            id: self.id,
+            url: self.url,
+            options: self.options,
+            msg: self.msg.map_with(to_msg),
+        }
+    }
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Options {
+    pub event_type: String,
+    pub last_event_id: Option<String>,
+    pub with_credentials: bool,
+}
+
+impl Options {
+    pub fn new() -> Options {
+        Options {
+            event_type: "message".to_string(),
+            last_event_id: None,
+            with_credentials: false,
+        }
+    }
+
+    /// Listens to events sent with `event: <name>` instead of unnamed ones.
+    pub fn event_type(self, event_type: &str) -> Options {
+        Options {
+            event_type: event_type.to_string(),
+            ..self
+        }
+    }
+
+    /// Resumes after the event with `id` when the connection is opened.
+    ///
+    /// Browsers don't allow setting the `Last-Event-ID` header on a new
+    /// connection, so the id is sent as the `lastEventId` query parameter
+    /// instead. This is not part of the SSE standard, the server has to read
+    /// the parameter for the connection to resume, otherwise it is ignored.
+    /// Reconnects of an open connection send the header as usual.
+    pub fn last_event_id(self, id: &str) -> Options {
+        Options {
+            last_event_id: Some(id.to_string()),
+            ..self
+        }
+    }
+
+    pub fn with_credentials(self) -> Options {
+        Options {
+            with_credentials: true,
+            ..self
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options::new()
+    }
+}
+
+/// A server event along with its id, as delivered by `listen_with_options`.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerEvent<T> {
+    pub id: Option<String>,
+    pub event_type: String,
+    pub data: T,
+}
+
+/// Connects to an SSE endpoint while subscribed, decoding the data of
+/// every unnamed event as JSON.
+///
+/// Subscriptions to the same URL share one connection, so they must use the
+/// same `last_event_id` and `with_credentials` options.
+pub fn listen<Msg, ToMsg, T>(url: &str, to_msg: ToMsg) -> Subscription<Msg>
+where
+    ToMsg: Fn(Capture<T>) -> Msg,
+    T: Default,
+{
+    let options = Options::new();
+
+    Subscription::EventSource(EventSource {
+        id: subscription_id(url, &options),
+        url: url.to_string(),
+        options,
+        msg: SubscriptionMsg::effectful(to_msg, event_source_effect::get_event_json_data()),
+    })
+}
+
+/// Like `listen`, but captures the data of every event as a string.
+pub fn listen_string<Msg, ToMsg>(url: &str, to_msg: ToMsg) -> Subscription<Msg>
+where
+    ToMsg: Fn(Capture<String>) -> Msg,
+{
+    let options = Options::new();
+
+    Subscription::EventSource(EventSource {
+        id: subscription_id(url, &options),
+        url: url.to_string(),
+        options,
+        msg: SubscriptionMsg::effectful(to_msg, event_source_effect::get_event_string_data()),
+    })
+}
+
+/// Like `listen`, delivering the event id along with the data so the page
+/// can resume with `Options::last_event_id`.
+pub fn listen_with_options<Msg, ToMsg, T>(
+    url: &str,
+    options: Options,
+    to_msg: ToMsg,
+) -> Subscription<Msg>
+where
+    ToMsg: Fn(Capture<ServerEvent<T>>) -> Msg,
+    T: Default,
+{
+    Subscription::EventSource(EventSource {
+        id: subscription_id(url, &options),
+        url: url.to_string(),
+        options,
+        msg: SubscriptionMsg::effectful(to_msg, event_source_effect::get_server_event()),
+    })
+}
+
+/// Checks that event sources sharing a connection agree on the options it is
+/// opened with, since only one set of them can be used.
+pub fn check_shared_connections<Msg>(subscriptions: &[Subscription<Msg>]) -> Result<(), String> {
+    let event_sources: Vec<&EventSource<Msg>> = subscriptions
+        .iter()
+        .filter_map(|subscription| match subscription {
+            Subscription::EventSource(event_source) => Some(event_source),
+            _ => None,
+        })
+        .collect();
+
+    for (index, event_source) in event_sources.iter().enumerate() {
+        let conflict = event_sources[..index].iter().find(|other| {
+            other.url == event_source.url
+                && (other.options.last_event_id != event_source.options.last_event_id
+                    || other.options.with_credentials != event_source.options.with_credentials)
+        });
+
+        if conflict.is_some() {
+            return Err(format!(
+                "Event sources on '{}' share a connection but use different options",
+                event_source.url
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn subscription_id(url: &str, options: &Options) -> String {
+    format!("event-source-{}-{}", url, options.event_type)
+}
@@ -12,6 +12,26 @@ pub struct Interval<Msg> {
     msg: SubscriptionMsg<Msg>,
 }
 
+impl<Msg> Interval<Msg> {
+    pub(crate) fn map_with<ParentMsg>(
+        self,
+        to_msg: &dyn Fn(Msg) -> ParentMsg,
+    ) -> Interval<ParentMsg> {
+        Interval {
+            id: self.id,
+            duration: self.duration,
+            msg: self.msg.map_with(to_msg),
+        }
+    }
+
+    pub(crate) fn scope(self, prefix: &str) -> Interval<Msg> {
+        Interval {
+            id: format!("{}-{}", prefix, self.id),
+            ..self
+        }
+    }
+}
+
 pub fn interval<Msg>(duration: Duration, msg: Msg) -> Subscription<Msg> {
     Subscription::Interval(Interval {
         id: format!("interval-{}", duration.as_millis()),
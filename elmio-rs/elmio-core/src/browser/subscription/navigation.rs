@@ -0,0 +1,102 @@
+use crate::browser::dom_id::DomId;
+use crate::browser::effect::navigation;
+use crate::browser::mouse::Button;
+use crate::browser::selector::Selector;
+use crate::browser::subscription::Subscription;
+use crate::browser::subscription::SubscriptionMsg;
+use crate::browser::subscription::event_listener::EventListener;
+use crate::browser::subscription::event_listener::EventMatcher;
+use crate::browser::subscription::event_listener::EventPropagation;
+use crate::browser::subscription::event_listener::EventType;
+use crate::browser::subscription::event_listener::ListenTarget;
+use crate::browser::value::Capture;
+use crate::browser::value::Value;
+
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    pub url: String,
+    pub state: Value,
+}
+
+/// A followed link, holding its absolute URL. Links to the origin of the
+/// current page are internal.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "config")]
+#[serde(rename_all = "camelCase")]
+pub enum UrlRequest {
+    Internal(String),
+    External(String),
+}
+
+impl Default for UrlRequest {
+    fn default() -> Self {
+        UrlRequest::Internal(String::new())
+    }
+}
+
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UrlChange<Msg> {
+    pub id: String,
+    pub msg: SubscriptionMsg<Msg>,
+}
+
+impl<Msg> UrlChange<Msg> {
+    pub(crate) fn map_with<ParentMsg>(
+        self,
+        to_msg: &dyn Fn(Msg) -> ParentMsg,
+    ) -> UrlChange<ParentMsg> {
+        UrlChange {
+            id: self.id,
+            msg: self.msg.map_with(to_msg),
+        }
+    }
+}
+
+/// Fires when the URL changes through history traversal or a fragment
+/// change, i.e. on `popstate` and `hashchange`.
+///
+/// URLs changed by `push_url` and `replace_url` are not reported, since
+/// the page already knows about them.
+pub fn on_url_change<Msg, ToMsg>(to_msg: ToMsg) -> Subscription<Msg>
+where
+    ToMsg: Fn(Capture<Location>) -> Msg,
+{
+    Subscription::UrlChange(UrlChange {
+        id: "url-change".to_string(),
+        msg: SubscriptionMsg::effectful(to_msg, navigation::get_location()),
+    })
+}
+
+/// Intercepts clicks on links inside the element with `id`, instead of
+/// letting the browser load the page.
+///
+/// Clicks with modifier keys, and links with a `download` attribute or a
+/// `target` other than `_self`, are left to the browser.
+pub fn on_url_request<Id, Msg, ToMsg>(id: Id, to_msg: ToMsg) -> Subscription<Msg>
+where
+    Id: DomId,
+    ToMsg: Fn(Capture<UrlRequest>) -> Msg,
+{
+    Subscription::EventListener(EventListener {
+        id: format!("url-request-{}", id),
+        listen_target: ListenTarget::Document,
+        matchers: vec![
+            EventMatcher::ClosestSelector {
+                selector: Selector::links(&id.to_string()),
+            },
+            EventMatcher::MouseButton {
+                button: Button::Main,
+            },
+            EventMatcher::LinkClick,
+        ],
+        event_type: EventType::Click,
+        msg: SubscriptionMsg::effectful(to_msg, navigation::get_url_request()),
+        propagation: EventPropagation {
+            stop_propagation: false,
+            prevent_default: true,
+        },
+        rate_limit: None,
+    })
+}
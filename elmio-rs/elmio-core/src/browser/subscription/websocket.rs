@@ -0,0 +1,162 @@
+use crate::browser::effect::websocket as websocket_effect;
+use crate::browser::subscription::Subscription;
+use crate::browser::subscription::SubscriptionMsg;
+use crate::browser::value::Capture;
+use std::time::Duration;
+
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebSocket<Msg> {
+    pub id: String,
+    pub url: String,
+    pub backoff: Backoff,
+    pub msg: SubscriptionMsg<Msg>,
+    pub state_msgs: Option<StateMsgs<Msg>>,
+}
+
+impl<Msg> WebSocket<Msg> {
+    pub(crate) fn map_with<ParentMsg>(
+        self,
+        to_msg: &dyn Fn(Msg) -> ParentMsg,
+    ) -> WebSocket<ParentMsg> {
+        WebSocket {
+            id: self.id,
+            url: self.url,
+            backoff: self.backoff,
+            msg: self.msg.map_with(to_msg),
+            state_msgs: self.state_msgs.map(|state_msgs| StateMsgs {
+                connecting: to_msg(state_msgs.connecting),
+                open: to_msg(state_msgs.open),
+                closed: to_msg(state_msgs.closed),
+                error: to_msg(state_msgs.error),
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConnectionState {
+    Connecting,
+    Open,
+    Closed,
+    Error,
+}
+
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateMsgs<Msg> {
+    pub connecting: Msg,
+    pub open: Msg,
+    pub closed: Msg,
+    pub error: Msg,
+}
+
+/// How long to wait before reconnecting a closed socket. The delay is
+/// multiplied after every failed attempt, up to `max_delay`.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Backoff {
+    initial_delay: u64,
+    max_delay: u64,
+    multiplier: f64,
+    max_attempts: Option<u32>,
+}
+
+impl Backoff {
+    pub fn new(initial_delay: Duration, max_delay: Duration) -> Backoff {
+        Backoff {
+            initial_delay: initial_delay.as_millis() as u64,
+            max_delay: max_delay.as_millis() as u64,
+            ..Backoff::default()
+        }
+    }
+
+    /// Never reconnects.
+    pub fn none() -> Backoff {
+        Backoff {
+            max_attempts: Some(0),
+            ..Backoff::default()
+        }
+    }
+
+    pub fn multiplier(self, multiplier: f64) -> Backoff {
+        Backoff { multiplier, ..self }
+    }
+
+    pub fn max_attempts(self, max_attempts: u32) -> Backoff {
+        Backoff {
+            max_attempts: Some(max_attempts),
+            ..self
+        }
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Backoff {
+            initial_delay: 1000,
+            max_delay: 30000,
+            multiplier: 2.0,
+            max_attempts: None,
+        }
+    }
+}
+
+/// Opens a socket while subscribed, delivering every text message.
+///
+/// Sockets are keyed by URL, so they stay open across updates and close
+/// once the subscription is gone.
+pub fn listen<Msg, ToMsg>(url: &str, to_msg: ToMsg) -> Subscription<Msg>
+where
+    ToMsg: Fn(Capture<String>) -> Msg,
+{
+    Subscription::WebSocket(WebSocket {
+        id: format!("websocket-{}", url),
+        url: url.to_string(),
+        backoff: Backoff::default(),
+        msg: SubscriptionMsg::effectful(to_msg, websocket_effect::get_message_string_data()),
+        state_msgs: None,
+    })
+}
+
+/// Like `listen`, but parses every message as JSON.
+pub fn listen_json<Msg, ToMsg, T>(url: &str, to_msg: ToMsg) -> Subscription<Msg>
+where
+    ToMsg: Fn(Capture<T>) -> Msg,
+    T: Default,
+{
+    Subscription::WebSocket(WebSocket {
+        id: format!("websocket-{}", url),
+        url: url.to_string(),
+        backoff: Backoff::default(),
+        msg: SubscriptionMsg::effectful(to_msg, websocket_effect::get_message_json_data()),
+        state_msgs: None,
+    })
+}
+
+/// Like `listen`, with a custom backoff and a message for every change of
+/// the connection state.
+pub fn listen_with_state<Msg, ToMsg, ToStateMsg>(
+    url: &str,
+    backoff: Backoff,
+    to_msg: ToMsg,
+    to_state_msg: ToStateMsg,
+) -> Subscription<Msg>
+where
+    ToMsg: Fn(Capture<String>) -> Msg,
+    ToStateMsg: Fn(ConnectionState) -> Msg,
+{
+    Subscription::WebSocket(WebSocket {
+        id: format!("websocket-{}", url),
+        url: url.to_string(),
+        backoff,
+        msg: SubscriptionMsg::effectful(to_msg, websocket_effect::get_message_string_data()),
+        state_msgs: Some(StateMsgs {
+            connecting: to_state_msg(ConnectionState::Connecting),
+            open: to_state_msg(ConnectionState::Open),
+            closed: to_state_msg(ConnectionState::Closed),
+            error: to_state_msg(ConnectionState::Error),
+        }),
+    })
+}
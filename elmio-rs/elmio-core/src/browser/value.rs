@@ -42,6 +42,12 @@ where
 #[derive(Debug, Clone)]
 pub struct Capture<T>(T);
 
+impl<T> Capture<T> {
+    pub fn new(value: T) -> Self {
+        Capture(value)
+    }
+}
+
 impl<T: Clone> Capture<T> {
     pub fn into_value(self) -> T {
         self.0
@@ -0,0 +1,90 @@
+use crate::browser::dom_id::DomId;
+use crate::browser::effect::Effect;
+use crate::browser::subscription;
+use crate::browser::subscription::Subscription;
+use std::fmt;
+
+/// A reusable part of a page with its own model and messages.
+///
+/// Components are embedded by holding an instance in the page, mapping their
+/// effects into the page's messages with `Effect::map`, and their
+/// subscriptions with `Component::embed_subscriptions`.
+///
+/// Each instance has its own `Scope`. The `DomId`s of a component must be
+/// created with `Component::id` so that they don't collide between two
+/// instances on the same page, while subscription ids are scoped by
+/// `embed_subscriptions`.
+pub trait Component<Model, Msg, Markup> {
+    fn scope(&self) -> &Scope;
+    fn init(&self) -> Result<(Model, Effect<Msg>), String>;
+    fn update(&self, msg: &Msg, model: &mut Model) -> Result<Effect<Msg>, String>;
+    fn subscriptions(&self, _model: &Model) -> Subscription<Msg> {
+        subscription::none()
+    }
+    fn view(&self, model: &Model) -> Markup;
+
+    /// The subscriptions of this instance, scoped with `Subscription::scope`
+    /// and mapped into the parent's messages.
+    fn embed_subscriptions<ParentMsg, ToMsg>(
+        &self,
+        model: &Model,
+        to_msg: ToMsg,
+    ) -> Subscription<ParentMsg>
+    where
+        ToMsg: Fn(Msg) -> ParentMsg,
+        Self: Sized,
+    {
+        self.subscriptions(model).scope(self.scope()).map(to_msg)
+    }
+
+    fn id<Id>(&self, id: Id) -> ScopedId
+    where
+        Id: DomId,
+        Self: Sized,
+    {
+        self.scope().id(id)
+    }
+}
+
+/// The DomId namespace of a component instance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scope {
+    prefix: String,
+}
+
+impl Scope {
+    pub fn new(prefix: &str) -> Scope {
+        Scope {
+            prefix: prefix.to_string(),
+        }
+    }
+
+    /// Creates the scope of a component nested in this one.
+    pub fn child(&self, prefix: &str) -> Scope {
+        Scope {
+            prefix: format!("{}-{}", self.prefix, prefix),
+        }
+    }
+
+    pub fn id<Id>(&self, id: Id) -> ScopedId
+    where
+        Id: DomId,
+    {
+        ScopedId(format!("{}-{}", self.prefix, id))
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScopedId(String);
+
+impl fmt::Display for ScopedId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl DomId for ScopedId {}
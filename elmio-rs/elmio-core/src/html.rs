@@ -0,0 +1,431 @@
+pub(crate) const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Elements whose content is text up to their end tag, kept as is.
+pub(crate) const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// Like `RAW_TEXT_ELEMENTS`, but character references are decoded.
+pub(crate) const ESCAPABLE_RAW_TEXT_ELEMENTS: [&str; 2] = ["textarea", "title"];
+
+pub type NodeId = usize;
+
+#[derive(Clone, Debug)]
+pub enum NodeKind {
+    Document,
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct Element {
+    pub tag: String,
+    pub attributes: Vec<(String, String)>,
+}
+
+impl Element {
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.attribute(name).is_some()
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.attribute("id")
+    }
+
+    pub fn classes(&self) -> impl Iterator<Item = &str> {
+        self.attribute("class")
+            .unwrap_or("")
+            .split_ascii_whitespace()
+    }
+
+    pub fn set_attribute(&mut self, name: &str, value: &str) {
+        match self
+            .attributes
+            .iter_mut()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        {
+            Some((_, old_value)) => *old_value = value.to_string(),
+            None => self.attributes.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    pub fn remove_attribute(&mut self, name: &str) {
+        self.attributes
+            .retain(|(key, _)| !key.eq_ignore_ascii_case(name));
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Node {
+    pub kind: NodeKind,
+    pub parent: Option<NodeId>,
+    pub children: Vec<NodeId>,
+}
+
+/// A parsed HTML fragment, stored as an arena of nodes.
+///
+/// The parser is intentionally forgiving and only aims to understand the
+/// markup produced by `Page::render`.
+#[derive(Clone, Debug)]
+pub struct Document {
+    nodes: Vec<Node>,
+}
+
+impl Document {
+    pub const ROOT: NodeId = 0;
+
+    pub fn parse(html: &str) -> Document {
+        Parser::new(html).parse()
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn element(&self, id: NodeId) -> Option<&Element> {
+        match &self.nodes[id].kind {
+            NodeKind::Element(element) => Some(element),
+            _ => None,
+        }
+    }
+
+    pub fn element_mut(&mut self, id: NodeId) -> Option<&mut Element> {
+        match &mut self.nodes[id].kind {
+            NodeKind::Element(element) => Some(element),
+            _ => None,
+        }
+    }
+
+    pub fn parent_element(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id]
+            .parent
+            .filter(|parent| self.element(*parent).is_some())
+    }
+
+    /// Returns the element children of the node's parent, including the node
+    /// itself, in document order.
+    pub fn sibling_elements(&self, id: NodeId) -> Vec<NodeId> {
+        match self.nodes[id].parent {
+            Some(parent) => self.nodes[parent]
+                .children
+                .iter()
+                .copied()
+                .filter(|child| self.element(*child).is_some())
+                .collect(),
+            None => vec![id],
+        }
+    }
+
+    /// Returns the ids of all elements in document order.
+    pub fn elements(&self) -> Vec<NodeId> {
+        let mut ids = Vec::new();
+        self.collect_elements(Self::ROOT, &mut ids);
+        ids
+    }
+
+    pub fn element_by_id(&self, element_id: &str) -> Option<NodeId> {
+        self.elements()
+            .into_iter()
+            .find(|id| self.element(*id).and_then(Element::id) == Some(element_id))
+    }
+
+    /// Returns the concatenated text of the node and all of its descendants.
+    pub fn text_content(&self, id: NodeId) -> String {
+        match &self.nodes[id].kind {
+            NodeKind::Text(text) => text.clone(),
+            _ => self.nodes[id]
+                .children
+                .iter()
+                .map(|child| self.text_content(*child))
+                .collect(),
+        }
+    }
+
+    fn collect_elements(&self, id: NodeId, ids: &mut Vec<NodeId>) {
+        for child in &self.nodes[id].children {
+            if self.element(*child).is_some() {
+                ids.push(*child);
+                self.collect_elements(*child, ids);
+            }
+        }
+    }
+
+    fn push(&mut self, kind: NodeKind, parent: NodeId) -> NodeId {
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            kind,
+            parent: Some(parent),
+            children: Vec::new(),
+        });
+        self.nodes[parent].children.push(id);
+        id
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    document: Document,
+    open_elements: Vec<NodeId>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            pos: 0,
+            document: Document {
+                nodes: vec![Node {
+                    kind: NodeKind::Document,
+                    parent: None,
+                    children: Vec::new(),
+                }],
+            },
+            open_elements: vec![Document::ROOT],
+        }
+    }
+
+    fn parse(mut self) -> Document {
+        while self.pos < self.input.len() {
+            let rest = &self.input[self.pos..];
+
+            if rest.starts_with("<!--") {
+                self.skip_past("-->");
+            } else if rest.starts_with("<!") || rest.starts_with("<?") {
+                self.skip_past(">");
+            } else if rest.starts_with("</") {
+                self.parse_end_tag();
+            } else if rest.starts_with('<')
+                && rest[1..].starts_with(|c: char| c.is_ascii_alphabetic())
+            {
+                self.parse_start_tag();
+            } else {
+                self.parse_text();
+            }
+        }
+
+        self.document
+    }
+
+    fn current(&self) -> NodeId {
+        *self.open_elements.last().unwrap_or(&Document::ROOT)
+    }
+
+    fn skip_past(&mut self, pattern: &str) {
+        match self.input[self.pos..].find(pattern) {
+            Some(index) => self.pos += index + pattern.len(),
+            None => self.pos = self.input.len(),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = &self.input[self.pos..];
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    fn parse_text(&mut self) {
+        // A lone '<' that does not open a tag is treated as text.
+        let start = self.pos;
+        self.pos += self.input[self.pos..]
+            .chars()
+            .next()
+            .map_or(1, char::len_utf8);
+        let len = self.input[self.pos..]
+            .find('<')
+            .unwrap_or(self.input.len() - self.pos);
+        self.pos += len;
+
+        let text = decode_entities(&self.input[start..self.pos]);
+        let parent = self.current();
+        self.document.push(NodeKind::Text(text), parent);
+    }
+
+    fn parse_end_tag(&mut self) {
+        self.pos += 2;
+        let tag = self
+            .take_while(|c| !c.is_whitespace() && c != '>')
+            .to_ascii_lowercase();
+        self.skip_past(">");
+
+        let position = self.open_elements.iter().rposition(|id| {
+            self.document
+                .element(*id)
+                .is_some_and(|element| element.tag == tag)
+        });
+
+        if let Some(index) = position {
+            self.open_elements.truncate(index);
+        }
+    }
+
+    fn parse_start_tag(&mut self) {
+        self.pos += 1;
+        let tag = self
+            .take_while(|c| !c.is_whitespace() && c != '>' && c != '/')
+            .to_ascii_lowercase();
+
+        let mut attributes = Vec::new();
+        let mut self_closing = false;
+
+        loop {
+            self.skip_whitespace();
+            let rest = &self.input[self.pos..];
+
+            if rest.is_empty() {
+                break;
+            } else if rest.starts_with("/>") {
+                self.pos += 2;
+                self_closing = true;
+                break;
+            } else if rest.starts_with('>') {
+                self.pos += 1;
+                break;
+            } else if rest.starts_with('/') {
+                self.pos += 1;
+                continue;
+            }
+
+            let name = self
+                .take_while(|c| !c.is_whitespace() && c != '=' && c != '>' && c != '/')
+                .to_ascii_lowercase();
+            self.skip_whitespace();
+
+            let value = if self.input[self.pos..].starts_with('=') {
+                self.pos += 1;
+                self.skip_whitespace();
+                self.parse_attribute_value()
+            } else {
+                String::new()
+            };
+
+            attributes.push((name, value));
+        }
+
+        let parent = self.current();
+        let is_void = VOID_ELEMENTS.contains(&tag.as_str());
+        let is_raw_text = RAW_TEXT_ELEMENTS.contains(&tag.as_str());
+        let is_escapable_raw_text = ESCAPABLE_RAW_TEXT_ELEMENTS.contains(&tag.as_str());
+        let id = self.document.push(
+            NodeKind::Element(Element {
+                tag: tag.clone(),
+                attributes,
+            }),
+            parent,
+        );
+
+        if is_void || self_closing {
+            return;
+        }
+
+        if is_raw_text || is_escapable_raw_text {
+            let end_tag = format!("</{}", tag);
+            let rest = &self.input[self.pos..];
+            let len = find_ignore_case(rest, &end_tag).unwrap_or(rest.len());
+            let text = &rest[..len];
+            self.pos += len;
+
+            if !text.is_empty() {
+                let text = if is_raw_text {
+                    text.to_string()
+                } else {
+                    decode_entities(text)
+                };
+
+                self.document.push(NodeKind::Text(text), id);
+            }
+
+            self.skip_past(">");
+            return;
+        }
+
+        self.open_elements.push(id);
+    }
+
+    fn parse_attribute_value(&mut self) -> String {
+        let rest = &self.input[self.pos..];
+
+        match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                self.pos += 1;
+                let value = self.take_while(|c| c != quote);
+                self.pos = (self.pos + 1).min(self.input.len());
+                decode_entities(value)
+            }
+            _ => decode_entities(self.take_while(|c| !c.is_whitespace() && c != '>')),
+        }
+    }
+}
+
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .to_ascii_lowercase()
+        .find(&needle.to_ascii_lowercase())
+}
+
+fn decode_entities(s: &str) -> String {
+    if !s.contains('&') {
+        return s.to_string();
+    }
+
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(index) = rest.find('&') {
+        decoded.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let entity = rest
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| decode_entity(&rest[1..end]).map(|c| (c, end)));
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = if let Some(hex) = name.strip_prefix("#x").or(name.strip_prefix("#X")) {
+                u32::from_str_radix(hex, 16).ok()
+            } else {
+                name.strip_prefix('#').and_then(|dec| dec.parse().ok())
+            };
+
+            code.and_then(char::from_u32)
+        }
+    }
+}
@@ -1,5 +1,11 @@
 pub mod asset;
 pub mod browser;
+pub mod component;
+pub mod html;
 pub mod page;
+pub mod port;
+pub mod random;
 pub mod route;
+pub mod testing;
 pub mod time;
+pub mod vdom;
@@ -6,11 +6,18 @@ use crate::browser::effect::Effect;
 use crate::browser::subscription::Subscription;
 use maud::html;
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `Page`",
+    label = "not an elmio page",
+    note = "implement `Page<Model, Msg, Markup>` for `{Self}` to run it as a page"
+)]
 pub trait Page<Model, Msg, Markup> {
     fn id(&self) -> &'static dyn DomId;
     fn init(&self) -> Result<(Model, Effect<Msg>), String>;
     fn subscriptions(&self, model: &Model) -> Subscription<Msg>;
     fn update(&self, msg: &Msg, model: &mut Model) -> Result<Effect<Msg>, String>;
+    /// Handles the messages sent from JavaScript with `Elmio.sendMessage`.
+    /// Decode them with `IncomingPort::from_js_msg` for a typed port.
     fn update_from_js(&self, _msg: JsMsg, _model: &mut Model) -> Result<Effect<Msg>, String> {
         Ok(effect::none())
     }
@@ -19,7 +26,7 @@ pub trait Page<Model, Msg, Markup> {
     fn render_page(&self, markup: PageMarkup<Markup>) -> String;
 }
 
-#[derive(Clone, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct JsMsg {
     #[serde(rename = "type")]
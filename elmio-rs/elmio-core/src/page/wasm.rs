@@ -1,10 +1,19 @@
 use crate::browser::effect::Effect;
 use crate::browser::subscription::Subscription;
+use crate::browser::subscription::event_source;
 use crate::page::JsMsg;
 use crate::page::Page;
+use crate::vdom::Patch;
+use crate::vdom::RenderedView;
 use serde::Serialize;
+use std::marker::PhantomData;
 use wasm_bindgen::prelude::*;
 
+// Used by `#[elmio_macro::wasm_page]`, so pages do not need their own
+// wasm-bindgen dependency.
+#[doc(hidden)]
+pub use wasm_bindgen;
+
 const JSON_SERIALIZER: serde_wasm_bindgen::Serializer =
     serde_wasm_bindgen::Serializer::json_compatible();
 
@@ -101,6 +110,121 @@ where
     })
 }
 
+/// A page that keeps its model in wasm memory between messages.
+///
+/// Unlike the free functions in this module, the model is never sent to JS:
+/// `init`, `update` and `update_from_js` only return effects, and the view
+/// and subscriptions are computed from the resident model.
+pub struct StatefulPage<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+{
+    page: P,
+    model: Option<Model>,
+    rendered: RenderedView,
+    phantom: PhantomData<(Msg, Markup)>,
+}
+
+impl<P, Model, Msg, Markup> StatefulPage<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+    Msg: serde::Serialize,
+    Msg: serde::de::DeserializeOwned,
+{
+    pub fn new(page: P) -> Self {
+        Self {
+            page,
+            model: None,
+            rendered: RenderedView::default(),
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn page(&self) -> &P {
+        &self.page
+    }
+
+    pub fn init(&mut self) -> Result<JsValue, JsValue> {
+        let (model, effect) = self.page.init()?;
+        self.model = Some(model);
+
+        encode_effects(&Effects {
+            effects: effect.into_vec(),
+        })
+    }
+
+    pub fn view(&self) -> Result<String, JsValue> {
+        let markup = self.page.view(self.model()?);
+
+        Ok(self.page.render_page(markup))
+    }
+
+    pub fn view_body(&self) -> Result<String, JsValue> {
+        let markup = self.page.view(self.model()?);
+
+        Ok(self.page.render(markup.body))
+    }
+
+    /// Renders the body and returns the patches that turn the previously
+    /// rendered body into the new one.
+    ///
+    /// The first call, or a body that is not a single element, yields one
+    /// `Replace` patch for the whole app element.
+    ///
+    /// Since `Markup` is opaque, the rendered string is parsed back into a
+    /// tree and diffed as a whole on every call, which costs about as much
+    /// as rendering it. Only stateful pages pay for it; stateless pages send
+    /// the markup to elmio-js, which morphs the DOM itself.
+    pub fn view_patches(&mut self) -> Result<JsValue, JsValue> {
+        let patches = self.rendered.patches(self.view_body()?);
+        encode_patches(&patches)
+    }
+
+    /// Forgets the previously rendered body, so that the next `view_patches`
+    /// replaces the whole app element. Called by elmio-js when the patches
+    /// could not be applied, since the DOM no longer matches the last view.
+    pub fn reset_view(&mut self) {
+        self.rendered.reset();
+    }
+
+    pub fn get_subscriptions(&self) -> Result<JsValue, JsValue> {
+        let subscriptions = self.page.subscriptions(self.model()?);
+        encode_subscriptions(subscriptions.into_vec())
+    }
+
+    pub fn update(&mut self, js_msg: &JsValue) -> Result<JsValue, JsValue> {
+        let msg = decode_msg(js_msg)?;
+        let model = initialized_model(&mut self.model)?;
+        let effect = self.page.update(&msg, model)?;
+
+        encode_effects(&Effects {
+            effects: effect.into_vec(),
+        })
+    }
+
+    pub fn update_from_js(&mut self, js_msg: &JsValue) -> Result<JsValue, JsValue> {
+        let msg = decode_value(js_msg)?;
+        let model = initialized_model(&mut self.model)?;
+        let effect = self.page.update_from_js(msg, model)?;
+
+        encode_effects(&Effects {
+            effects: effect.into_vec(),
+        })
+    }
+
+    fn model(&self) -> Result<&Model, JsValue> {
+        self.model
+            .as_ref()
+            .ok_or_else(|| "Page has not been initialized".into())
+    }
+}
+
+fn initialized_model<Model>(model: &mut Option<Model>) -> Result<&mut Model, JsValue> {
+    model
+        .as_mut()
+        .ok_or_else(|| "Page has not been initialized".into())
+}
+
 pub fn encode_js_value(value: impl Serialize) -> Result<JsValue, serde_wasm_bindgen::Error> {
     value.serialize(&JSON_SERIALIZER)
 }
@@ -123,10 +247,23 @@ where
         .map_err(|err| format!("Failed to encode model and effects: {}", err).into())
 }
 
+fn encode_effects<Msg>(effects: &Effects<Msg>) -> Result<JsValue, JsValue>
+where
+    Msg: serde::Serialize,
+{
+    encode_js_value(effects).map_err(|err| format!("Failed to encode effects: {}", err).into())
+}
+
+fn encode_patches(patches: &[Patch]) -> Result<JsValue, JsValue> {
+    encode_js_value(patches).map_err(|err| format!("Failed to encode patches: {}", err).into())
+}
+
 fn encode_subscriptions<Msg>(subscriptions: Vec<Subscription<Msg>>) -> Result<JsValue, JsValue>
 where
     Msg: serde::Serialize,
 {
+    event_source::check_shared_connections(&subscriptions)?;
+
     encode_js_value(subscriptions)
         .map_err(|err| format!("Failed to encode subscriptions: {}", err).into())
 }
@@ -156,3 +293,9 @@ pub struct ModelAndEffects<Model, Msg> {
     pub model: Model,
     pub effects: Vec<Effect<Msg>>,
 }
+
+#[derive(Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Effects<Msg> {
+    pub effects: Vec<Effect<Msg>>,
+}
@@ -0,0 +1,82 @@
+use crate::browser::effect;
+use crate::browser::effect::Effect;
+use crate::page::JsMsg;
+
+/// Messages sent from JavaScript with `Elmio.sendMessage`.
+///
+/// Usually implemented with `#[derive(elmio_macro::IncomingPort)]` on an
+/// enum, where every variant is a message type named after the variant in
+/// camelCase, and its fields are the message data. `elmio gen-types`
+/// declares the messages of derived ports in TypeScript.
+pub trait IncomingPort: Sized {
+    fn from_js_msg(msg: JsMsg) -> Result<Self, String>;
+}
+
+/// Messages sent to the JavaScript handler registered with
+/// `Elmio.onCustomEffect`.
+///
+/// Usually implemented with `#[derive(elmio_macro::OutgoingPort)]`, the
+/// counterpart of `IncomingPort`.
+pub trait OutgoingPort {
+    fn to_js_msg(&self) -> Result<JsMsg, String>;
+
+    fn send<Msg>(&self) -> Result<Effect<Msg>, String> {
+        Ok(effect::custom(self.to_js_msg()?))
+    }
+}
+
+#[doc(hidden)]
+pub fn decode_data<T>(type_: &str, data: serde_json::Value) -> Result<T, String>
+where
+    T: serde::de::DeserializeOwned,
+{
+    serde_json::from_value(data)
+        .map_err(|err| format!("Invalid data for port message '{}': {}", type_, err))
+}
+
+#[doc(hidden)]
+pub fn decode_field<T>(type_: &str, data: &serde_json::Value, name: &str) -> Result<T, String>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let value = data.get(name).cloned().unwrap_or_default();
+
+    serde_json::from_value(value).map_err(|err| {
+        format!(
+            "Invalid field '{}' for port message '{}': {}",
+            name, type_, err
+        )
+    })
+}
+
+#[doc(hidden)]
+pub fn encode_data<T>(type_: &str, value: &T) -> Result<serde_json::Value, String>
+where
+    T: serde::Serialize,
+{
+    serde_json::to_value(value)
+        .map_err(|err| format!("Failed to encode port message '{}': {}", type_, err))
+}
+
+#[doc(hidden)]
+pub fn encode_fields(fields: Vec<(&str, serde_json::Value)>) -> serde_json::Value {
+    serde_json::Value::Object(
+        fields
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+    )
+}
+
+#[doc(hidden)]
+pub fn unknown_type(type_: &str) -> String {
+    format!("Unknown port message type '{}'", type_)
+}
+
+#[doc(hidden)]
+pub fn js_msg(type_: &str, data: serde_json::Value) -> JsMsg {
+    JsMsg {
+        type_: type_.to_string(),
+        data,
+    }
+}
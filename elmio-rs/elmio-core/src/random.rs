@@ -0,0 +1,307 @@
+use std::rc::Rc;
+
+/// The state of a pseudo random number generator.
+///
+/// Seeds are plain values, so they can be kept in the model and stepped in
+/// `update`. Get an unpredictable seed with `effect::random::new_seed`, or
+/// use `Seed::new` to get the same values on every run.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(from = "[u32; 4]", into = "[u32; 4]")]
+pub struct Seed {
+    state: [u32; 4],
+}
+
+impl Seed {
+    pub fn new(value: u64) -> Seed {
+        let mut value = value;
+        let mut state = [0; 4];
+
+        for pair in state.chunks_mut(2) {
+            let n = splitmix64(&mut value);
+            pair[0] = n as u32;
+            pair[1] = (n >> 32) as u32;
+        }
+
+        Seed { state }
+    }
+
+    // xoshiro128**, see https://prng.di.unimi.it
+    fn next_u32(self) -> (u32, Seed) {
+        let mut s = self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 9;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(11);
+
+        (result, Seed { state: s })
+    }
+
+    fn next_u64(self) -> (u64, Seed) {
+        let (high, seed) = self.next_u32();
+        let (low, seed) = seed.next_u32();
+
+        ((u64::from(high) << 32) | u64::from(low), seed)
+    }
+
+    // Returns a value in `0..n` without modulo bias.
+    fn next_below(self, n: u64) -> (u64, Seed) {
+        let threshold = n.wrapping_neg() % n;
+        let mut seed = self;
+
+        loop {
+            let (value, next_seed) = seed.next_u64();
+            seed = next_seed;
+
+            if value >= threshold {
+                return (value % n, seed);
+            }
+        }
+    }
+
+    fn next_f64(self) -> (f64, Seed) {
+        let (value, seed) = self.next_u64();
+        ((value >> 11) as f64 / (1u64 << 53) as f64, seed)
+    }
+}
+
+impl Default for Seed {
+    fn default() -> Self {
+        Seed::new(0)
+    }
+}
+
+impl From<[u32; 4]> for Seed {
+    fn from(state: [u32; 4]) -> Self {
+        // An all zero state would only ever produce zeros.
+        if state == [0; 4] {
+            return Seed::default();
+        }
+
+        Seed { state }
+    }
+}
+
+impl From<Seed> for [u32; 4] {
+    fn from(seed: Seed) -> Self {
+        seed.state
+    }
+}
+
+fn splitmix64(value: &mut u64) -> u64 {
+    *value = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+    let mut z = *value;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// A description of how to produce a random value, run with `step`.
+pub struct Generator<T> {
+    run: Rc<dyn Fn(Seed) -> (T, Seed)>,
+}
+
+impl<T> Clone for Generator<T> {
+    fn clone(&self) -> Self {
+        Generator {
+            run: Rc::clone(&self.run),
+        }
+    }
+}
+
+impl<T: 'static> Generator<T> {
+    pub fn new<F>(run: F) -> Generator<T>
+    where
+        F: Fn(Seed) -> (T, Seed) + 'static,
+    {
+        Generator { run: Rc::new(run) }
+    }
+
+    pub fn map<U, F>(self, f: F) -> Generator<U>
+    where
+        U: 'static,
+        F: Fn(T) -> U + 'static,
+    {
+        Generator::new(move |seed| {
+            let (value, seed) = step(&self, seed);
+            (f(value), seed)
+        })
+    }
+
+    /// Chains a generator that depends on the generated value.
+    pub fn and_then<U, F>(self, f: F) -> Generator<U>
+    where
+        U: 'static,
+        F: Fn(T) -> Generator<U> + 'static,
+    {
+        Generator::new(move |seed| {
+            let (value, seed) = step(&self, seed);
+            step(&f(value), seed)
+        })
+    }
+}
+
+/// Generates a value and returns it along with the next seed.
+pub fn step<T>(generator: &Generator<T>, seed: Seed) -> (T, Seed) {
+    (generator.run)(seed)
+}
+
+pub fn constant<T>(value: T) -> Generator<T>
+where
+    T: Clone + 'static,
+{
+    Generator::new(move |seed| (value.clone(), seed))
+}
+
+/// Generates an integer between `min` and `max`, both inclusive.
+pub fn int(min: i64, max: i64) -> Generator<i64> {
+    let (min, max) = if min <= max { (min, max) } else { (max, min) };
+    let range = (i128::from(max) - i128::from(min) + 1) as u128;
+
+    Generator::new(move |seed| {
+        if range > u128::from(u64::MAX) {
+            let (value, seed) = seed.next_u64();
+            return (value as i64, seed);
+        }
+
+        let (offset, seed) = seed.next_below(range as u64);
+        ((i128::from(min) + i128::from(offset)) as i64, seed)
+    })
+}
+
+/// Generates a float between `min` (inclusive) and `max` (exclusive).
+pub fn float(min: f64, max: f64) -> Generator<f64> {
+    Generator::new(move |seed| {
+        let (value, seed) = seed.next_f64();
+        (min + value * (max - min), seed)
+    })
+}
+
+pub fn bool() -> Generator<bool> {
+    Generator::new(|seed| {
+        let (value, seed) = seed.next_u32();
+        (value >> 31 == 1, seed)
+    })
+}
+
+/// Picks one of `items` with equal probability, or `None` when empty.
+pub fn choose<T>(items: Vec<T>) -> Generator<Option<T>>
+where
+    T: Clone + 'static,
+{
+    Generator::new(move |seed| {
+        if items.is_empty() {
+            return (None, seed);
+        }
+
+        let (index, seed) = seed.next_below(items.len() as u64);
+        (items.get(index as usize).cloned(), seed)
+    })
+}
+
+/// Picks one of `items` with a probability proportional to its weight.
+/// Items without a positive weight are never picked.
+pub fn weighted<T>(items: Vec<(f64, T)>) -> Generator<Option<T>>
+where
+    T: Clone + 'static,
+{
+    let items: Vec<(f64, T)> = items
+        .into_iter()
+        .filter(|(weight, _)| *weight > 0.0)
+        .collect();
+
+    let total: f64 = items.iter().map(|(weight, _)| weight).sum();
+
+    Generator::new(move |seed| {
+        let (value, seed) = seed.next_f64();
+        let mut remaining = value * total;
+
+        for (weight, item) in &items {
+            if remaining < *weight {
+                return (Some(item.clone()), seed);
+            }
+
+            remaining -= weight;
+        }
+
+        (items.last().map(|(_, item)| item.clone()), seed)
+    })
+}
+
+pub fn shuffle<T>(items: Vec<T>) -> Generator<Vec<T>>
+where
+    T: Clone + 'static,
+{
+    Generator::new(move |seed| {
+        let mut items = items.clone();
+        let mut seed = seed;
+
+        for i in (1..items.len()).rev() {
+            let (j, next_seed) = seed.next_below(i as u64 + 1);
+            seed = next_seed;
+            items.swap(i, j as usize);
+        }
+
+        (items, seed)
+    })
+}
+
+pub fn list<T>(length: usize, generator: Generator<T>) -> Generator<Vec<T>>
+where
+    T: 'static,
+{
+    Generator::new(move |seed| {
+        let mut seed = seed;
+        let mut values = Vec::with_capacity(length);
+
+        for _ in 0..length {
+            let (value, next_seed) = step(&generator, seed);
+            seed = next_seed;
+            values.push(value);
+        }
+
+        (values, seed)
+    })
+}
+
+pub fn pair<A, B>(a: Generator<A>, b: Generator<B>) -> Generator<(A, B)>
+where
+    A: 'static,
+    B: 'static,
+{
+    Generator::new(move |seed| {
+        let (a, seed) = step(&a, seed);
+        let (b, seed) = step(&b, seed);
+        ((a, b), seed)
+    })
+}
+
+/// Generates a version 4 UUID, such as `1b4e28ba-2fa1-41d2-883f-0016d3cca427`.
+///
+/// The UUID is only as unpredictable as the seed, use a seed from
+/// `effect::random::new_seed` when the ids must not collide across sessions.
+pub fn uuid() -> Generator<String> {
+    Generator::new(|seed| {
+        let (high, seed) = seed.next_u64();
+        let (low, seed) = seed.next_u64();
+
+        let high = (high & !0xF000) | 0x4000;
+        let low = (low & !(0xC << 60)) | (0x8 << 60);
+
+        let uuid = format!(
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            high >> 32,
+            (high >> 16) & 0xFFFF,
+            high & 0xFFFF,
+            low >> 48,
+            low & 0xFFFF_FFFF_FFFF,
+        );
+
+        (uuid, seed)
+    })
+}
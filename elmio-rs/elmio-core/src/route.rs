@@ -1,4 +1,228 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
 pub trait Route {
     fn name(&self) -> String;
     fn path(&self) -> String;
 }
+
+/// A route that can be parsed back from a path or a URL.
+///
+/// Usually implemented with `#[derive(elmio_macro::Route)]`.
+pub trait ParseRoute: Route + Sized {
+    /// Parses a path, optionally followed by a query string and a fragment.
+    fn from_path(path: &str) -> Result<Self, RouteError>;
+
+    /// Parses an absolute URL such as `https://example.com/users/1?tab=posts`.
+    fn from_url(url: &str) -> Result<Self, RouteError> {
+        Self::from_path(&path_from_url(url))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RouteError {
+    NotFound(String),
+    MissingQuery(String),
+    InvalidQuery { name: String, value: String },
+}
+
+impl fmt::Display for RouteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RouteError::NotFound(path) => write!(f, "no route matches '{}'", path),
+            RouteError::MissingQuery(name) => write!(f, "missing query parameter '{}'", name),
+            RouteError::InvalidQuery { name, value } => {
+                write!(
+                    f,
+                    "invalid value '{}' for query parameter '{}'",
+                    value, name
+                )
+            }
+        }
+    }
+}
+
+/// A decoded path and query string, matched against route patterns.
+pub struct RoutePath {
+    segments: Vec<String>,
+    query: Vec<(String, String)>,
+}
+
+impl RoutePath {
+    pub fn parse(path: &str) -> RoutePath {
+        let path = path.split('#').next().unwrap_or_default();
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+
+        let segments = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(decode_segment)
+            .collect();
+
+        let query = query
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (decode(name), decode(value))
+            })
+            .collect();
+
+        RoutePath { segments, query }
+    }
+
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    /// Matches the path against pattern segments, where segments starting
+    /// with `:` capture a value. Returns the captured values in order.
+    pub fn matches(&self, pattern: &[&str]) -> Option<Vec<&str>> {
+        if pattern.len() != self.segments.len() {
+            return None;
+        }
+
+        let mut captures = Vec::new();
+
+        for (expected, actual) in pattern.iter().zip(&self.segments) {
+            if expected.starts_with(':') {
+                captures.push(actual.as_str());
+            } else if expected != actual {
+                return None;
+            }
+        }
+
+        Some(captures)
+    }
+
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn required_query<T>(&self, name: &str) -> Result<T, RouteError>
+    where
+        T: FromStr,
+    {
+        self.optional_query(name)?
+            .ok_or_else(|| RouteError::MissingQuery(name.to_string()))
+    }
+
+    pub fn optional_query<T>(&self, name: &str) -> Result<Option<T>, RouteError>
+    where
+        T: FromStr,
+    {
+        match self.query(name) {
+            Some(value) => value
+                .parse()
+                .map(Some)
+                .map_err(|_| RouteError::InvalidQuery {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                }),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Returns the path, query and fragment of an absolute URL. The path is `/`
+/// when the URL has none, like `https://example.com?tab=posts`.
+pub fn path_from_url(url: &str) -> Cow<'_, str> {
+    let Some((_, rest)) = url
+        .split_once("://")
+        .filter(|(scheme, _)| is_scheme(scheme))
+    else {
+        return Cow::Borrowed(url);
+    };
+
+    match rest.find(['/', '?', '#']) {
+        Some(index) if rest[index..].starts_with('/') => Cow::Borrowed(&rest[index..]),
+        Some(index) => Cow::Owned(format!("/{}", &rest[index..])),
+        None => Cow::Borrowed("/"),
+    }
+}
+
+// Whether `s` is a URL scheme like `https`, so that a path with `://` in its
+// query, like `/search?q=http://a.com`, isn't mistaken for an absolute URL.
+fn is_scheme(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic())
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Appends the given parameters to `path` as a query string.
+pub fn with_query(path: String, query: &[(&str, String)]) -> String {
+    if query.is_empty() {
+        return path;
+    }
+
+    let query: Vec<String> = query
+        .iter()
+        .map(|(name, value)| format!("{}={}", encode(name), encode(value)))
+        .collect();
+
+    format!("{}?{}", path, query.join("&"))
+}
+
+/// Percent-encodes everything but unreserved characters.
+pub fn encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    encoded
+}
+
+/// Decodes a query string component, percent-encoded characters and `+` as
+/// a space.
+pub fn decode(s: &str) -> String {
+    percent_decode(s, true)
+}
+
+/// Decodes a path segment. Unlike in a query string, `+` is kept as is.
+pub fn decode_segment(s: &str) -> String {
+    percent_decode(s, false)
+}
+
+fn percent_decode(s: &str, plus_as_space: bool) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        let escaped = (byte == b'%')
+            .then(|| s.get(index + 1..index + 3))
+            .flatten()
+            .filter(|hex| hex.bytes().all(|byte| byte.is_ascii_hexdigit()))
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match (byte, escaped) {
+            (_, Some(escaped)) => {
+                decoded.push(escaped);
+                index += 3;
+            }
+            (b'+', None) if plus_as_space => {
+                decoded.push(b' ');
+                index += 1;
+            }
+            _ => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
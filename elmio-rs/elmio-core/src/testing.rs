@@ -0,0 +1,508 @@
+pub mod event;
+mod event_source;
+pub mod form;
+mod http;
+mod navigation;
+pub mod selector;
+mod timer;
+mod websocket;
+
+use crate::browser::WindowSize;
+use crate::browser::effect::Effect;
+use crate::browser::effect::browser::Browser;
+use crate::browser::effect::dom::Dom;
+use crate::browser::effect::effectful_msg::EffectfulMsg;
+use crate::browser::effect::navigation::Navigation;
+use crate::browser::file::FileInfo;
+use crate::browser::subscription::Subscription;
+use crate::browser::subscription::SubscriptionMsg;
+use crate::browser::subscription::event_listener::EventListener;
+use crate::browser::subscription::event_listener::EventMatcher;
+use crate::browser::subscription::event_listener::ListenTarget;
+use crate::browser::subscription::navigation::Location;
+use crate::html::Document;
+use crate::html::NodeId;
+use crate::page::JsMsg;
+use crate::page::Page;
+use crate::testing::event::KeySequences;
+use crate::testing::event::SimulatedEvent;
+use crate::testing::event::event_payload;
+use crate::testing::form::element_value;
+use crate::testing::navigation::url_request;
+use crate::testing::selector::SelectorList;
+use crate::testing::timer::named_timer_key;
+use crate::time::Posix;
+use crate::vdom::Patch;
+use crate::vdom::RenderedView;
+use std::marker::PhantomData;
+
+const PLACEHOLDER_VALUE: &str = "$CAPTURE_VALUE";
+
+/// Runs a `Page` natively, outside of wasm and the browser.
+///
+/// Every emitted effect is flattened and queued so tests can assert on it,
+/// and effectful messages can be resolved with fake values the same way
+/// elmio-js resolves them at runtime.
+pub struct PageTester<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+{
+    page: P,
+    model: Model,
+    effects: Vec<Effect<Msg>>,
+    window_size: WindowSize,
+    location: Location,
+    frame_timestamp: Posix,
+    key_sequences: KeySequences,
+    rendered: RenderedView,
+    markup: PhantomData<Markup>,
+}
+
+impl<P, Model, Msg, Markup> PageTester<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+{
+    /// Initializes the page and queues the effects returned by `init`.
+    pub fn new(page: P) -> Result<Self, String> {
+        let (model, effect) = page.init()?;
+
+        let mut tester = Self {
+            page,
+            model,
+            effects: Vec::new(),
+            window_size: WindowSize {
+                width: 1024,
+                height: 768,
+            },
+            location: Location {
+                url: "http://localhost/".to_string(),
+                ..Location::default()
+            },
+            frame_timestamp: Posix::from_millis(0),
+            key_sequences: KeySequences::default(),
+            rendered: RenderedView::default(),
+            markup: PhantomData,
+        };
+
+        tester.queue(effect.into_vec());
+        Ok(tester)
+    }
+
+    pub fn page(&self) -> &P {
+        &self.page
+    }
+
+    pub fn model(&self) -> &Model {
+        &self.model
+    }
+
+    pub fn model_mut(&mut self) -> &mut Model {
+        &mut self.model
+    }
+
+    /// Returns the effects that have not been taken or resolved yet.
+    pub fn effects(&self) -> &[Effect<Msg>] {
+        &self.effects
+    }
+
+    /// Removes and returns all queued effects.
+    pub fn take_effects(&mut self) -> Vec<Effect<Msg>> {
+        std::mem::take(&mut self.effects)
+    }
+
+    /// Runs `update` with the given message and queues the returned effects.
+    pub fn update(&mut self, msg: &Msg) -> Result<(), String> {
+        let effect = self.page.update(msg, &mut self.model)?;
+        self.queue(effect.into_vec());
+        Ok(())
+    }
+
+    /// Runs `update_from_js` with the given message and queues the returned effects.
+    pub fn update_from_js(&mut self, msg: JsMsg) -> Result<(), String> {
+        let effect = self.page.update_from_js(msg, &mut self.model)?;
+        self.queue(effect.into_vec());
+        Ok(())
+    }
+
+    // Queues `effects`, dropping pending named timers that are cleared or
+    // replaced the same way elmio-js cancels them.
+    fn queue(&mut self, effects: Vec<Effect<Msg>>) {
+        for effect in effects {
+            let cancelled_key = match &effect {
+                Effect::Browser(Browser::ClearTimeout { key }) => Some(key.clone()),
+                _ => named_timer_key(&effect).map(str::to_string),
+            };
+
+            if let Some(key) = cancelled_key {
+                self.effects
+                    .retain(|queued| named_timer_key(queued) != Some(key.as_str()));
+            }
+
+            self.effects.push(effect);
+        }
+    }
+
+    pub fn subscriptions(&self) -> Vec<Subscription<Msg>> {
+        self.page.subscriptions(&self.model).into_vec()
+    }
+
+    /// Renders the full document, like `wasm::view`.
+    pub fn view(&self) -> String {
+        let markup = self.page.view(&self.model);
+        self.page.render_page(markup)
+    }
+
+    /// Renders the body markup, like `wasm::view_body`.
+    pub fn view_body(&self) -> String {
+        let markup = self.page.view(&self.model);
+        self.page.render(markup.body)
+    }
+
+    /// Returns the patches that turn the body rendered by the previous call
+    /// into the current one, like `StatefulPage::view_patches`.
+    pub fn view_patches(&mut self) -> Vec<Patch> {
+        let html = self.view_body();
+        self.rendered.patches(html)
+    }
+}
+
+impl<P, Model, Msg, Markup> PageTester<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+    Msg: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Returns the queued effectful messages in the order they were emitted.
+    pub fn effectful_msgs(&self) -> Vec<&EffectfulMsg<Msg>> {
+        self.effects
+            .iter()
+            .filter_map(|effect| match effect {
+                Effect::EffectfulMsg(effectful_msg) => Some(effectful_msg.as_ref()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Removes the first queued effectful message, fills its captures with
+    /// `value` and sends the resulting message to `update`.
+    ///
+    /// Returns the effect that the runtime would have executed to obtain the value.
+    pub fn resolve_next<T>(&mut self, value: T) -> Result<Effect<Msg>, String>
+    where
+        T: serde::Serialize,
+    {
+        let index = self
+            .effects
+            .iter()
+            .position(|effect| matches!(effect, Effect::EffectfulMsg(_)))
+            .ok_or("No effectful message to resolve")?;
+
+        let effectful_msg = match self.effects.remove(index) {
+            Effect::EffectfulMsg(effectful_msg) => *effectful_msg,
+            _ => unreachable!(),
+        };
+
+        let msg = resolve_capture(&effectful_msg.msg, value)?;
+        self.update(&msg)?;
+
+        Ok(effectful_msg.effect)
+    }
+
+    /// Parses the markup currently rendered by `view_body`.
+    pub fn document(&self) -> Document {
+        Document::parse(&self.view_body())
+    }
+
+    /// Returns the ids of event listeners whose selectors match no element
+    /// in the rendered markup, usually a sign of a stale `DomId`.
+    pub fn unrendered_subscriptions(&self) -> Vec<String> {
+        let document = self.document();
+
+        self.event_listeners()
+            .into_iter()
+            .filter(|listener| {
+                listener.matchers.iter().any(|matcher| match matcher {
+                    EventMatcher::ExactSelector { selector }
+                    | EventMatcher::ClosestSelector { selector } => {
+                        SelectorList::from(selector.clone())
+                            .query(&document)
+                            .is_none()
+                    }
+                    _ => false,
+                })
+            })
+            .map(|listener| listener.id)
+            .collect()
+    }
+
+    /// Dispatches `event` to every matching event listener and returns how
+    /// many listeners handled it.
+    ///
+    /// Window listeners run before document listeners, and a listener that
+    /// stops propagation prevents document listeners from running.
+    /// Rate limits are not simulated, every matching event is dispatched.
+    pub fn dispatch(
+        &mut self,
+        document: &Document,
+        event: &SimulatedEvent,
+    ) -> Result<usize, String> {
+        let (window_listeners, document_listeners): (Vec<_>, Vec<_>) = self
+            .event_listeners()
+            .into_iter()
+            .filter(|listener| event.matches(document, listener, &mut self.key_sequences))
+            .partition(|listener| listener.listen_target == ListenTarget::Window);
+
+        let mut handled = 0;
+
+        for listeners in [window_listeners, document_listeners] {
+            let mut stopped = false;
+
+            for listener in listeners {
+                handled += 1;
+                stopped |= listener.propagation.stop_propagation;
+
+                match listener.msg {
+                    SubscriptionMsg::Effectful {
+                        msg,
+                        effect: Effect::Dom(Dom::GetEventPayload { kind }),
+                    } => {
+                        let value = event_payload(document, event, kind)?;
+                        self.update(&resolve_capture(&msg, value)?)?;
+                    }
+
+                    msg => self.dispatch_subscription_msg(document, event.target, msg)?,
+                }
+            }
+
+            if stopped {
+                break;
+            }
+        }
+
+        Ok(handled)
+    }
+
+    fn event_listeners(&self) -> Vec<EventListener<Msg>> {
+        self.subscriptions()
+            .into_iter()
+            .filter_map(|subscription| match subscription {
+                Subscription::EventListener(listener) => Some(listener),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn fire(
+        &mut self,
+        selector: &str,
+        to_event: impl FnOnce(NodeId) -> SimulatedEvent,
+    ) -> Result<(), String> {
+        let document = self.document();
+        let target = query(&document, selector)?;
+        self.fire_in(&document, &to_event(target), selector)
+    }
+
+    fn fire_in(
+        &mut self,
+        document: &Document,
+        event: &SimulatedEvent,
+        target: &str,
+    ) -> Result<(), String> {
+        if self.dispatch(document, event)? == 0 {
+            let event_type = serde_json::to_value(&event.event_type).unwrap_or_default();
+
+            return Err(format!(
+                "No subscription handled the {} event on '{}'",
+                event_type, target
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn dispatch_subscription_msg(
+        &mut self,
+        document: &Document,
+        target: Option<NodeId>,
+        msg: SubscriptionMsg<Msg>,
+    ) -> Result<(), String> {
+        match msg {
+            SubscriptionMsg::Pure(msg) => self.update(&msg),
+
+            SubscriptionMsg::Effectful { msg, effect } => {
+                let value = self.run_effect(document, target, &effect)?;
+                let msg = resolve_capture(&msg, value)?;
+                self.update(&msg)
+            }
+        }
+    }
+
+    // Produces the value elmio-js would capture for `effect`, using the
+    // rendered markup in place of the real DOM.
+    fn run_effect(
+        &self,
+        document: &Document,
+        target: Option<NodeId>,
+        effect: &Effect<Msg>,
+    ) -> Result<serde_json::Value, String> {
+        let dom = match effect {
+            Effect::Dom(dom) => dom,
+            Effect::Navigation(Navigation::GetLocation) => {
+                return serde_json::to_value(&self.location)
+                    .map_err(|err| format!("Failed to encode location: {}", err));
+            }
+            Effect::Navigation(Navigation::GetUrlRequest) => {
+                let href = target
+                    .and_then(|target| {
+                        SelectorList::parse("a[href]")
+                            .ok()?
+                            .closest(document, target)
+                    })
+                    .and_then(|id| document.element(id)?.attribute("href"))
+                    .ok_or("No link at the event target")?;
+
+                return serde_json::to_value(url_request(&self.location.url, href))
+                    .map_err(|err| format!("Failed to encode url request: {}", err));
+            }
+            _ => {
+                let effect = serde_json::to_string(effect).unwrap_or_default();
+                return Err(format!("Cannot run effect natively: {}", effect));
+            }
+        };
+
+        match dom {
+            Dom::GetElementValue {
+                element_id,
+                parse_as_json,
+            } => {
+                let id = document
+                    .element_by_id(element_id)
+                    .ok_or_else(|| format!("No element with id '{}'", element_id))?;
+
+                parse_value(element_value(document, id), *parse_as_json)
+            }
+
+            Dom::GetRadioGroupValue {
+                selector,
+                parse_as_json,
+            } => {
+                let checked = SelectorList::from(selector.clone())
+                    .query_all(document)
+                    .into_iter()
+                    .filter_map(|id| document.element(id))
+                    .find(|element| element.has_attribute("checked"))
+                    .map(|element| element.attribute("value").unwrap_or("on").to_string());
+
+                match checked {
+                    Some(value) => parse_value(value, *parse_as_json),
+                    None => Ok(serde_json::Value::Null),
+                }
+            }
+
+            Dom::GetTargetDataValue {
+                name,
+                parse_as_json,
+            } => {
+                let attribute = format!("data-{}", name);
+                let value = target
+                    .and_then(|target| {
+                        SelectorList::parse(&format!("[{}]", attribute))
+                            .ok()?
+                            .closest(document, target)
+                    })
+                    .and_then(|id| document.element(id)?.attribute(&attribute))
+                    .map(str::to_string);
+
+                match value {
+                    Some(value) => parse_value(value, *parse_as_json),
+                    None => Ok(serde_json::Value::Null),
+                }
+            }
+
+            Dom::GetFiles { .. } => Ok(serde_json::to_value(Vec::<FileInfo>::new())
+                .map_err(|err| format!("Failed to encode files: {}", err))?),
+
+            Dom::GetFormData { element_id } => {
+                let id = document
+                    .element_by_id(element_id)
+                    .filter(|id| document.element(*id).is_some_and(|e| e.tag == "form"))
+                    .ok_or_else(|| format!("No form with id '{}'", element_id))?;
+
+                Ok(form::form_data(document, id))
+            }
+
+            Dom::GetWindowSize => serde_json::to_value(&self.window_size)
+                .map_err(|err| format!("Failed to encode window size: {}", err)),
+
+            Dom::GetEventPayload { .. } => {
+                Err("Event payloads can only be read from an event listener".to_string())
+            }
+
+            Dom::FocusElement { .. } | Dom::SelectInputText { .. } | Dom::DispatchEvent { .. } => {
+                Ok(serde_json::Value::Null)
+            }
+        }
+    }
+}
+
+fn query(document: &Document, selector: &str) -> Result<NodeId, String> {
+    SelectorList::parse(selector)?
+        .query(document)
+        .ok_or_else(|| format!("No element matches selector '{}'", selector))
+}
+
+fn parse_value(value: String, parse_as_json: bool) -> Result<serde_json::Value, String> {
+    if parse_as_json {
+        serde_json::from_str(&value).map_err(|err| format!("Failed to parse json: {}", err))
+    } else {
+        Ok(serde_json::Value::String(value))
+    }
+}
+
+/// Replaces every `Capture` placeholder in `msg` with `value`.
+///
+/// Mirrors how elmio-js fills in captured values: only messages that
+/// serialize to an object or array are rewritten.
+pub fn resolve_capture<Msg, T>(msg: &Msg, value: T) -> Result<Msg, String>
+where
+    Msg: serde::Serialize + serde::de::DeserializeOwned,
+    T: serde::Serialize,
+{
+    let json_msg =
+        serde_json::to_value(msg).map_err(|err| format!("Failed to encode msg: {}", err))?;
+
+    if !(json_msg.is_object() || json_msg.is_array()) {
+        return serde_json::from_value(json_msg)
+            .map_err(|err| format!("Failed to decode msg: {}", err));
+    }
+
+    let json_value =
+        serde_json::to_value(value).map_err(|err| format!("Failed to encode value: {}", err))?;
+
+    serde_json::from_value(replace_placeholder(json_msg, &json_value))
+        .map_err(|err| format!("Failed to decode msg: {}", err))
+}
+
+fn replace_placeholder(
+    old_value: serde_json::Value,
+    new_value: &serde_json::Value,
+) -> serde_json::Value {
+    match old_value {
+        serde_json::Value::String(s) if s == PLACEHOLDER_VALUE => new_value.clone(),
+
+        serde_json::Value::Array(values) => serde_json::Value::Array(
+            values
+                .into_iter()
+                .map(|value| replace_placeholder(value, new_value))
+                .collect(),
+        ),
+
+        serde_json::Value::Object(entries) => serde_json::Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key, replace_placeholder(value, new_value)))
+                .collect(),
+        ),
+
+        _ => old_value,
+    }
+}
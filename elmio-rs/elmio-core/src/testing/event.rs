@@ -0,0 +1,467 @@
+use crate::browser::WindowSize;
+use crate::browser::event::EventPayloadKind;
+use crate::browser::event::FocusData;
+use crate::browser::event::Modifiers;
+use crate::browser::event::MouseData;
+use crate::browser::event::PointerData;
+use crate::browser::event::ScrollData;
+use crate::browser::event::TouchData;
+use crate::browser::event::WheelData;
+use crate::browser::keyboard::Chord;
+use crate::browser::keyboard::KeyPress;
+use crate::browser::keyboard::NamedKey;
+use crate::browser::keyboard::Shortcut;
+use crate::browser::mouse::Button;
+use crate::browser::subscription::event_listener::EventListener;
+use crate::browser::subscription::event_listener::EventMatcher;
+use crate::browser::subscription::event_listener::EventType;
+use crate::browser::subscription::event_listener::ModifierKey;
+use crate::html::Document;
+use crate::html::NodeId;
+use crate::page::Page;
+use crate::testing::PageTester;
+use crate::testing::query;
+use crate::testing::selector::SelectorList;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+/// An event dispatched against the rendered markup of a page.
+#[derive(Clone)]
+pub struct SimulatedEvent {
+    pub event_type: EventType,
+    pub target: Option<NodeId>,
+    pub button: Option<Button>,
+    pub key: Option<String>,
+    pub code: Option<String>,
+    pub modifiers: Modifiers,
+    /// The payload read by `dom::get_event_payload`, `Null` to derive a
+    /// default payload from the markup.
+    pub payload: serde_json::Value,
+}
+
+impl SimulatedEvent {
+    pub fn new(event_type: EventType, target: Option<NodeId>) -> Self {
+        Self {
+            event_type,
+            target,
+            button: None,
+            key: None,
+            code: None,
+            modifiers: Modifiers::default(),
+            payload: serde_json::Value::Null,
+        }
+    }
+
+    pub fn mouse(event_type: EventType, target: NodeId, button: Button) -> Self {
+        Self {
+            button: Some(button),
+            ..Self::new(event_type, Some(target))
+        }
+    }
+
+    /// Creates a keyboard event, `code` being a value of `KeyboardEvent.code`.
+    /// The key is the one a US keyboard produces for `code`.
+    pub fn keyboard(
+        event_type: EventType,
+        target: Option<NodeId>,
+        code: &str,
+        modifier: &ModifierKey,
+    ) -> Self {
+        let modifiers = modifier.modifiers();
+
+        Self {
+            key: Some(key_for_code(code, modifiers.shift)),
+            code: Some(code.to_string()),
+            modifiers,
+            ..Self::new(event_type, target)
+        }
+    }
+
+    /// Creates the keyboard event pressing `chord` on a US keyboard.
+    pub fn chord(event_type: EventType, target: Option<NodeId>, chord: &Chord) -> Self {
+        let (key, code) = match &chord.key {
+            KeyPress::Character(c) => {
+                let key = if chord.modifiers.shift {
+                    c.to_uppercase()
+                } else {
+                    c.clone()
+                };
+                let code = c.chars().next().map(code_for_char).unwrap_or_default();
+
+                (key, code)
+            }
+            KeyPress::Named(key) => (key.to_string(), key.to_string()),
+            KeyPress::Code(code) => (
+                key_for_code(code.as_str(), chord.modifiers.shift),
+                code.to_string(),
+            ),
+        };
+
+        Self {
+            key: Some(key),
+            code: Some(code),
+            modifiers: chord.modifiers,
+            ..Self::new(event_type, target)
+        }
+    }
+
+    pub fn with_payload<T>(self, payload: T) -> Result<Self, String>
+    where
+        T: serde::Serialize,
+    {
+        let payload = serde_json::to_value(payload)
+            .map_err(|err| format!("Failed to encode event payload: {}", err))?;
+
+        Ok(Self { payload, ..self })
+    }
+
+    pub fn matches<Msg>(
+        &self,
+        document: &Document,
+        listener: &EventListener<Msg>,
+        sequences: &mut KeySequences,
+    ) -> bool {
+        listener.event_type == self.event_type
+            && listener
+                .matchers
+                .iter()
+                .all(|matcher| self.matches_matcher(document, matcher, &listener.id, sequences))
+    }
+
+    fn matches_matcher(
+        &self,
+        document: &Document,
+        matcher: &EventMatcher,
+        listener_id: &str,
+        sequences: &mut KeySequences,
+    ) -> bool {
+        match matcher {
+            EventMatcher::ExactSelector { selector } => self.target.is_some_and(|target| {
+                SelectorList::from(selector.clone()).matches(document, target)
+            }),
+
+            EventMatcher::ClosestSelector { selector } => self.target.is_some_and(|target| {
+                SelectorList::from(selector.clone())
+                    .closest(document, target)
+                    .is_some()
+            }),
+
+            EventMatcher::MouseButton { button } => self.button.as_ref() == Some(button),
+
+            EventMatcher::LinkClick => {
+                let link = self.target.and_then(|target| {
+                    let selector = SelectorList::parse("a[href]").ok()?;
+                    document.element(selector.closest(document, target)?)
+                });
+
+                link.is_some_and(|link| {
+                    self.modifiers == Modifiers::default()
+                        && !link.has_attribute("download")
+                        && link
+                            .attribute("target")
+                            .is_none_or(|t| t.is_empty() || t == "_self")
+                })
+            }
+
+            EventMatcher::KeyboardKey {
+                key,
+                requires_ctrl,
+                requires_meta,
+                requires_shift,
+                requires_alt,
+            } => self.code.as_ref().is_some_and(|code| {
+                key.matches(code)
+                    && (!requires_ctrl || self.modifiers.ctrl)
+                    && (!requires_meta || self.modifiers.meta)
+                    && (!requires_shift || self.modifiers.shift)
+                    && (!requires_alt || self.modifiers.alt)
+            }),
+
+            EventMatcher::Chord(chord) => match (&self.key, &self.code) {
+                (Some(key), Some(code)) => chord.matches(key, code, &self.modifiers),
+                _ => false,
+            },
+
+            EventMatcher::KeySequence { chords, .. } => {
+                sequences.advance(listener_id, chords, self)
+            }
+        }
+    }
+}
+
+/// The progress of key sequence listeners, by listener id. Timeouts between
+/// chords are not simulated.
+#[derive(Default)]
+pub struct KeySequences(HashMap<String, usize>);
+
+impl KeySequences {
+    // Moves the sequence forward when `event` presses its next chord, and
+    // returns whether that completed it.
+    fn advance(&mut self, listener_id: &str, chords: &[Chord], event: &SimulatedEvent) -> bool {
+        let (Some(key), Some(code)) = (&event.key, &event.code) else {
+            return false;
+        };
+
+        if is_modifier_key(key) {
+            return false;
+        }
+
+        let presses = |chord: &Chord| chord.matches(key, code, &event.modifiers);
+        let progress = self.0.entry(listener_id.to_string()).or_default();
+
+        *progress = match chords.get(*progress) {
+            Some(chord) if presses(chord) => *progress + 1,
+            _ if chords.first().is_some_and(presses) => 1,
+            _ => 0,
+        };
+
+        if *progress == chords.len() {
+            *progress = 0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn is_modifier_key(key: &str) -> bool {
+    [
+        NamedKey::Alt,
+        NamedKey::AltGraph,
+        NamedKey::Control,
+        NamedKey::Meta,
+        NamedKey::Shift,
+    ]
+    .iter()
+    .any(|modifier| modifier.as_str() == key)
+}
+
+/// Returns the `KeyboardEvent.key` a US keyboard produces for `code`,
+/// ignoring the symbols Shift produces on digit and punctuation keys.
+pub fn key_for_code(code: &str, shift: bool) -> String {
+    let character = code
+        .strip_prefix("Key")
+        .or_else(|| code.strip_prefix("Digit"))
+        .or_else(|| code.strip_prefix("Numpad"))
+        .filter(|rest| rest.len() == 1);
+
+    if let Some(character) = character {
+        return if shift {
+            character.to_string()
+        } else {
+            character.to_lowercase()
+        };
+    }
+
+    let key = match code {
+        "Space" => " ",
+        "Minus" => "-",
+        "Equal" => "=",
+        "Comma" => ",",
+        "Period" => ".",
+        "Slash" => "/",
+        "Semicolon" => ";",
+        "Quote" => "'",
+        "BracketLeft" => "[",
+        "BracketRight" => "]",
+        "Backslash" => "\\",
+        "Backquote" => "`",
+        "NumpadEnter" => "Enter",
+        "ShiftLeft" | "ShiftRight" => "Shift",
+        "ControlLeft" | "ControlRight" => "Control",
+        "AltLeft" | "AltRight" => "Alt",
+        "MetaLeft" | "MetaRight" => "Meta",
+        _ => match code.parse::<NamedKey>() {
+            Ok(key) => return key.to_string(),
+            Err(_) => "Unidentified",
+        },
+    };
+
+    key.to_string()
+}
+
+/// Returns the `KeyboardEvent.code` a US keyboard produces for `c`.
+pub fn code_for_char(c: char) -> String {
+    match c {
+        'a'..='z' | 'A'..='Z' => format!("Key{}", c.to_ascii_uppercase()),
+        '0'..='9' => format!("Digit{}", c),
+        ' ' => "Space".to_string(),
+        '\n' => "Enter".to_string(),
+        '\t' => "Tab".to_string(),
+        '-' | '_' => "Minus".to_string(),
+        '=' | '+' => "Equal".to_string(),
+        ',' | '<' => "Comma".to_string(),
+        '.' | '>' => "Period".to_string(),
+        '/' | '?' => "Slash".to_string(),
+        ';' | ':' => "Semicolon".to_string(),
+        '\'' | '"' => "Quote".to_string(),
+        '[' | '{' => "BracketLeft".to_string(),
+        ']' | '}' => "BracketRight".to_string(),
+        '\\' | '|' => "Backslash".to_string(),
+        '`' | '~' => "Backquote".to_string(),
+        _ => "Unidentified".to_string(),
+    }
+}
+
+impl<P, Model, Msg, Markup> PageTester<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+    Msg: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Clicks the first element matching `selector`.
+    pub fn click(&mut self, selector: &str) -> Result<(), String> {
+        self.fire(selector, |target| {
+            SimulatedEvent::mouse(EventType::Click, target, Button::Main)
+        })
+    }
+
+    pub fn mouse_down(&mut self, selector: &str, button: Button) -> Result<(), String> {
+        self.fire(selector, |target| {
+            SimulatedEvent::mouse(EventType::Mousedown, target, button.clone())
+        })
+    }
+
+    pub fn submit(&mut self, selector: &str) -> Result<(), String> {
+        self.fire(selector, |target| {
+            SimulatedEvent::new(EventType::Submit, Some(target))
+        })
+    }
+
+    /// Presses a key on the document, `code` being a value of `KeyboardEvent.code`.
+    pub fn key_down(&mut self, code: &str, modifier: ModifierKey) -> Result<(), String> {
+        let event = SimulatedEvent::keyboard(EventType::Keydown, None, code, &modifier);
+        self.fire_in(&self.document(), &event, "document")
+    }
+
+    pub fn key_up(&mut self, code: &str, modifier: ModifierKey) -> Result<(), String> {
+        let event = SimulatedEvent::keyboard(EventType::Keyup, None, code, &modifier);
+        self.fire_in(&self.document(), &event, "document")
+    }
+
+    /// Presses a shortcut like `Ctrl+Shift+K` or `g g` on the document,
+    /// firing `keydown` and `keyup` for every chord.
+    pub fn press(&mut self, shortcut: &str) -> Result<(), String> {
+        let shortcut = Shortcut::parse(shortcut)?;
+        let mut handled = 0;
+
+        for chord in &shortcut.chords {
+            for event_type in [EventType::Keydown, EventType::Keyup] {
+                let event = SimulatedEvent::chord(event_type, None, chord);
+                handled += self.dispatch(&self.document(), &event)?;
+            }
+        }
+
+        if handled == 0 {
+            return Err(format!(
+                "No subscription handled the shortcut '{}'",
+                shortcut
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Fires `event_type` on the first element matching `selector`, with a
+    /// default payload carrying the `data-*` attributes of the element.
+    pub fn trigger(&mut self, selector: &str, event_type: EventType) -> Result<(), String> {
+        self.fire(selector, |target| {
+            SimulatedEvent::new(event_type, Some(target))
+        })
+    }
+
+    /// Fires `event_type` on the first element matching `selector`, e.g.
+    /// with `PointerData` for a pointer event.
+    pub fn trigger_with<T>(
+        &mut self,
+        selector: &str,
+        event_type: EventType,
+        payload: T,
+    ) -> Result<(), String>
+    where
+        T: serde::Serialize,
+    {
+        let document = self.document();
+        let target = query(&document, selector)?;
+        let event = SimulatedEvent::new(event_type, Some(target)).with_payload(payload)?;
+        self.fire_in(&document, &event, selector)
+    }
+
+    /// Scrolls the simulated document and fires `scroll` on the window.
+    pub fn scroll_window(&mut self, scroll: ScrollData) -> Result<(), String> {
+        let event = SimulatedEvent::new(EventType::Scroll, None).with_payload(scroll)?;
+        self.fire_in(&self.document(), &event, "window")
+    }
+
+    /// Resizes the simulated window and fires `resize`.
+    pub fn resize(&mut self, width: u32, height: u32) -> Result<(), String> {
+        self.window_size = WindowSize { width, height };
+
+        let event = SimulatedEvent::new(EventType::Resize, None);
+        self.fire_in(&self.document(), &event, "window")
+    }
+}
+
+// The payload given to the simulated event, or a default one carrying the
+// `data-*` attributes of the target, like elmio-js reads them.
+pub(super) fn event_payload(
+    document: &Document,
+    event: &SimulatedEvent,
+    kind: EventPayloadKind,
+) -> Result<serde_json::Value, String> {
+    if !event.payload.is_null() {
+        return Ok(event.payload.clone());
+    }
+
+    let data = event
+        .target
+        .map(|target| data_attributes(document, target))
+        .unwrap_or_default();
+
+    let mouse = MouseData {
+        data: data.clone(),
+        ..MouseData::default()
+    };
+
+    let value = match kind {
+        EventPayloadKind::Mouse => serde_json::to_value(mouse),
+        EventPayloadKind::Pointer => serde_json::to_value(PointerData {
+            mouse,
+            is_primary: true,
+            ..PointerData::default()
+        }),
+        EventPayloadKind::Wheel => serde_json::to_value(WheelData {
+            mouse,
+            ..WheelData::default()
+        }),
+        EventPayloadKind::Touch => serde_json::to_value(TouchData {
+            data,
+            ..TouchData::default()
+        }),
+        EventPayloadKind::Focus => serde_json::to_value(FocusData {
+            data,
+            ..FocusData::default()
+        }),
+        EventPayloadKind::Scroll => serde_json::to_value(ScrollData::default()),
+    };
+
+    value.map_err(|err| format!("Failed to encode event payload: {}", err))
+}
+
+fn data_attributes(document: &Document, target: NodeId) -> BTreeMap<String, String> {
+    let mut data = BTreeMap::new();
+    let mut current = Some(target);
+
+    while let Some(id) = current {
+        let attributes = document.element(id).into_iter().flat_map(|e| &e.attributes);
+
+        for (name, value) in attributes {
+            if let Some(key) = name.strip_prefix("data-") {
+                data.entry(key.to_string()).or_insert_with(|| value.clone());
+            }
+        }
+
+        current = document.parent_element(id);
+    }
+
+    data
+}
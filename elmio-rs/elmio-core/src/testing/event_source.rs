@@ -0,0 +1,83 @@
+use crate::browser::effect::Effect;
+use crate::browser::effect::event_source::EventSource as EventSourceEffect;
+use crate::browser::subscription::Subscription;
+use crate::browser::subscription::SubscriptionMsg;
+use crate::browser::subscription::event_source;
+use crate::browser::subscription::event_source::ServerEvent;
+use crate::page::Page;
+use crate::testing::PageTester;
+use crate::testing::parse_value;
+use crate::testing::resolve_capture;
+
+impl<P, Model, Msg, Markup> PageTester<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+    Msg: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Delivers a server event to the subscriptions listening to
+    /// `event_type` on `url`.
+    pub fn receive_server_event(
+        &mut self,
+        url: &str,
+        event_type: &str,
+        id: Option<&str>,
+        data: &str,
+    ) -> Result<(), String> {
+        let subscriptions = self.subscriptions();
+        event_source::check_shared_connections(&subscriptions)?;
+
+        let event_sources: Vec<_> = subscriptions
+            .into_iter()
+            .filter_map(|subscription| match subscription {
+                Subscription::EventSource(event_source)
+                    if event_source.url == url && event_source.options.event_type == event_type =>
+                {
+                    Some(event_source)
+                }
+                _ => None,
+            })
+            .collect();
+
+        if event_sources.is_empty() {
+            return Err(format!(
+                "No subscription to '{}' events on '{}'",
+                event_type, url
+            ));
+        }
+
+        for event_source in event_sources {
+            let msg = match event_source.msg {
+                SubscriptionMsg::Pure(msg) => msg,
+
+                SubscriptionMsg::Effectful { msg, effect } => {
+                    let value = match effect {
+                        Effect::EventSource(EventSourceEffect::GetEventData {
+                            parse_as_json,
+                            with_metadata,
+                        }) => {
+                            let data = parse_value(data.to_string(), parse_as_json)?;
+
+                            if with_metadata {
+                                serde_json::to_value(ServerEvent {
+                                    id: id.map(str::to_string),
+                                    event_type: event_type.to_string(),
+                                    data,
+                                })
+                                .map_err(|err| format!("Failed to encode server event: {}", err))?
+                            } else {
+                                data
+                            }
+                        }
+                        _ => self.run_effect(&self.document(), None, &effect)?,
+                    };
+
+                    resolve_capture(&msg, value)?
+                }
+            };
+
+            self.update(&msg)?;
+        }
+
+        Ok(())
+    }
+}
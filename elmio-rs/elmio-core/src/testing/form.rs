@@ -0,0 +1,348 @@
+use crate::browser::subscription::event_listener::EventType;
+use crate::browser::subscription::event_listener::ModifierKey;
+use crate::html::Document;
+use crate::html::NodeId;
+use crate::page::Page;
+use crate::testing::PageTester;
+use crate::testing::event;
+use crate::testing::event::SimulatedEvent;
+use crate::testing::query;
+use crate::testing::selector::SelectorList;
+use serde_json::Map;
+use serde_json::Value;
+
+/// Reads the fields of the `<form>` at `form` the way elmio-js does for
+/// `dom::get_form_data`, using the rendered markup as the form state.
+/// File inputs are read as empty lists.
+pub fn form_data(document: &Document, form: NodeId) -> Value {
+    let mut data = Map::new();
+
+    for id in document.elements() {
+        if !is_descendant(document, id, form) {
+            continue;
+        }
+
+        let Some(element) = document.element(id) else {
+            continue;
+        };
+
+        let Some(name) = element.attribute("name") else {
+            continue;
+        };
+
+        if element.has_attribute("disabled") {
+            continue;
+        }
+
+        let input_type = element.attribute("type").unwrap_or("text");
+        let checked = element.has_attribute("checked");
+
+        let value = match (element.tag.as_str(), input_type) {
+            ("input", "submit" | "button" | "reset" | "image") => continue,
+            ("textarea", _) => Value::String(element_value(document, id)),
+            ("select", _) if element.has_attribute("multiple") => {
+                let selected = document
+                    .elements()
+                    .into_iter()
+                    .filter(|option| is_descendant(document, *option, id))
+                    .filter(|option| {
+                        document.element(*option).is_some_and(|option| {
+                            option.tag == "option" && option.has_attribute("selected")
+                        })
+                    })
+                    .map(|option| Value::String(option_value(document, option)))
+                    .collect();
+
+                Value::Array(selected)
+            }
+            ("select", _) => Value::String(element_value(document, id)),
+            ("input", "checkbox") => match element.attribute("value") {
+                Some(value) => {
+                    let mut values = match field(&mut data, name) {
+                        Some(Value::Array(values)) => std::mem::take(values),
+                        _ => Vec::new(),
+                    };
+                    if checked {
+                        values.push(Value::String(value.to_string()));
+                    }
+
+                    Value::Array(values)
+                }
+                None => Value::Bool(checked),
+            },
+            ("input", "radio") => {
+                if checked {
+                    Value::String(element.attribute("value").unwrap_or("on").to_string())
+                } else if field(&mut data, name).is_some() {
+                    continue;
+                } else {
+                    Value::Null
+                }
+            }
+            ("input", "number" | "range") => number(element.attribute("value").unwrap_or("")),
+            ("input", "file") => Value::Array(Vec::new()),
+            ("input", _) => Value::String(element.attribute("value").unwrap_or("").to_string()),
+            _ => continue,
+        };
+
+        insert(&mut data, name, value);
+    }
+
+    Value::Object(data)
+}
+
+// Whole numbers are encoded as integers so they deserialize into integer
+// fields.
+fn number(value: &str) -> Value {
+    match value.trim().parse::<f64>() {
+        Ok(n) if n.is_finite() && n.fract() == 0.0 && n.abs() < i64::MAX as f64 => {
+            Value::from(n as i64)
+        }
+        Ok(n) => serde_json::Number::from_f64(n).map_or(Value::Null, Value::Number),
+        Err(_) => Value::Null,
+    }
+}
+
+// The current value of the field at the dotted `name`, if any.
+fn field<'a>(data: &'a mut Map<String, Value>, name: &str) -> Option<&'a mut Value> {
+    let mut parts = name.split('.');
+    let mut current = data.get_mut(parts.next()?)?;
+
+    for part in parts {
+        current = current.as_object_mut()?.get_mut(part)?;
+    }
+
+    Some(current)
+}
+
+fn insert(data: &mut Map<String, Value>, name: &str, value: Value) {
+    match name.split_once('.') {
+        Some((head, rest)) => {
+            let nested = data
+                .entry(head.to_string())
+                .or_insert_with(|| Value::Object(Map::new()));
+
+            if !nested.is_object() {
+                *nested = Value::Object(Map::new());
+            }
+
+            if let Value::Object(nested) = nested {
+                insert(nested, rest, value);
+            }
+        }
+        None => {
+            data.insert(name.to_string(), value);
+        }
+    }
+}
+
+impl<P, Model, Msg, Markup> PageTester<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+    Msg: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Sets the value of the first element matching `selector` and fires `input`.
+    pub fn input(&mut self, selector: &str, value: &str) -> Result<(), String> {
+        let mut document = self.document();
+        let target = query(&document, selector)?;
+        set_value(&mut document, target, value);
+
+        let event = SimulatedEvent::new(EventType::Input, Some(target));
+        self.fire_in(&document, &event, selector)
+    }
+
+    /// Sets the value of the first element matching `selector` and fires `change`.
+    pub fn change(&mut self, selector: &str, value: &str) -> Result<(), String> {
+        let mut document = self.document();
+        let target = query(&document, selector)?;
+        set_value(&mut document, target, value);
+
+        let event = SimulatedEvent::new(EventType::Change, Some(target));
+        self.fire_in(&document, &event, selector)
+    }
+
+    /// Checks the first checkbox or radio button matching `selector` and fires `change`.
+    pub fn check(&mut self, selector: &str) -> Result<(), String> {
+        let mut document = self.document();
+        let target = query(&document, selector)?;
+        set_checked(&mut document, target);
+
+        let event = SimulatedEvent::new(EventType::Change, Some(target));
+        self.fire_in(&document, &event, selector)
+    }
+
+    /// Types `text` into the first element matching `selector`, firing
+    /// `keydown`, `input` and `keyup` for every character.
+    pub fn type_text(&mut self, selector: &str, text: &str) -> Result<(), String> {
+        let document = self.document();
+        let target = query(&document, selector)?;
+        let mut value = element_value(&document, target);
+        let mut handled = 0;
+
+        for c in text.chars() {
+            let code = event::code_for_char(c);
+
+            let document = self.document();
+            let target = query(&document, selector)?;
+            let keydown = typed_key(
+                SimulatedEvent::keyboard(
+                    EventType::Keydown,
+                    Some(target),
+                    &code,
+                    &ModifierKey::None,
+                ),
+                c,
+            );
+            handled += self.dispatch(&document, &keydown)?;
+
+            value.push(c);
+
+            let mut document = self.document();
+            let target = query(&document, selector)?;
+            set_value(&mut document, target, &value);
+            handled += self.dispatch(
+                &document,
+                &SimulatedEvent::new(EventType::Input, Some(target)),
+            )?;
+
+            let mut document = self.document();
+            let target = query(&document, selector)?;
+            set_value(&mut document, target, &value);
+            let keyup = typed_key(
+                SimulatedEvent::keyboard(EventType::Keyup, Some(target), &code, &ModifierKey::None),
+                c,
+            );
+            handled += self.dispatch(&document, &keyup)?;
+        }
+
+        if handled == 0 {
+            return Err(format!(
+                "No subscription handled typing into '{}'",
+                selector
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+// Typing a character produces it as the key, whatever its code.
+fn typed_key(event: SimulatedEvent, c: char) -> SimulatedEvent {
+    if c.is_control() {
+        return event;
+    }
+
+    SimulatedEvent {
+        key: Some(c.to_string()),
+        ..event
+    }
+}
+
+// The value of a `textarea` is kept in its `value` attribute once it has
+// been set from a test, since the rendered text content no longer applies.
+pub(super) fn element_value(document: &Document, id: NodeId) -> String {
+    let Some(element) = document.element(id) else {
+        return String::new();
+    };
+
+    match element.tag.as_str() {
+        "textarea" => element
+            .attribute("value")
+            .map(str::to_string)
+            .unwrap_or_else(|| document.text_content(id)),
+
+        "select" => {
+            let options = SelectorList::parse("option")
+                .map(|selector| selector.query_all(document))
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|option| is_descendant(document, *option, id))
+                .collect::<Vec<_>>();
+
+            options
+                .iter()
+                .find(|option| {
+                    document
+                        .element(**option)
+                        .is_some_and(|element| element.has_attribute("selected"))
+                })
+                .or(options.first())
+                .map(|option| option_value(document, *option))
+                .unwrap_or_default()
+        }
+
+        _ => element.attribute("value").unwrap_or_default().to_string(),
+    }
+}
+
+fn option_value(document: &Document, id: NodeId) -> String {
+    document
+        .element(id)
+        .and_then(|element| element.attribute("value"))
+        .map(str::to_string)
+        .unwrap_or_else(|| document.text_content(id))
+}
+
+fn is_descendant(document: &Document, id: NodeId, ancestor: NodeId) -> bool {
+    let mut current = document.parent_element(id);
+
+    while let Some(parent) = current {
+        if parent == ancestor {
+            return true;
+        }
+        current = document.parent_element(parent);
+    }
+
+    false
+}
+
+fn set_value(document: &mut Document, id: NodeId, value: &str) {
+    let is_select = document
+        .element(id)
+        .is_some_and(|element| element.tag == "select");
+
+    if !is_select {
+        if let Some(element) = document.element_mut(id) {
+            element.set_attribute("value", value);
+        }
+        return;
+    }
+
+    for option in document.elements() {
+        if !is_descendant(document, option, id) {
+            continue;
+        }
+
+        let selected = option_value(document, option) == value;
+        if let Some(element) = document.element_mut(option) {
+            if selected {
+                element.set_attribute("selected", "");
+            } else {
+                element.remove_attribute("selected");
+            }
+        }
+    }
+}
+
+fn set_checked(document: &mut Document, id: NodeId) {
+    let radio_name = document
+        .element(id)
+        .filter(|element| element.attribute("type") == Some("radio"))
+        .and_then(|element| element.attribute("name"))
+        .map(str::to_string);
+
+    if let Some(name) = radio_name {
+        for other in document.elements() {
+            if let Some(element) = document.element_mut(other)
+                && element.attribute("type") == Some("radio")
+                && element.attribute("name") == Some(&name)
+            {
+                element.remove_attribute("checked");
+            }
+        }
+    }
+
+    if let Some(element) = document.element_mut(id) {
+        element.set_attribute("checked", "");
+    }
+}
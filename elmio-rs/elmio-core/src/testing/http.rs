@@ -0,0 +1,44 @@
+use crate::browser::effect::Effect;
+use crate::browser::effect::http::HttpError;
+use crate::browser::effect::http::Request;
+use crate::page::Page;
+use crate::testing::PageTester;
+use crate::testing::resolve_capture;
+
+impl<P, Model, Msg, Markup> PageTester<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+    Msg: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Removes the first queued HTTP request and delivers `result` as its
+    /// response, the way elmio-js does. A body that doesn't match the type
+    /// expected by the page is delivered as `HttpError::Decode`.
+    pub fn respond_http<T>(&mut self, result: Result<T, HttpError>) -> Result<Request, String>
+    where
+        T: serde::Serialize,
+    {
+        let index = self
+            .effects
+            .iter()
+            .position(|effect| match effect {
+                Effect::EffectfulMsg(effectful_msg) => {
+                    matches!(effectful_msg.effect, Effect::Http(_))
+                }
+                _ => false,
+            })
+            .ok_or("No HTTP request to respond to")?;
+
+        let effectful_msg = match self.effects.remove(index) {
+            Effect::EffectfulMsg(effectful_msg) => *effectful_msg,
+            _ => unreachable!(),
+        };
+
+        let msg = resolve_capture(&effectful_msg.msg, result)?;
+        self.update(&msg)?;
+
+        match effectful_msg.effect {
+            Effect::Http(request) => Ok(request),
+            _ => unreachable!(),
+        }
+    }
+}
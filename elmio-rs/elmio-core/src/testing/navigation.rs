@@ -0,0 +1,98 @@
+use crate::browser::subscription::Subscription;
+use crate::browser::subscription::navigation::Location;
+use crate::browser::subscription::navigation::UrlRequest;
+use crate::page::Page;
+use crate::testing::PageTester;
+
+impl<P, Model, Msg, Markup> PageTester<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+    Msg: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Changes the simulated URL the way the back and forward buttons do,
+    /// and fires the `on_url_change` subscriptions.
+    pub fn change_url(&mut self, url: &str) -> Result<(), String> {
+        self.location = Location {
+            url: url.to_string(),
+            ..Location::default()
+        };
+
+        let document = self.document();
+        let url_changes: Vec<_> = self
+            .subscriptions()
+            .into_iter()
+            .filter_map(|subscription| match subscription {
+                Subscription::UrlChange(url_change) => Some(url_change),
+                _ => None,
+            })
+            .collect();
+
+        if url_changes.is_empty() {
+            return Err(format!(
+                "No subscription handled the URL change to '{}'",
+                url
+            ));
+        }
+
+        for url_change in url_changes {
+            self.dispatch_subscription_msg(&document, None, url_change.msg)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Resolves `href` against `base` and classifies it by origin, covering the
+// URL forms found in markup rather than the full URL spec.
+pub(super) fn url_request(base: &str, href: &str) -> UrlRequest {
+    let origin_end = base
+        .find("://")
+        .map(|scheme_end| {
+            base[scheme_end + 3..]
+                .find('/')
+                .map_or(base.len(), |i| scheme_end + 3 + i)
+        })
+        .unwrap_or(0);
+    let origin = &base[..origin_end];
+    let scheme = base.split_once("://").map_or("http", |(scheme, _)| scheme);
+
+    let has_scheme = href
+        .split_once(':')
+        .is_some_and(|(scheme, _)| !scheme.is_empty() && !scheme.contains(['/', '?', '#']));
+
+    let url = if has_scheme {
+        href.to_string()
+    } else if let Some(rest) = href.strip_prefix("//") {
+        format!("{}://{}", scheme, rest)
+    } else if href.starts_with('/') {
+        format!("{}{}", origin, href)
+    } else if href.starts_with('?') {
+        let path = base.split(['?', '#']).next().unwrap_or(base);
+        format!("{}{}", path, href)
+    } else if href.starts_with('#') {
+        let path = base.split('#').next().unwrap_or(base);
+        format!("{}{}", path, href)
+    } else {
+        let path = base.split(['?', '#']).next().unwrap_or(base);
+        let dir_end = path
+            .rfind('/')
+            .filter(|i| *i >= origin_end)
+            .map_or(path.len(), |i| i + 1);
+        let dir = &path[..dir_end];
+        if dir.len() == origin.len() {
+            format!("{}/{}", origin, href)
+        } else {
+            format!("{}{}", dir, href)
+        }
+    };
+
+    let same_origin = url
+        .strip_prefix(origin)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '?', '#']));
+
+    if same_origin {
+        UrlRequest::Internal(url)
+    } else {
+        UrlRequest::External(url)
+    }
+}
@@ -0,0 +1,238 @@
+use crate::browser::selector::{
+    AttributeOperator, Combinator, Compound, Condition, PseudoClass, Selector,
+};
+use crate::html::Document;
+use crate::html::Element;
+use crate::html::NodeId;
+use crate::html::NodeKind;
+
+/// A selector matched against a parsed `Document`, supporting everything
+/// `Selector` can express.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectorList(Selector);
+
+impl SelectorList {
+    pub fn parse(selector: &str) -> Result<SelectorList, String> {
+        Selector::parse(selector).map(SelectorList)
+    }
+
+    pub fn matches(&self, document: &Document, id: NodeId) -> bool {
+        matches_selector(document, id, &self.0)
+    }
+
+    pub fn query_all(&self, document: &Document) -> Vec<NodeId> {
+        document
+            .elements()
+            .into_iter()
+            .filter(|id| self.matches(document, *id))
+            .collect()
+    }
+
+    pub fn query(&self, document: &Document) -> Option<NodeId> {
+        document
+            .elements()
+            .into_iter()
+            .find(|id| self.matches(document, *id))
+    }
+
+    pub fn closest(&self, document: &Document, id: NodeId) -> Option<NodeId> {
+        let mut current = Some(id);
+
+        while let Some(id) = current {
+            if self.matches(document, id) {
+                return Some(id);
+            }
+            current = document.parent_element(id);
+        }
+
+        None
+    }
+}
+
+impl From<Selector> for SelectorList {
+    fn from(selector: Selector) -> Self {
+        SelectorList(selector)
+    }
+}
+
+fn matches_selector(document: &Document, id: NodeId, selector: &Selector) -> bool {
+    selector
+        .0
+        .iter()
+        .any(|complex| matches_complex(document, id, &complex.0))
+}
+
+// Matches from the subject, the last compound, towards the left.
+fn matches_complex(document: &Document, id: NodeId, parts: &[(Combinator, Compound)]) -> bool {
+    let Some(((combinator, compound), rest)) = parts.split_last() else {
+        return true;
+    };
+
+    let Some(element) = document.element(id) else {
+        return false;
+    };
+
+    if !matches_compound(document, id, element, compound) {
+        return false;
+    }
+
+    match combinator {
+        Combinator::None => true,
+
+        Combinator::Child => document
+            .parent_element(id)
+            .is_some_and(|parent| matches_complex(document, parent, rest)),
+
+        Combinator::Descendant => {
+            let mut ancestor = document.parent_element(id);
+
+            while let Some(ancestor_id) = ancestor {
+                if matches_complex(document, ancestor_id, rest) {
+                    return true;
+                }
+                ancestor = document.parent_element(ancestor_id);
+            }
+
+            false
+        }
+
+        Combinator::NextSibling => previous_siblings(document, id)
+            .first()
+            .is_some_and(|sibling| matches_complex(document, *sibling, rest)),
+
+        Combinator::SubsequentSibling => previous_siblings(document, id)
+            .iter()
+            .any(|sibling| matches_complex(document, *sibling, rest)),
+    }
+}
+
+// The element siblings before `id`, nearest first.
+fn previous_siblings(document: &Document, id: NodeId) -> Vec<NodeId> {
+    let mut siblings: Vec<NodeId> = document
+        .sibling_elements(id)
+        .into_iter()
+        .take_while(|sibling| *sibling != id)
+        .collect();
+
+    siblings.reverse();
+    siblings
+}
+
+fn matches_compound(
+    document: &Document,
+    id: NodeId,
+    element: &Element,
+    compound: &Compound,
+) -> bool {
+    let tag_matches = compound
+        .tag
+        .as_ref()
+        .is_none_or(|tag| element.tag.eq_ignore_ascii_case(tag));
+
+    tag_matches
+        && compound
+            .conditions
+            .iter()
+            .all(|condition| matches_condition(document, id, element, condition))
+}
+
+fn matches_condition(
+    document: &Document,
+    id: NodeId,
+    element: &Element,
+    condition: &Condition,
+) -> bool {
+    match condition {
+        Condition::Id(id) => element.id() == Some(id),
+
+        Condition::Class(class) => element.classes().any(|c| c == class),
+
+        Condition::Not(selector) => !matches_selector(document, id, selector),
+
+        Condition::PseudoClass(pseudo_class) => {
+            matches_pseudo_class(document, id, element, *pseudo_class)
+        }
+
+        Condition::Attribute {
+            name,
+            operator,
+            ignore_case,
+        } => {
+            let Some(actual) = element.attribute(name) else {
+                return false;
+            };
+
+            let Some((operator, expected)) = operator else {
+                return true;
+            };
+
+            let (actual, expected) = if *ignore_case {
+                (actual.to_lowercase(), expected.to_lowercase())
+            } else {
+                (actual.to_string(), expected.clone())
+            };
+            let (actual, expected) = (actual.as_str(), expected.as_str());
+
+            match operator {
+                AttributeOperator::Equals => actual == expected,
+                AttributeOperator::Includes => {
+                    actual.split_ascii_whitespace().any(|v| v == expected)
+                }
+                AttributeOperator::DashMatch => {
+                    actual == expected || actual.starts_with(&format!("{}-", expected))
+                }
+                AttributeOperator::Prefix => !expected.is_empty() && actual.starts_with(expected),
+                AttributeOperator::Suffix => !expected.is_empty() && actual.ends_with(expected),
+                AttributeOperator::Substring => !expected.is_empty() && actual.contains(expected),
+            }
+        }
+    }
+}
+
+const FORM_CONTROLS: [&str; 7] = [
+    "button", "fieldset", "input", "optgroup", "option", "select", "textarea",
+];
+
+fn matches_pseudo_class(
+    document: &Document,
+    id: NodeId,
+    element: &Element,
+    pseudo_class: PseudoClass,
+) -> bool {
+    let tag = element.tag.to_ascii_lowercase();
+    let is_form_control = FORM_CONTROLS.contains(&tag.as_str());
+
+    let siblings = || document.sibling_elements(id);
+    let siblings_of_type = || {
+        siblings()
+            .into_iter()
+            .filter(|sibling| {
+                document
+                    .element(*sibling)
+                    .is_some_and(|sibling| sibling.tag.eq_ignore_ascii_case(&tag))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    match pseudo_class {
+        PseudoClass::Checked => match tag.as_str() {
+            "input" => element.has_attribute("checked"),
+            "option" => element.has_attribute("selected"),
+            _ => false,
+        },
+        PseudoClass::Disabled => is_form_control && element.has_attribute("disabled"),
+        PseudoClass::Enabled => is_form_control && !element.has_attribute("disabled"),
+        PseudoClass::Required => {
+            matches!(tag.as_str(), "input" | "select" | "textarea")
+                && element.has_attribute("required")
+        }
+        PseudoClass::Empty => document.node(id).children.iter().all(
+            |child| matches!(&document.node(*child).kind, NodeKind::Text(text) if text.is_empty()),
+        ),
+        PseudoClass::FirstChild => siblings().first() == Some(&id),
+        PseudoClass::LastChild => siblings().last() == Some(&id),
+        PseudoClass::OnlyChild => siblings() == [id],
+        PseudoClass::FirstOfType => siblings_of_type().first() == Some(&id),
+        PseudoClass::LastOfType => siblings_of_type().last() == Some(&id),
+    }
+}
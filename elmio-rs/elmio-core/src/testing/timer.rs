@@ -0,0 +1,72 @@
+use crate::browser::effect::Effect;
+use crate::browser::effect::browser::Browser;
+use crate::browser::effect::time::Time;
+use crate::browser::subscription::Subscription;
+use crate::browser::subscription::SubscriptionMsg;
+use crate::browser::subscription::animation_frame::Frame;
+use crate::page::Page;
+use crate::testing::PageTester;
+use crate::testing::resolve_capture;
+use crate::time::Posix;
+use std::time::Duration;
+
+impl<P, Model, Msg, Markup> PageTester<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+    Msg: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Renders a frame `delta` after the previous one, delivering it to the
+    /// `animation_frame` subscriptions.
+    pub fn animation_frame(&mut self, delta: Duration) -> Result<(), String> {
+        let timestamp = self.frame_timestamp.as_millis() + delta.as_millis() as i128;
+        self.frame_timestamp = Posix::from_millis(timestamp);
+
+        let frame = Frame {
+            timestamp: self.frame_timestamp,
+            delta,
+        };
+
+        let animation_frames: Vec<_> = self
+            .subscriptions()
+            .into_iter()
+            .filter_map(|subscription| match subscription {
+                Subscription::AnimationFrame(animation_frame) => Some(animation_frame),
+                _ => None,
+            })
+            .collect();
+
+        if animation_frames.is_empty() {
+            return Err("No subscription to animation frames".to_string());
+        }
+
+        for animation_frame in animation_frames {
+            let msg = match animation_frame.msg {
+                SubscriptionMsg::Pure(msg) => msg,
+
+                SubscriptionMsg::Effectful { msg, effect } => {
+                    let value = match effect {
+                        Effect::Time(Time::GetAnimationFrame) => serde_json::to_value(frame)
+                            .map_err(|err| format!("Failed to encode frame: {}", err))?,
+                        _ => self.run_effect(&self.document(), None, &effect)?,
+                    };
+
+                    resolve_capture(&msg, value)?
+                }
+            };
+
+            self.update(&msg)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub(super) fn named_timer_key<Msg>(effect: &Effect<Msg>) -> Option<&str> {
+    match effect {
+        Effect::EffectfulMsg(effectful_msg) => match &effectful_msg.effect {
+            Effect::Browser(Browser::SetNamedTimeout { key, .. }) => Some(key),
+            _ => None,
+        },
+        _ => None,
+    }
+}
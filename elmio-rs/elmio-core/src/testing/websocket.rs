@@ -0,0 +1,64 @@
+use crate::browser::effect::Effect;
+use crate::browser::effect::websocket::WebSocket as WebSocketEffect;
+use crate::browser::subscription::Subscription;
+use crate::browser::subscription::SubscriptionMsg;
+use crate::browser::subscription::websocket::ConnectionState;
+use crate::browser::subscription::websocket::WebSocket;
+use crate::page::Page;
+use crate::testing::PageTester;
+use crate::testing::parse_value;
+use crate::testing::resolve_capture;
+
+impl<P, Model, Msg, Markup> PageTester<P, Model, Msg, Markup>
+where
+    P: Page<Model, Msg, Markup>,
+    Msg: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Delivers a text message on the socket subscribed to `url`.
+    pub fn receive_websocket_message(&mut self, url: &str, data: &str) -> Result<(), String> {
+        let msg = match self.websocket(url)?.msg {
+            SubscriptionMsg::Pure(msg) => msg,
+
+            SubscriptionMsg::Effectful { msg, effect } => {
+                let value = match effect {
+                    Effect::WebSocket(WebSocketEffect::GetMessageData { parse_as_json }) => {
+                        parse_value(data.to_string(), parse_as_json)?
+                    }
+                    _ => self.run_effect(&self.document(), None, &effect)?,
+                };
+
+                resolve_capture(&msg, value)?
+            }
+        };
+
+        self.update(&msg)
+    }
+
+    /// Moves the socket subscribed to `url` to `state`, delivering the
+    /// matching message of `listen_with_state`.
+    pub fn set_websocket_state(&mut self, url: &str, state: ConnectionState) -> Result<(), String> {
+        let state_msgs = self
+            .websocket(url)?
+            .state_msgs
+            .ok_or_else(|| format!("The socket for '{}' has no state messages", url))?;
+
+        let msg = match state {
+            ConnectionState::Connecting => state_msgs.connecting,
+            ConnectionState::Open => state_msgs.open,
+            ConnectionState::Closed => state_msgs.closed,
+            ConnectionState::Error => state_msgs.error,
+        };
+
+        self.update(&msg)
+    }
+
+    fn websocket(&self, url: &str) -> Result<WebSocket<Msg>, String> {
+        self.subscriptions()
+            .into_iter()
+            .find_map(|subscription| match subscription {
+                Subscription::WebSocket(websocket) if websocket.url == url => Some(websocket),
+                _ => None,
+            })
+            .ok_or_else(|| format!("No socket subscribed to '{}'", url))
+    }
+}
@@ -1,6 +1,6 @@
 use std::{ops::Sub, time};
 
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, serde::Serialize, serde::Deserialize)]
 pub struct Posix {
     milliseconds: i128,
 }
@@ -23,7 +23,91 @@ impl Posix {
 
     /// Converts the `Posix` time to a `time::SystemTime` instance.
     pub fn as_system_time(&self) -> time::SystemTime {
-        time::UNIX_EPOCH + time::Duration::from_millis(self.milliseconds as u64)
+        let duration = time::Duration::from_millis(self.milliseconds.unsigned_abs() as u64);
+
+        if self.milliseconds < 0 {
+            time::UNIX_EPOCH - duration
+        } else {
+            time::UNIX_EPOCH + duration
+        }
+    }
+
+    /// Breaks the time down into calendar fields in `zone`.
+    pub fn to_date_time(&self, zone: &Zone) -> DateTime {
+        let millis = self.milliseconds + i128::from(zone.offset) * 60_000;
+        let days = millis.div_euclid(MILLIS_PER_DAY);
+        let millis_of_day = millis.rem_euclid(MILLIS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+
+        DateTime {
+            year,
+            month: Month::from_number(month).unwrap_or(Month::Jan),
+            day,
+            hour: (millis_of_day / 3_600_000) as u8,
+            minute: (millis_of_day / 60_000 % 60) as u8,
+            second: (millis_of_day / 1000 % 60) as u8,
+            millisecond: (millis_of_day % 1000) as u16,
+            weekday: Weekday::from_days(days),
+        }
+    }
+
+    /// Formats the time in `zone` following a `strftime`-like pattern.
+    ///
+    /// Supports `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%L` (milliseconds),
+    /// `%A`, `%a`, `%B`, `%b`, `%z` (`+01:00`) and `%%`. Other characters are
+    /// copied as is.
+    pub fn format(&self, zone: &Zone, pattern: &str) -> String {
+        let date_time = self.to_date_time(zone);
+        let mut formatted = String::with_capacity(pattern.len());
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                formatted.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('Y') => formatted.push_str(&format!("{:04}", date_time.year)),
+                Some('m') => formatted.push_str(&format!("{:02}", date_time.month.number())),
+                Some('d') => formatted.push_str(&format!("{:02}", date_time.day)),
+                Some('H') => formatted.push_str(&format!("{:02}", date_time.hour)),
+                Some('M') => formatted.push_str(&format!("{:02}", date_time.minute)),
+                Some('S') => formatted.push_str(&format!("{:02}", date_time.second)),
+                Some('L') => formatted.push_str(&format!("{:03}", date_time.millisecond)),
+                Some('A') => formatted.push_str(date_time.weekday.name()),
+                Some('a') => formatted.push_str(&date_time.weekday.name()[..3]),
+                Some('B') => formatted.push_str(date_time.month.name()),
+                Some('b') => formatted.push_str(&date_time.month.name()[..3]),
+                Some('z') => formatted.push_str(&format_offset(zone.offset)),
+                Some('%') => formatted.push('%'),
+                Some(other) => {
+                    formatted.push('%');
+                    formatted.push(other);
+                }
+                None => formatted.push('%'),
+            }
+        }
+
+        formatted
+    }
+
+    /// Formats the time as ISO-8601 in `zone`, e.g. `2024-03-01T13:30:00.000+01:00`.
+    /// UTC is written as `Z`.
+    pub fn to_iso_string(&self, zone: &Zone) -> String {
+        if zone.offset == 0 {
+            self.format(zone, "%Y-%m-%dT%H:%M:%S.%LZ")
+        } else {
+            self.format(zone, "%Y-%m-%dT%H:%M:%S.%L%z")
+        }
+    }
+
+    /// Parses an ISO-8601 date, such as `2024-03-01`, `2024-03-01T13:30Z` or
+    /// `2024-03-01T13:30:00.250+01:00`.
+    ///
+    /// Dates without an offset are read as UTC.
+    pub fn from_iso_string(s: &str) -> Result<Self, String> {
+        parse_iso(s).ok_or_else(|| format!("Invalid ISO-8601 date: '{}'", s))
     }
 }
 
@@ -35,3 +119,323 @@ impl Sub for Posix {
         time::Duration::from_millis(diff.max(0) as u64)
     }
 }
+
+const MILLIS_PER_DAY: i128 = 86_400_000;
+
+/// A UTC offset, in minutes east of UTC, along with the IANA name of the
+/// time zone it was obtained from.
+///
+/// Like Elm's `Time.here`, the offset is the one in effect when the zone was
+/// obtained, so dates on the other side of a daylight saving transition are
+/// off by the difference.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Zone {
+    name: Option<String>,
+    offset: i32,
+}
+
+impl Zone {
+    pub fn utc() -> Self {
+        Self {
+            name: Some("UTC".to_string()),
+            offset: 0,
+        }
+    }
+
+    /// Creates a zone with a fixed offset, in minutes east of UTC.
+    pub fn fixed(offset: i32) -> Self {
+        Self { name: None, offset }
+    }
+
+    /// Returns the IANA name, such as `Europe/Oslo`, when known.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the offset in minutes east of UTC.
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+}
+
+impl Default for Zone {
+    fn default() -> Self {
+        Zone::utc()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Month {
+    Jan,
+    Feb,
+    Mar,
+    Apr,
+    May,
+    Jun,
+    Jul,
+    Aug,
+    Sep,
+    Oct,
+    Nov,
+    Dec,
+}
+
+impl Month {
+    const ALL: [Month; 12] = [
+        Month::Jan,
+        Month::Feb,
+        Month::Mar,
+        Month::Apr,
+        Month::May,
+        Month::Jun,
+        Month::Jul,
+        Month::Aug,
+        Month::Sep,
+        Month::Oct,
+        Month::Nov,
+        Month::Dec,
+    ];
+
+    /// Returns the month for a number from 1 to 12.
+    pub fn from_number(number: u8) -> Option<Month> {
+        Self::ALL.get(usize::from(number).checked_sub(1)?).copied()
+    }
+
+    /// Returns the number of the month, from 1 to 12.
+    pub fn number(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Month::Jan => "January",
+            Month::Feb => "February",
+            Month::Mar => "March",
+            Month::Apr => "April",
+            Month::May => "May",
+            Month::Jun => "June",
+            Month::Jul => "July",
+            Month::Aug => "August",
+            Month::Sep => "September",
+            Month::Oct => "October",
+            Month::Nov => "November",
+            Month::Dec => "December",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Weekday {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+impl Weekday {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weekday::Mon => "Monday",
+            Weekday::Tue => "Tuesday",
+            Weekday::Wed => "Wednesday",
+            Weekday::Thu => "Thursday",
+            Weekday::Fri => "Friday",
+            Weekday::Sat => "Saturday",
+            Weekday::Sun => "Sunday",
+        }
+    }
+
+    fn from_days(days: i128) -> Weekday {
+        // 1970-01-01 was a Thursday.
+        match (days + 3).rem_euclid(7) {
+            0 => Weekday::Mon,
+            1 => Weekday::Tue,
+            2 => Weekday::Wed,
+            3 => Weekday::Thu,
+            4 => Weekday::Fri,
+            5 => Weekday::Sat,
+            _ => Weekday::Sun,
+        }
+    }
+}
+
+/// A `Posix` time broken down into calendar fields in some `Zone`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DateTime {
+    pub year: i32,
+    pub month: Month,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub millisecond: u16,
+    pub weekday: Weekday,
+}
+
+impl DateTime {
+    /// Converts the calendar fields back to a `Posix` time, ignoring `weekday`.
+    pub fn to_posix(&self, zone: &Zone) -> Posix {
+        let days = days_from_civil(self.year, self.month.number(), self.day);
+        let millis = days * MILLIS_PER_DAY
+            + i128::from(self.hour) * 3_600_000
+            + i128::from(self.minute) * 60_000
+            + i128::from(self.second) * 1000
+            + i128::from(self.millisecond);
+
+        Posix::from_millis(millis - i128::from(zone.offset) * 60_000)
+    }
+}
+
+fn parse_iso(s: &str) -> Option<Posix> {
+    let date = s.get(..10)?.as_bytes();
+    if date[4] != b'-' || date[7] != b'-' {
+        return None;
+    }
+
+    let year = digits(&s[..4])? as i32;
+    let month = digits(&s[5..7])? as u8;
+    let day = digits(&s[8..10])? as u8;
+
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    let rest = &s[10..];
+    let (time, offset) = match rest.chars().next() {
+        None => ("00:00", "Z"),
+        Some('T' | 't' | ' ') => {
+            let rest = &rest[1..];
+            let index = rest.find(['Z', 'z', '+', '-']).unwrap_or(rest.len());
+            rest.split_at(index)
+        }
+        Some(_) => return None,
+    };
+
+    let (hour, minute, second, millisecond) = parse_time(time)?;
+    let offset = parse_offset(offset)?;
+
+    let date_time = DateTime {
+        year,
+        month: Month::from_number(month)?,
+        day,
+        hour,
+        minute,
+        second,
+        millisecond,
+        weekday: Weekday::Mon,
+    };
+
+    Some(date_time.to_posix(&Zone::fixed(offset)))
+}
+
+fn parse_time(time: &str) -> Option<(u8, u8, u8, u16)> {
+    let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+    let mut parts = time.split(':');
+
+    let hour = digits(parts.next().filter(|part| part.len() == 2)?)? as u8;
+    let minute = digits(parts.next().filter(|part| part.len() == 2)?)? as u8;
+    let second = match parts.next() {
+        Some(part) if part.len() == 2 => digits(part)? as u8,
+        Some(_) => return None,
+        None => 0,
+    };
+
+    if parts.next().is_some() || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    let millisecond = match fraction {
+        "" => 0,
+        fraction => {
+            if !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+                return None;
+            }
+
+            let millis = format!("{:0<3}", &fraction[..fraction.len().min(3)]);
+            digits(&millis)? as u16
+        }
+    };
+
+    Some((hour, minute, second, millisecond))
+}
+
+fn parse_offset(offset: &str) -> Option<i32> {
+    let (sign, rest) = match offset.chars().next() {
+        None | Some('Z' | 'z') if offset.len() <= 1 => return Some(0),
+        Some('+') => (1, &offset[1..]),
+        Some('-') => (-1, &offset[1..]),
+        _ => return None,
+    };
+
+    let (hours, minutes) = match rest.len() {
+        2 => (rest, "00"),
+        4 => (rest.get(..2)?, rest.get(2..)?),
+        5 if rest.as_bytes()[2] == b':' => (&rest[..2], &rest[3..]),
+        _ => return None,
+    };
+
+    Some(sign * (digits(hours)? as i32 * 60 + digits(minutes)? as i32))
+}
+
+fn format_offset(offset: i32) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs();
+    format!("{}{:02}:{:02}", sign, offset / 60, offset % 60)
+}
+
+fn digits(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01 to a proleptic Gregorian date, see
+// http://howardhinnant.github.io/date_algorithms.html
+fn civil_from_days(days: i128) -> (i32, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i128::from(month <= 2);
+
+    (year as i32, month as u8, day as u8)
+}
+
+fn days_from_civil(year: i32, month: u8, day: u8) -> i128 {
+    let year = i128::from(year) - i128::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (i128::from(month) + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + i128::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
@@ -0,0 +1,329 @@
+use crate::html::Document;
+use crate::html::NodeId;
+use crate::html::NodeKind;
+use crate::html::RAW_TEXT_ELEMENTS;
+use crate::html::VOID_ELEMENTS;
+
+/// An owned snapshot of rendered markup, used to compute DOM patches.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VNode {
+    Element {
+        tag: String,
+        attributes: Vec<(String, String)>,
+        children: Vec<VNode>,
+    },
+    Text(String),
+}
+
+impl VNode {
+    /// Parses the output of `Page::render` into its top level nodes.
+    pub fn parse(html: &str) -> Vec<VNode> {
+        let document = Document::parse(html);
+        children_of(&document, Document::ROOT)
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        self.write_html(&mut html, false);
+        html
+    }
+
+    fn write_html(&self, html: &mut String, raw_text: bool) {
+        match self {
+            VNode::Text(text) if raw_text => html.push_str(text),
+
+            VNode::Text(text) => html.push_str(&escape(text)),
+
+            VNode::Element {
+                tag,
+                attributes,
+                children,
+            } => {
+                html.push('<');
+                html.push_str(tag);
+
+                for (name, value) in attributes {
+                    html.push(' ');
+                    html.push_str(name);
+                    html.push_str("=\"");
+                    html.push_str(&escape(value));
+                    html.push('"');
+                }
+
+                html.push('>');
+
+                if VOID_ELEMENTS.contains(&tag.as_str()) {
+                    return;
+                }
+
+                let raw_text = RAW_TEXT_ELEMENTS.contains(&tag.as_str());
+                for child in children {
+                    child.write_html(html, raw_text);
+                }
+
+                html.push_str("</");
+                html.push_str(tag);
+                html.push('>');
+            }
+        }
+    }
+
+    /// The name of the node in the DOM, the lowercase tag of an element or
+    /// `#text`.
+    pub fn node_name(&self) -> &str {
+        match self {
+            VNode::Element { tag, .. } => tag,
+            VNode::Text(_) => "#text",
+        }
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        match self {
+            VNode::Element { attributes, .. } => attributes
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str()),
+            VNode::Text(_) => None,
+        }
+    }
+}
+
+/// A single DOM mutation.
+///
+/// Paths are child node indices starting at the app element, so an empty
+/// path refers to the app element itself. Patches must be applied in order,
+/// since every path is relative to the DOM left by the previous patches.
+///
+/// The browser does not always build the DOM the markup parser expects, e.g.
+/// it adds an implied `<tbody>`, so every patch also carries the `node` name
+/// it expects at `path`, see `VNode::node_name`. A patch whose path leads to
+/// another node is not applied and the runtime renders the full markup.
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(tag = "type", content = "config")]
+#[serde(rename_all = "camelCase")]
+pub enum Patch {
+    #[serde(rename_all = "camelCase")]
+    Replace {
+        path: Vec<usize>,
+        node: String,
+        html: String,
+    },
+    /// Inserts a child into the element at `path`, which is named `node`.
+    #[serde(rename_all = "camelCase")]
+    Insert {
+        path: Vec<usize>,
+        node: String,
+        index: usize,
+        html: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Remove { path: Vec<usize>, node: String },
+    #[serde(rename_all = "camelCase")]
+    SetAttribute {
+        path: Vec<usize>,
+        node: String,
+        name: String,
+        value: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    RemoveAttribute {
+        path: Vec<usize>,
+        node: String,
+        name: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    SetText {
+        path: Vec<usize>,
+        node: String,
+        text: String,
+    },
+}
+
+/// Computes the patches that turn the `old` app element into the `new` one.
+///
+/// Children are compared by position. Elements carrying the `unmanaged`
+/// attribute are left untouched, mirroring the morphdom based update.
+pub fn diff(old: &VNode, new: &VNode) -> Vec<Patch> {
+    let mut patches = Vec::new();
+    diff_node(old, new, &mut Vec::new(), &mut patches);
+    patches
+}
+
+/// The app element last rendered by the runtime, diffed against each new
+/// render to patch the DOM in place.
+#[derive(Clone, Debug, Default)]
+pub struct RenderedView {
+    node: Option<VNode>,
+}
+
+impl RenderedView {
+    /// Returns the patches that turn the previously rendered body into `html`.
+    ///
+    /// The first call, or a body that is not a single element, yields one
+    /// `Replace` patch for the whole app element.
+    pub fn patches(&mut self, html: String) -> Vec<Patch> {
+        let new = match VNode::parse(&html).as_slice() {
+            [node @ VNode::Element { .. }] => Some(node.clone()),
+            _ => None,
+        };
+
+        let patches = match (&self.node, &new) {
+            (Some(old), Some(new)) => diff(old, new),
+            // The whole app element is morphed, whatever its current node.
+            (old, _) => vec![Patch::Replace {
+                path: vec![],
+                node: old
+                    .as_ref()
+                    .map(|old| old.node_name().to_string())
+                    .unwrap_or_default(),
+                html,
+            }],
+        };
+
+        self.node = new;
+        patches
+    }
+
+    /// Forgets the rendered body, so that the next patches replace the whole
+    /// app element.
+    pub fn reset(&mut self) {
+        self.node = None;
+    }
+}
+
+fn diff_node(old: &VNode, new: &VNode, path: &mut Vec<usize>, patches: &mut Vec<Patch>) {
+    match (old, new) {
+        (VNode::Text(old_text), VNode::Text(new_text)) => {
+            if old_text != new_text {
+                patches.push(Patch::SetText {
+                    path: path.clone(),
+                    node: old.node_name().to_string(),
+                    text: new_text.clone(),
+                });
+            }
+        }
+
+        (
+            VNode::Element {
+                tag: old_tag,
+                attributes: old_attributes,
+                children: old_children,
+            },
+            VNode::Element {
+                tag: new_tag,
+                attributes: new_attributes,
+                children: new_children,
+            },
+        ) if old_tag == new_tag => {
+            if old.attribute("unmanaged").is_some() {
+                return;
+            }
+
+            diff_attributes(old_tag, old_attributes, new_attributes, path, patches);
+            diff_children(old_tag, old_children, new_children, path, patches);
+        }
+
+        _ => patches.push(Patch::Replace {
+            path: path.clone(),
+            node: old.node_name().to_string(),
+            html: new.to_html(),
+        }),
+    }
+}
+
+fn diff_attributes(
+    tag: &str,
+    old: &[(String, String)],
+    new: &[(String, String)],
+    path: &[usize],
+    patches: &mut Vec<Patch>,
+) {
+    for (name, value) in new {
+        let old_value = old.iter().find(|(key, _)| key == name).map(|(_, v)| v);
+
+        if old_value != Some(value) {
+            patches.push(Patch::SetAttribute {
+                path: path.to_vec(),
+                node: tag.to_string(),
+                name: name.clone(),
+                value: value.clone(),
+            });
+        }
+    }
+
+    for (name, _) in old {
+        if !new.iter().any(|(key, _)| key == name) {
+            patches.push(Patch::RemoveAttribute {
+                path: path.to_vec(),
+                node: tag.to_string(),
+                name: name.clone(),
+            });
+        }
+    }
+}
+
+fn diff_children(
+    tag: &str,
+    old: &[VNode],
+    new: &[VNode],
+    path: &mut Vec<usize>,
+    patches: &mut Vec<Patch>,
+) {
+    for (index, (old_child, new_child)) in old.iter().zip(new).enumerate() {
+        path.push(index);
+        diff_node(old_child, new_child, path, patches);
+        path.pop();
+    }
+
+    for (index, new_child) in new.iter().enumerate().skip(old.len()) {
+        patches.push(Patch::Insert {
+            path: path.clone(),
+            node: tag.to_string(),
+            index,
+            html: new_child.to_html(),
+        });
+    }
+
+    // Removing from the end keeps the paths of the remaining children valid.
+    for index in (new.len()..old.len()).rev() {
+        path.push(index);
+        patches.push(Patch::Remove {
+            path: path.clone(),
+            node: old[index].node_name().to_string(),
+        });
+        path.pop();
+    }
+}
+
+fn children_of(document: &Document, id: NodeId) -> Vec<VNode> {
+    document
+        .node(id)
+        .children
+        .iter()
+        .map(|child| match &document.node(*child).kind {
+            NodeKind::Element(element) => VNode::Element {
+                tag: element.tag.clone(),
+                attributes: element.attributes.clone(),
+                children: children_of(document, *child),
+            },
+            NodeKind::Text(text) => VNode::Text(text.clone()),
+            NodeKind::Document => VNode::Text(String::new()),
+        })
+        .collect()
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
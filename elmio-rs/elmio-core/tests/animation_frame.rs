@@ -0,0 +1,106 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::animation_frame::Frame;
+use elmio_core::browser::subscription::animation_frame::animation_frame;
+use elmio_core::browser::value::Capture;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+use std::time::Duration;
+
+#[derive(Clone, Copy)]
+struct Id;
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "ball")
+    }
+}
+
+impl DomId for Id {}
+
+// Moves 100 pixels per second until it reaches the end.
+#[derive(Default)]
+struct Model {
+    x: f64,
+    timestamps: Vec<i128>,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    Frame(Capture<Frame>),
+}
+
+struct Ball;
+
+impl Page<Model, Msg, Markup> for Ball {
+    fn id(&self) -> &'static dyn DomId {
+        &Id
+    }
+
+    fn init(&self) -> Result<(Model, Effect<Msg>), String> {
+        Ok((Model::default(), effect::none()))
+    }
+
+    fn subscriptions(&self, model: &Model) -> Subscription<Msg> {
+        if model.x < 5.0 {
+            animation_frame(Msg::Frame)
+        } else {
+            subscription::none()
+        }
+    }
+
+    fn update(&self, msg: &Msg, model: &mut Model) -> Result<Effect<Msg>, String> {
+        match msg {
+            Msg::Frame(frame) => {
+                let frame = frame.value();
+                model.x += 100.0 * frame.delta.as_secs_f64();
+                model.timestamps.push(frame.timestamp.as_millis());
+            }
+        }
+
+        Ok(effect::none())
+    }
+
+    fn view(&self, model: &Model) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! { div id=(Id) style={ "left: " (model.x) "px" } {} },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn frames_carry_the_delta_since_the_previous_one() {
+    let mut tester = PageTester::new(Ball).unwrap();
+
+    tester.animation_frame(Duration::ZERO).unwrap();
+    tester.animation_frame(Duration::from_millis(16)).unwrap();
+    tester.animation_frame(Duration::from_millis(20)).unwrap();
+
+    assert_eq!(tester.model().timestamps, [0, 16, 36]);
+    assert!((tester.model().x - 3.6).abs() < 1e-9);
+}
+
+#[test]
+fn frames_stop_with_the_subscription() {
+    let mut tester = PageTester::new(Ball).unwrap();
+
+    tester.animation_frame(Duration::from_millis(50)).unwrap();
+
+    assert!(tester.animation_frame(Duration::from_millis(16)).is_err());
+    assert_eq!(tester.model().timestamps, [50]);
+}
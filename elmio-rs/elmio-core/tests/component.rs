@@ -0,0 +1,168 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::event_listener::on_click;
+use elmio_core::component::Component;
+use elmio_core::component::Scope;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+
+#[derive(elmio_macro::DomId)]
+#[dom_id(display)]
+enum CounterId {
+    Increment,
+}
+
+struct Counter {
+    scope: Scope,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum CounterMsg {
+    Increment,
+}
+
+impl Component<u32, CounterMsg, Markup> for Counter {
+    fn scope(&self) -> &Scope {
+        &self.scope
+    }
+
+    fn init(&self) -> Result<(u32, Effect<CounterMsg>), String> {
+        Ok((0, effect::none()))
+    }
+
+    fn update(&self, msg: &CounterMsg, count: &mut u32) -> Result<Effect<CounterMsg>, String> {
+        match msg {
+            CounterMsg::Increment => *count += 1,
+        }
+
+        Ok(effect::none())
+    }
+
+    fn subscriptions(&self, _count: &u32) -> Subscription<CounterMsg> {
+        on_click(self.id(CounterId::Increment), CounterMsg::Increment)
+    }
+
+    fn view(&self, count: &u32) -> Markup {
+        html! {
+            button id=(self.id(CounterId::Increment)) { (count) }
+        }
+    }
+}
+
+#[derive(elmio_macro::DomId)]
+#[dom_id(display)]
+enum Id {
+    Counters,
+}
+
+struct Counters {
+    left: Counter,
+    right: Counter,
+}
+
+impl Counters {
+    fn new() -> Self {
+        Self {
+            left: Counter {
+                scope: Scope::new("left"),
+            },
+            right: Counter {
+                scope: Scope::new("right"),
+            },
+        }
+    }
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    Left(CounterMsg),
+    Right(CounterMsg),
+}
+
+impl Page<(u32, u32), Msg, Markup> for Counters {
+    fn id(&self) -> &'static dyn DomId {
+        &Id::Counters
+    }
+
+    fn init(&self) -> Result<((u32, u32), Effect<Msg>), String> {
+        Ok(((0, 0), effect::none()))
+    }
+
+    fn subscriptions(&self, model: &(u32, u32)) -> Subscription<Msg> {
+        subscription::batch(vec![
+            self.left.embed_subscriptions(&model.0, Msg::Left),
+            self.right.embed_subscriptions(&model.1, Msg::Right),
+        ])
+    }
+
+    fn update(&self, msg: &Msg, model: &mut (u32, u32)) -> Result<Effect<Msg>, String> {
+        match msg {
+            Msg::Left(msg) => Ok(self.left.update(msg, &mut model.0)?.map(Msg::Left)),
+            Msg::Right(msg) => Ok(self.right.update(msg, &mut model.1)?.map(Msg::Right)),
+        }
+    }
+
+    fn view(&self, model: &(u32, u32)) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! {
+                div id=(Id::Counters) {
+                    (self.left.view(&model.0))
+                    (self.right.view(&model.1))
+                }
+            },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn instances_have_distinct_subscription_ids() {
+    let tester = PageTester::new(Counters::new()).unwrap();
+
+    let ids: Vec<String> = tester
+        .subscriptions()
+        .into_iter()
+        .filter_map(|subscription| match subscription {
+            Subscription::EventListener(listener) => Some(listener.id),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(ids, ["left-left-increment", "right-right-increment"]);
+    assert!(tester.unrendered_subscriptions().is_empty());
+}
+
+#[test]
+fn events_reach_their_own_instance() {
+    let mut tester = PageTester::new(Counters::new()).unwrap();
+
+    tester.click("#right-increment").unwrap();
+    tester.click("#right-increment").unwrap();
+    tester.click("#left-increment").unwrap();
+
+    assert_eq!(*tester.model(), (1, 2));
+}
+
+#[test]
+fn nested_scopes_are_prefixed_by_their_parent() {
+    let scope = Scope::new("form").child("date");
+
+    assert_eq!(
+        scope.id(CounterId::Increment).to_string(),
+        "form-date-increment"
+    );
+}
@@ -0,0 +1,64 @@
+use elmio_core::browser::dom_id::DomId;
+
+#[derive(elmio_macro::DomId)]
+#[dom_id(display)]
+enum Id {
+    App,
+    TodoList,
+    HTTPStatus,
+    Row(usize),
+    Cell {
+        row: usize,
+        col: usize,
+    },
+    #[dom_id(rename = "main")]
+    Content,
+}
+
+#[derive(elmio_macro::DomId)]
+#[dom_id(display, prefix = "todo")]
+enum TodoId {
+    Input,
+    Item(u32),
+}
+
+// Display implemented elsewhere, e.g. by `strum_macros::Display`.
+#[derive(elmio_macro::DomId)]
+enum ExternalId {
+    Counter,
+}
+
+impl std::fmt::Display for ExternalId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "external-counter")
+    }
+}
+
+fn id(dom_id: &dyn DomId) -> String {
+    dom_id.to_string()
+}
+
+#[test]
+fn variants_render_in_kebab_case() {
+    assert_eq!(id(&Id::App), "app");
+    assert_eq!(id(&Id::TodoList), "todo-list");
+    assert_eq!(id(&Id::HTTPStatus), "http-status");
+}
+
+#[test]
+fn fields_are_appended() {
+    assert_eq!(id(&Id::Row(3)), "row-3");
+    assert_eq!(id(&Id::Cell { row: 1, col: 2 }), "cell-1-2");
+}
+
+#[test]
+fn rename_and_prefix() {
+    assert_eq!(id(&Id::Content), "main");
+    assert_eq!(id(&TodoId::Input), "todo-input");
+    assert_eq!(id(&TodoId::Item(7)), "todo-item-7");
+}
+
+#[test]
+fn display_is_opt_in() {
+    assert_eq!(id(&ExternalId::Counter), "external-counter");
+}
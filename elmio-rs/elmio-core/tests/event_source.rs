@@ -0,0 +1,65 @@
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::event_source;
+use elmio_core::browser::subscription::event_source::Options;
+use elmio_core::browser::subscription::event_source::ServerEvent;
+use elmio_core::browser::value::Capture;
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    Message(Capture<String>),
+    Update(Capture<ServerEvent<String>>),
+}
+
+fn check(subscriptions: Vec<Subscription<Msg>>) -> Result<(), String> {
+    event_source::check_shared_connections(&Subscription::Batch(subscriptions).into_vec())
+}
+
+#[test]
+fn shared_url_with_same_options() {
+    let options = Options::new().last_event_id("7").with_credentials();
+
+    let result = check(vec![
+        event_source::listen_with_options("/events", options.clone(), Msg::Update),
+        event_source::listen_with_options("/events", options.event_type("ping"), Msg::Update),
+    ]);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn different_urls_with_different_options() {
+    let result = check(vec![
+        event_source::listen_string("/events", Msg::Message),
+        event_source::listen_with_options("/other", Options::new().last_event_id("7"), Msg::Update),
+    ]);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn shared_url_with_different_last_event_id() {
+    let result = check(vec![
+        event_source::listen_string("/events", Msg::Message),
+        event_source::listen_with_options(
+            "/events",
+            Options::new().event_type("update").last_event_id("7"),
+            Msg::Update,
+        ),
+    ]);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn shared_url_with_different_credentials() {
+    let result = check(vec![
+        event_source::listen_with_options(
+            "/events",
+            Options::new().event_type("update").with_credentials(),
+            Msg::Update,
+        ),
+        event_source::listen_string("/events", Msg::Message),
+    ]);
+
+    assert!(result.is_err());
+}
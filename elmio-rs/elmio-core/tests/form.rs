@@ -0,0 +1,139 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::event_listener::on_submit_form;
+use elmio_core::browser::value::Capture;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+
+#[derive(Clone, Copy)]
+enum Id {
+    App,
+    Signup,
+}
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Id::App => write!(f, "app"),
+            Id::Signup => write!(f, "signup"),
+        }
+    }
+}
+
+impl DomId for Id {}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Signup {
+    name: String,
+    age: u32,
+    newsletter: bool,
+    topics: Vec<String>,
+    plan: Option<String>,
+    country: String,
+    address: Address,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Address {
+    city: String,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    Submitted(Capture<Signup>),
+}
+
+struct Form;
+
+impl Page<Option<Signup>, Msg, Markup> for Form {
+    fn id(&self) -> &'static dyn DomId {
+        &Id::App
+    }
+
+    fn init(&self) -> Result<(Option<Signup>, Effect<Msg>), String> {
+        Ok((None, effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &Option<Signup>) -> Subscription<Msg> {
+        subscription::batch(vec![on_submit_form(Id::Signup, Msg::Submitted)])
+    }
+
+    fn update(&self, msg: &Msg, model: &mut Option<Signup>) -> Result<Effect<Msg>, String> {
+        match msg {
+            Msg::Submitted(signup) => *model = Some(signup.value()),
+        }
+
+        Ok(effect::none())
+    }
+
+    fn view(&self, _model: &Option<Signup>) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! {
+                div id=(Id::App) {
+                    form id=(Id::Signup) {
+                        input name="name" value="Ada";
+                        input name="nickname" value="ada" disabled;
+                        input type="number" name="age" value="36";
+                        input type="checkbox" name="newsletter" checked;
+                        input type="checkbox" name="topics" value="rust" checked;
+                        input type="checkbox" name="topics" value="elm";
+                        input type="checkbox" name="topics" value="wasm" checked;
+                        input type="radio" name="plan" value="free";
+                        input type="radio" name="plan" value="pro";
+                        select name="country" {
+                            option value="fr" { "France" }
+                            option value="uk" selected { "United Kingdom" }
+                        }
+                        input name="address.city" value="London";
+                        input type="submit" value="Sign up";
+                    }
+                }
+            },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn submitted_forms_are_read_into_the_captured_type() {
+    let mut tester = PageTester::new(Form).unwrap();
+
+    tester.submit("#signup").unwrap();
+
+    assert_eq!(
+        tester.model(),
+        &Some(Signup {
+            name: "Ada".to_string(),
+            age: 36,
+            newsletter: true,
+            topics: vec!["rust".to_string(), "wasm".to_string()],
+            plan: None,
+            country: "uk".to_string(),
+            address: Address {
+                city: "London".to_string(),
+            },
+        })
+    );
+}
+
+#[test]
+fn only_the_form_itself_is_submitted() {
+    let mut tester = PageTester::new(Form).unwrap();
+
+    assert!(tester.submit("#signup input").is_err());
+    assert_eq!(tester.model(), &None);
+}
@@ -0,0 +1,92 @@
+use elmio_core::html::Document;
+use elmio_core::html::NodeId;
+
+fn tags(document: &Document, ids: &[NodeId]) -> Vec<String> {
+    ids.iter()
+        .filter_map(|id| document.element(*id))
+        .map(|element| element.tag.clone())
+        .collect()
+}
+
+fn by_id(document: &Document, id: &str) -> NodeId {
+    document.element_by_id(id).expect("element should exist")
+}
+
+#[test]
+fn void_elements_take_no_children() {
+    let document = Document::parse(r#"<div id="a"><input id="b"><br><span id="c"></span></div>"#);
+
+    let a = by_id(&document, "a");
+    assert_eq!(
+        tags(&document, &document.node(a).children),
+        ["input", "br", "span"]
+    );
+    assert!(document.node(by_id(&document, "b")).children.is_empty());
+    assert_eq!(document.parent_element(by_id(&document, "c")), Some(a));
+}
+
+#[test]
+fn self_closing_tags_are_closed() {
+    let document = Document::parse(r#"<div id="a"><svg id="b"/><p id="c"></p></div>"#);
+
+    let a = by_id(&document, "a");
+    assert_eq!(document.parent_element(by_id(&document, "c")), Some(a));
+}
+
+#[test]
+fn raw_text_elements_keep_markup_as_text() {
+    let document = Document::parse(
+        r#"<script id="a">if (a < b) { x = "<div>"; }</script><textarea id="b">&lt;b&gt; <i></textarea><p id="c"></p>"#,
+    );
+
+    let script = by_id(&document, "a");
+    assert_eq!(
+        document.text_content(script),
+        r#"if (a < b) { x = "<div>"; }"#
+    );
+    assert_eq!(document.text_content(by_id(&document, "b")), "<b> <i>");
+    assert_eq!(document.parent_element(by_id(&document, "c")), None);
+    assert_eq!(
+        tags(&document, &document.elements()),
+        ["script", "textarea", "p"]
+    );
+}
+
+#[test]
+fn unclosed_elements_are_closed_by_an_ancestor_end_tag() {
+    let document = Document::parse(r#"<ul id="a"><li id="b">one<li id="c">two</ul><p id="d">"#);
+
+    let b = by_id(&document, "b");
+    assert_eq!(document.parent_element(by_id(&document, "c")), Some(b));
+    assert_eq!(document.parent_element(by_id(&document, "d")), None);
+}
+
+#[test]
+fn stray_end_tags_are_ignored() {
+    let document = Document::parse(r#"<div id="a"></span><p id="b"></p></div>"#);
+
+    assert_eq!(
+        document.parent_element(by_id(&document, "b")),
+        Some(by_id(&document, "a"))
+    );
+}
+
+#[test]
+fn attributes_are_decoded() {
+    let document =
+        Document::parse(r#"<input id="a" value='a &amp; b' disabled data-x=1 class=" one  two ">"#);
+
+    let element = document.element(by_id(&document, "a")).unwrap();
+    assert_eq!(element.attribute("value"), Some("a & b"));
+    assert_eq!(element.attribute("disabled"), Some(""));
+    assert_eq!(element.attribute("data-x"), Some("1"));
+    assert_eq!(element.classes().collect::<Vec<_>>(), ["one", "two"]);
+}
+
+#[test]
+fn comments_and_doctype_are_skipped() {
+    let document = Document::parse("<!DOCTYPE html><!-- <p> --><p id=\"a\">x &lt; y &#65;</p>");
+
+    assert_eq!(tags(&document, &document.elements()), ["p"]);
+    assert_eq!(document.text_content(by_id(&document, "a")), "x < y A");
+}
@@ -0,0 +1,121 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::effect::http;
+use elmio_core::browser::effect::http::HttpError;
+use elmio_core::browser::effect::http::HttpResult;
+use elmio_core::browser::effect::http::Method;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::value::Capture;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+use serde_json::json;
+
+#[derive(elmio_macro::DomId)]
+#[dom_id(display)]
+enum Id {
+    Profile,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct User {
+    name: String,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    GotUser(Capture<HttpResult<User>>),
+}
+
+struct Profile;
+
+impl Page<Option<Result<User, HttpError>>, Msg, Markup> for Profile {
+    fn id(&self) -> &'static dyn DomId {
+        &Id::Profile
+    }
+
+    fn init(&self) -> Result<(Option<Result<User, HttpError>>, Effect<Msg>), String> {
+        let effect = http::get("/api/user").query("id", 1).send(Msg::GotUser);
+
+        Ok((None, effect))
+    }
+
+    fn subscriptions(&self, _model: &Option<Result<User, HttpError>>) -> Subscription<Msg> {
+        subscription::none()
+    }
+
+    fn update(
+        &self,
+        msg: &Msg,
+        model: &mut Option<Result<User, HttpError>>,
+    ) -> Result<Effect<Msg>, String> {
+        match msg {
+            Msg::GotUser(result) => *model = Some(result.value().into_result()),
+        }
+
+        Ok(effect::none())
+    }
+
+    fn view(&self, _model: &Option<Result<User, HttpError>>) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! { div id=(Id::Profile) {} },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn matching_body_is_decoded() {
+    let mut tester = PageTester::new(Profile).unwrap();
+
+    let request = tester.respond_http(Ok(json!({ "name": "Ada" }))).unwrap();
+
+    assert_eq!(request.method(), &Method::Get);
+    assert_eq!(request.url(), "/api/user");
+    assert_eq!(
+        tester.model(),
+        &Some(Ok(User {
+            name: "Ada".to_string()
+        }))
+    );
+    assert!(tester.respond_http(Ok(())).is_err());
+}
+
+#[test]
+fn mismatching_body_is_a_decode_error() {
+    let mut tester = PageTester::new(Profile).unwrap();
+
+    tester
+        .respond_http(Ok(json!({ "username": "Ada" })))
+        .unwrap();
+
+    match tester.model() {
+        Some(Err(HttpError::Decode(err))) => assert!(err.contains("name"), "{}", err),
+        _ => panic!("expected a decode error"),
+    }
+}
+
+#[test]
+fn errors_are_delivered() {
+    let mut tester = PageTester::new(Profile).unwrap();
+
+    let error = HttpError::BadStatus {
+        status: 404,
+        body: "not found".to_string(),
+    };
+    tester.respond_http::<User>(Err(error.clone())).unwrap();
+
+    assert_eq!(tester.model(), &Some(Err(error)));
+}
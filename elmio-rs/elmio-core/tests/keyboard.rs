@@ -0,0 +1,144 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::keyboard::Chord;
+use elmio_core::browser::keyboard::Code;
+use elmio_core::browser::keyboard::Key;
+use elmio_core::browser::keyboard::KeyPress;
+use elmio_core::browser::keyboard::NamedKey;
+use elmio_core::browser::keyboard::Shortcut;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::event_listener::ModifierKey;
+use elmio_core::browser::subscription::event_listener::on_keydown;
+use elmio_core::browser::subscription::event_listener::on_shortcut;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+use serde_json::json;
+
+#[derive(Clone, Copy)]
+struct Id;
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "editor")
+    }
+}
+
+impl DomId for Id {}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    Save,
+    Close,
+    Search,
+}
+
+struct Editor;
+
+impl Page<Vec<&'static str>, Msg, Markup> for Editor {
+    fn id(&self) -> &'static dyn DomId {
+        &Id
+    }
+
+    fn init(&self) -> Result<(Vec<&'static str>, Effect<Msg>), String> {
+        Ok((Vec::new(), effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &Vec<&'static str>) -> Subscription<Msg> {
+        subscription::batch(vec![
+            on_keydown(Key::Key("KeyS".to_string()), ModifierKey::Ctrl, Msg::Save),
+            on_keydown(Key::Escape, ModifierKey::None, Msg::Close),
+            on_shortcut(Shortcut::parse("Ctrl+K").unwrap(), Msg::Search),
+        ])
+    }
+
+    fn update(&self, msg: &Msg, model: &mut Vec<&'static str>) -> Result<Effect<Msg>, String> {
+        model.push(match msg {
+            Msg::Save => "save",
+            Msg::Close => "close",
+            Msg::Search => "search",
+        });
+
+        Ok(effect::none())
+    }
+
+    fn view(&self, _model: &Vec<&'static str>) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! { div id=(Id) {} },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn keys_serialize_as_before() {
+    assert_eq!(serde_json::to_value(Key::Escape).unwrap(), json!("escape"));
+    assert_eq!(serde_json::to_value(Key::Enter).unwrap(), json!("enter"));
+    assert_eq!(serde_json::to_value(Key::Any).unwrap(), json!("any"));
+
+    let key: Key = serde_json::from_value(json!("KeyA")).unwrap();
+    assert_eq!(key, Key::Key("KeyA".to_string()));
+    assert_eq!(key.to_string(), "KeyA");
+}
+
+#[test]
+fn chords_serialize_keys_and_codes() {
+    let chord = Chord::parse("Ctrl+K").unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&chord).unwrap(),
+        json!({
+            "key": { "type": "key", "config": "k" },
+            "modifiers": { "alt": false, "ctrl": true, "meta": false, "shift": false },
+        })
+    );
+
+    for chord in [
+        chord,
+        Chord::named(NamedKey::Escape),
+        Chord::code(Code::KeyK),
+    ] {
+        let json = serde_json::to_string(&chord).unwrap();
+        assert_eq!(serde_json::from_str::<Chord>(&json).unwrap(), chord);
+    }
+
+    assert!(serde_json::from_value::<KeyPress>(json!({ "type": "code", "config": "K" })).is_err());
+}
+
+#[test]
+fn on_keydown_matches_the_code_and_allows_other_modifiers() {
+    let mut tester = PageTester::new(Editor).unwrap();
+
+    tester
+        .key_down(
+            "KeyS",
+            ModifierKey::Multiple(vec![ModifierKey::Ctrl, ModifierKey::Shift]),
+        )
+        .unwrap();
+    tester.key_down("Escape", ModifierKey::Ctrl).unwrap();
+    assert!(tester.key_down("KeyS", ModifierKey::None).is_err());
+
+    assert_eq!(tester.model(), &vec!["save", "close"]);
+}
+
+#[test]
+fn shortcuts_require_other_modifiers_to_be_released() {
+    let mut tester = PageTester::new(Editor).unwrap();
+
+    tester.press("Ctrl+K").unwrap();
+    assert!(tester.press("Ctrl+Shift+K").is_err());
+
+    assert_eq!(tester.model(), &vec!["search"]);
+}
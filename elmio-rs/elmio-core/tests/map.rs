@@ -0,0 +1,139 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::effect::effectful_msg::effectful_msg;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::event_listener::on_click;
+use elmio_core::browser::subscription::event_listener::on_input;
+use elmio_core::browser::value::Capture;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+
+#[derive(Clone, Copy)]
+enum Id {
+    App,
+    Query,
+    Submit,
+}
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let id = match self {
+            Id::App => "app",
+            Id::Query => "query",
+            Id::Submit => "submit",
+        };
+
+        write!(f, "{}", id)
+    }
+}
+
+impl DomId for Id {}
+
+// The messages of a search box, nested in the page messages.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum SearchMsg {
+    QueryChanged(Capture<String>),
+    Submit,
+    Submitted(Capture<String>),
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    Search(SearchMsg),
+}
+
+#[derive(Default)]
+struct Model {
+    query: String,
+    submitted: Option<String>,
+}
+
+fn search_subscriptions() -> Subscription<SearchMsg> {
+    subscription::batch(vec![
+        on_input(Id::Query, SearchMsg::QueryChanged),
+        on_click(Id::Submit, SearchMsg::Submit),
+    ])
+}
+
+fn search_update(msg: &SearchMsg, model: &mut Model) -> Effect<SearchMsg> {
+    match msg {
+        SearchMsg::QueryChanged(query) => model.query = query.value(),
+        SearchMsg::Submit => {
+            return effectful_msg(
+                SearchMsg::Submitted(Capture::default()),
+                effect::dom::get_element_string_value(Id::Query),
+            );
+        }
+        SearchMsg::Submitted(query) => model.submitted = Some(query.value()),
+    }
+
+    effect::none()
+}
+
+struct App;
+
+impl Page<Model, Msg, Markup> for App {
+    fn id(&self) -> &'static dyn DomId {
+        &Id::App
+    }
+
+    fn init(&self) -> Result<(Model, Effect<Msg>), String> {
+        Ok((Model::default(), effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &Model) -> Subscription<Msg> {
+        search_subscriptions().map(Msg::Search)
+    }
+
+    fn update(&self, msg: &Msg, model: &mut Model) -> Result<Effect<Msg>, String> {
+        match msg {
+            Msg::Search(msg) => Ok(search_update(msg, model).map(Msg::Search)),
+        }
+    }
+
+    fn view(&self, model: &Model) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! {
+                div id=(Id::App) {
+                    input id=(Id::Query) value=(model.query);
+                    button id=(Id::Submit) { "Search" }
+                }
+            },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn mapped_subscriptions_capture_into_the_nested_msg() {
+    let mut tester = PageTester::new(App).unwrap();
+
+    tester.input("#query", "elm").unwrap();
+
+    assert_eq!(tester.model().query, "elm");
+}
+
+#[test]
+fn mapped_effects_keep_their_captures() {
+    let mut tester = PageTester::new(App).unwrap();
+
+    tester.click("#submit").unwrap();
+    assert_eq!(tester.effectful_msgs().len(), 1);
+
+    tester.resolve_next("rust").unwrap();
+
+    assert_eq!(tester.model().submitted.as_deref(), Some("rust"));
+}
@@ -0,0 +1,123 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::navigation::Location;
+use elmio_core::browser::subscription::navigation::UrlRequest;
+use elmio_core::browser::subscription::navigation::on_url_change;
+use elmio_core::browser::subscription::navigation::on_url_request;
+use elmio_core::browser::value::Capture;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+
+#[derive(Clone, Copy)]
+struct Id;
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "app")
+    }
+}
+
+impl DomId for Id {}
+
+#[derive(Default)]
+struct Model {
+    urls: Vec<String>,
+    requests: Vec<UrlRequest>,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    UrlChanged(Capture<Location>),
+    UrlRequested(Capture<UrlRequest>),
+}
+
+struct App;
+
+impl Page<Model, Msg, Markup> for App {
+    fn id(&self) -> &'static dyn DomId {
+        &Id
+    }
+
+    fn init(&self) -> Result<(Model, Effect<Msg>), String> {
+        Ok((Model::default(), effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &Model) -> Subscription<Msg> {
+        subscription::batch(vec![
+            on_url_change(Msg::UrlChanged),
+            on_url_request(Id, Msg::UrlRequested),
+        ])
+    }
+
+    fn update(&self, msg: &Msg, model: &mut Model) -> Result<Effect<Msg>, String> {
+        match msg {
+            Msg::UrlChanged(location) => model.urls.push(location.value().url),
+            Msg::UrlRequested(request) => model.requests.push(request.value()),
+        }
+
+        Ok(effect::none())
+    }
+
+    fn view(&self, _model: &Model) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! {
+                div id=(Id) {
+                    a #posts href="posts?page=2" { span { "Posts" } }
+                    a #docs href="https://docs.rs/elmio" { "Docs" }
+                    a #report href="/report.pdf" download { "Report" }
+                }
+            },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn url_changes_deliver_the_new_location() {
+    let mut tester = PageTester::new(App).unwrap();
+
+    tester.change_url("http://localhost/posts/1").unwrap();
+    tester
+        .change_url("http://localhost/posts/1#comments")
+        .unwrap();
+
+    assert_eq!(
+        tester.model().urls,
+        [
+            "http://localhost/posts/1",
+            "http://localhost/posts/1#comments"
+        ]
+    );
+}
+
+#[test]
+fn link_clicks_are_requests_resolved_against_the_current_url() {
+    let mut tester = PageTester::new(App).unwrap();
+    tester.change_url("http://localhost/blog/").unwrap();
+
+    tester.click("#posts span").unwrap();
+    tester.click("#docs").unwrap();
+    assert!(tester.click("#report").is_err());
+
+    assert_eq!(
+        tester.model().requests,
+        [
+            UrlRequest::Internal("http://localhost/blog/posts?page=2".to_string()),
+            UrlRequest::External("https://docs.rs/elmio".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,200 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::effect::dom::Dom;
+use elmio_core::browser::event::MouseData;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::event_listener::EventType;
+use elmio_core::browser::subscription::event_listener::on_click;
+use elmio_core::browser::subscription::event_listener::on_double_click;
+use elmio_core::browser::subscription::event_listener::on_input;
+use elmio_core::browser::subscription::event_listener::on_radio_change_string;
+use elmio_core::browser::value::Capture;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+
+#[derive(Clone, Copy)]
+enum Id {
+    Counter,
+    Increment,
+    Name,
+    Save,
+    Rows,
+}
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let id = match self {
+            Id::Counter => "counter",
+            Id::Increment => "increment",
+            Id::Name => "name",
+            Id::Save => "save",
+            Id::Rows => "rows",
+        };
+
+        write!(f, "{}", id)
+    }
+}
+
+impl DomId for Id {}
+
+#[derive(Default)]
+struct Model {
+    count: i32,
+    name: String,
+    saved: Option<String>,
+    size: String,
+    opened_row: Option<String>,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    Increment,
+    NameChanged(Capture<String>),
+    Save,
+    Saved(Capture<String>),
+    SizeChanged(Capture<String>),
+    RowOpened(Capture<MouseData>),
+}
+
+struct Counter;
+
+impl Page<Model, Msg, Markup> for Counter {
+    fn id(&self) -> &'static dyn DomId {
+        &Id::Counter
+    }
+
+    fn init(&self) -> Result<(Model, Effect<Msg>), String> {
+        Ok((Model::default(), effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &Model) -> Subscription<Msg> {
+        subscription::batch(vec![
+            on_click(Id::Increment, Msg::Increment),
+            on_input(Id::Name, Msg::NameChanged),
+            on_click(Id::Save, Msg::Save),
+            on_radio_change_string("size", Msg::SizeChanged),
+            on_double_click(Id::Rows, Msg::RowOpened),
+        ])
+    }
+
+    fn update(&self, msg: &Msg, model: &mut Model) -> Result<Effect<Msg>, String> {
+        match msg {
+            Msg::Increment => model.count += 1,
+            Msg::NameChanged(name) => model.name = name.value(),
+            Msg::Save => {
+                return Ok(effect::effectful_msg::effectful_msg(
+                    Msg::Saved(Capture::default()),
+                    effect::dom::get_element_string_value(Id::Name),
+                ));
+            }
+            Msg::Saved(name) => model.saved = Some(name.value()),
+            Msg::SizeChanged(size) => model.size = size.value(),
+            Msg::RowOpened(mouse) => model.opened_row = mouse.value().data.get("row").cloned(),
+        }
+
+        Ok(effect::none())
+    }
+
+    fn view(&self, model: &Model) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! {
+                div id=(Id::Counter) {
+                    span { (model.count) }
+                    button id=(Id::Increment) { span { "+" } }
+                    input id=(Id::Name) value=(model.name);
+                    button id=(Id::Save) { "Save" }
+                    input type="radio" name="size" value="small" checked;
+                    input type="radio" name="size" value="large";
+                    ul id=(Id::Rows) {
+                        li data-row="1" { span { "One" } }
+                        li data-row="2" { span { "Two" } }
+                    }
+                }
+            },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn click_dispatches_to_matching_listener() {
+    let mut tester = PageTester::new(Counter).unwrap();
+
+    tester.click("#increment").unwrap();
+    tester.click("#increment").unwrap();
+
+    assert_eq!(tester.model().count, 2);
+    assert!(tester.view_body().contains("<span>2</span>"));
+}
+
+#[test]
+fn click_on_descendant_does_not_match_exact_selector() {
+    let mut tester = PageTester::new(Counter).unwrap();
+
+    assert!(tester.click("#increment span").is_err());
+    assert!(tester.click("#missing").is_err());
+    assert_eq!(tester.model().count, 0);
+}
+
+#[test]
+fn input_captures_the_new_value() {
+    let mut tester = PageTester::new(Counter).unwrap();
+
+    tester.input("#name", "Ada").unwrap();
+
+    assert_eq!(tester.model().name, "Ada");
+    assert!(tester.effectful_msgs().is_empty());
+}
+
+#[test]
+fn resolve_next_fills_the_capture() {
+    let mut tester = PageTester::new(Counter).unwrap();
+
+    tester.click("#save").unwrap();
+    assert_eq!(tester.effectful_msgs().len(), 1);
+
+    let effect = tester.resolve_next("Grace").unwrap();
+
+    assert!(matches!(
+        effect,
+        Effect::Dom(Dom::GetElementValue { ref element_id, .. }) if element_id == "name"
+    ));
+    assert_eq!(tester.model().saved.as_deref(), Some("Grace"));
+    assert!(tester.resolve_next("again").is_err());
+}
+
+#[test]
+fn check_selects_one_radio_of_the_group() {
+    let mut tester = PageTester::new(Counter).unwrap();
+
+    tester.check("input[value=large]").unwrap();
+    assert_eq!(tester.model().size, "large");
+
+    tester.check("input[value=small]").unwrap();
+    assert_eq!(tester.model().size, "small");
+}
+
+#[test]
+fn trigger_sends_the_data_attributes_of_the_target() {
+    let mut tester = PageTester::new(Counter).unwrap();
+
+    tester
+        .trigger("li[data-row='2'] span", EventType::Dblclick)
+        .unwrap();
+    assert_eq!(tester.model().opened_row.as_deref(), Some("2"));
+
+    assert!(tester.trigger("#save", EventType::Dblclick).is_err());
+}
@@ -0,0 +1,163 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::event::MouseData;
+use elmio_core::browser::event::PointerData;
+use elmio_core::browser::event::PointerType;
+use elmio_core::browser::event::ScrollData;
+use elmio_core::browser::event::WheelData;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::event_listener::EventType;
+use elmio_core::browser::subscription::event_listener::on_pointer_down;
+use elmio_core::browser::subscription::event_listener::on_scroll;
+use elmio_core::browser::subscription::event_listener::on_wheel;
+use elmio_core::browser::subscription::event_listener::on_window_scroll;
+use elmio_core::browser::value::Capture;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+
+#[derive(Clone, Copy)]
+enum Id {
+    App,
+    Canvas,
+    Log,
+}
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let id = match self {
+            Id::App => "app",
+            Id::Canvas => "canvas",
+            Id::Log => "log",
+        };
+
+        write!(f, "{}", id)
+    }
+}
+
+impl DomId for Id {}
+
+#[derive(Default)]
+struct Model {
+    pointer: Option<PointerData>,
+    wheel: Option<WheelData>,
+    log_scroll: Option<ScrollData>,
+    window_scroll: Option<ScrollData>,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    PointerDown(Capture<PointerData>),
+    Wheel(Capture<WheelData>),
+    LogScrolled(Capture<ScrollData>),
+    WindowScrolled(Capture<ScrollData>),
+}
+
+struct Drawing;
+
+impl Page<Model, Msg, Markup> for Drawing {
+    fn id(&self) -> &'static dyn DomId {
+        &Id::App
+    }
+
+    fn init(&self) -> Result<(Model, Effect<Msg>), String> {
+        Ok((Model::default(), effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &Model) -> Subscription<Msg> {
+        subscription::batch(vec![
+            on_pointer_down(Id::Canvas, Msg::PointerDown),
+            on_wheel(Id::Canvas, Msg::Wheel),
+            on_scroll(Id::Log, Msg::LogScrolled),
+            on_window_scroll(Msg::WindowScrolled),
+        ])
+    }
+
+    fn update(&self, msg: &Msg, model: &mut Model) -> Result<Effect<Msg>, String> {
+        match msg {
+            Msg::PointerDown(pointer) => model.pointer = Some(pointer.value()),
+            Msg::Wheel(wheel) => model.wheel = Some(wheel.value()),
+            Msg::LogScrolled(scroll) => model.log_scroll = Some(scroll.value()),
+            Msg::WindowScrolled(scroll) => model.window_scroll = Some(scroll.value()),
+        }
+
+        Ok(effect::none())
+    }
+
+    fn view(&self, _model: &Model) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! {
+                div id=(Id::App) {
+                    div id=(Id::Canvas) data-layer="ink" { span { "Draw here" } }
+                    ul id=(Id::Log) { li { "Started" } }
+                }
+            },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn pointer_and_wheel_events_capture_their_payload() {
+    let mut tester = PageTester::new(Drawing).unwrap();
+
+    let pointer = PointerData {
+        mouse: MouseData {
+            client_x: 10.0,
+            client_y: 20.0,
+            ..MouseData::default()
+        },
+        pointer_type: PointerType::Pen,
+        pressure: 0.5,
+        ..PointerData::default()
+    };
+    tester
+        .trigger_with("#canvas span", EventType::Pointerdown, pointer.clone())
+        .unwrap();
+    assert_eq!(tester.model().pointer, Some(pointer));
+
+    // Without a payload, the data attributes of the target are captured.
+    tester.trigger("#canvas span", EventType::Wheel).unwrap();
+    let wheel = tester.model().wheel.clone().unwrap();
+    assert_eq!(
+        wheel.mouse.data.get("layer").map(String::as_str),
+        Some("ink")
+    );
+}
+
+#[test]
+fn scroll_events_capture_the_scroll_position() {
+    let mut tester = PageTester::new(Drawing).unwrap();
+
+    let scroll = ScrollData {
+        scroll_top: 120.0,
+        scroll_height: 800.0,
+        client_height: 200.0,
+        ..ScrollData::default()
+    };
+
+    tester
+        .trigger_with("#log", EventType::Scroll, scroll.clone())
+        .unwrap();
+    assert_eq!(tester.model().log_scroll, Some(scroll.clone()));
+
+    let window_scroll = ScrollData {
+        scroll_top: 40.0,
+        ..scroll.clone()
+    };
+    tester.scroll_window(window_scroll.clone()).unwrap();
+    assert_eq!(tester.model().window_scroll, Some(window_scroll));
+    assert_eq!(tester.model().log_scroll, Some(scroll));
+}
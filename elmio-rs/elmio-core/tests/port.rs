@@ -0,0 +1,153 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::page::JsMsg;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::port::IncomingPort;
+use elmio_core::port::OutgoingPort;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+use serde_json::json;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy)]
+struct Id;
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "chat")
+    }
+}
+
+impl DomId for Id {}
+
+#[derive(elmio_macro::IncomingPort)]
+enum FromJs {
+    Clear,
+    SetName(String),
+    Post { author: String, text: String },
+}
+
+#[derive(elmio_macro::OutgoingPort)]
+enum ToJs {
+    Posted { count: usize },
+    Scores(HashMap<(u8, u8), u8>),
+}
+
+#[derive(Default)]
+struct Model {
+    name: String,
+    posts: Vec<String>,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {}
+
+struct Chat;
+
+impl Page<Model, Msg, Markup> for Chat {
+    fn id(&self) -> &'static dyn DomId {
+        &Id
+    }
+
+    fn init(&self) -> Result<(Model, Effect<Msg>), String> {
+        Ok((Model::default(), effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &Model) -> Subscription<Msg> {
+        subscription::none()
+    }
+
+    fn update(&self, msg: &Msg, _model: &mut Model) -> Result<Effect<Msg>, String> {
+        match *msg {}
+    }
+
+    fn update_from_js(&self, msg: JsMsg, model: &mut Model) -> Result<Effect<Msg>, String> {
+        match FromJs::from_js_msg(msg)? {
+            FromJs::Clear => model.posts.clear(),
+            FromJs::SetName(name) => model.name = name,
+            FromJs::Post { author, text } => model.posts.push(format!("{}: {}", author, text)),
+        }
+
+        ToJs::Posted {
+            count: model.posts.len(),
+        }
+        .send()
+    }
+
+    fn view(&self, model: &Model) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! {
+                p { (model.name) }
+                @for post in &model.posts {
+                    p { (post) }
+                }
+            },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+fn js_msg(type_: &str, data: serde_json::Value) -> JsMsg {
+    JsMsg {
+        type_: type_.to_string(),
+        data,
+    }
+}
+
+#[test]
+fn incoming_messages_are_decoded_in_update_from_js() {
+    let mut tester = PageTester::new(Chat).unwrap();
+
+    tester
+        .update_from_js(js_msg("setName", json!("Ada")))
+        .unwrap();
+    tester
+        .update_from_js(js_msg("post", json!({ "author": "Ada", "text": "Hi" })))
+        .unwrap();
+
+    assert!(tester.view_body().contains("<p>Ada</p><p>Ada: Hi</p>"));
+
+    tester.update_from_js(js_msg("clear", json!(null))).unwrap();
+
+    assert!(!tester.view_body().contains("Hi"));
+}
+
+#[test]
+fn invalid_incoming_messages_are_errors() {
+    let mut tester = PageTester::new(Chat).unwrap();
+
+    assert!(
+        tester
+            .update_from_js(js_msg("logout", json!(null)))
+            .is_err()
+    );
+    assert!(tester.update_from_js(js_msg("setName", json!(1))).is_err());
+}
+
+#[test]
+fn outgoing_messages_are_encoded() {
+    let msg = ToJs::Posted { count: 2 }.to_js_msg().unwrap();
+
+    assert_eq!(msg.type_, "posted");
+    assert_eq!(msg.data, json!({ "count": 2 }));
+}
+
+#[test]
+fn outgoing_encoding_failures_are_errors() {
+    let scores = HashMap::from([((1, 2), 3)]);
+
+    assert!(ToJs::Scores(scores).to_js_msg().is_err());
+}
@@ -0,0 +1,89 @@
+use elmio_core::route::ParseRoute;
+use elmio_core::route::Route;
+use elmio_core::route::RouteError;
+use elmio_core::route::decode;
+use elmio_core::route::decode_segment;
+use elmio_core::route::path_from_url;
+
+#[derive(Debug, PartialEq, elmio_macro::Route)]
+enum AppRoute {
+    #[route("/")]
+    Home,
+    #[route("/tags/:tag")]
+    Tag { tag: String, page: Option<u32> },
+    #[route("/users/:id/posts")]
+    UserPosts(u32),
+}
+
+#[test]
+fn plus_is_kept_in_path_segments() {
+    assert_eq!(
+        AppRoute::from_path("/tags/c++"),
+        Ok(AppRoute::Tag {
+            tag: "c++".to_string(),
+            page: None,
+        })
+    );
+}
+
+#[test]
+fn plus_is_a_space_in_query_values() {
+    assert_eq!(
+        AppRoute::from_path("/tags/a%20b?page=2+"),
+        Err(RouteError::InvalidQuery {
+            name: "page".to_string(),
+            value: "2 ".to_string(),
+        })
+    );
+}
+
+#[test]
+fn paths_round_trip() {
+    let route = AppRoute::Tag {
+        tag: "c++ & rust".to_string(),
+        page: Some(2),
+    };
+
+    assert_eq!(AppRoute::from_path(&route.path()), Ok(route));
+    assert_eq!(
+        AppRoute::from_path("/users/7/posts#top"),
+        Ok(AppRoute::UserPosts(7))
+    );
+}
+
+#[test]
+fn urls_without_a_path_keep_their_query() {
+    assert_eq!(path_from_url("https://host?x=1"), "/?x=1");
+    assert_eq!(path_from_url("https://host#top"), "/#top");
+    assert_eq!(path_from_url("https://host"), "/");
+    assert_eq!(path_from_url("https://host/a?b=1"), "/a?b=1");
+    assert_eq!(path_from_url("/a"), "/a");
+
+    assert_eq!(
+        AppRoute::from_url("https://host?page=3"),
+        Ok(AppRoute::Home)
+    );
+    assert_eq!(
+        AppRoute::from_url("http://host/tags/x?page=3"),
+        Ok(AppRoute::Tag {
+            tag: "x".to_string(),
+            page: Some(3),
+        })
+    );
+}
+
+#[test]
+fn urls_in_the_query_are_not_a_scheme() {
+    assert_eq!(
+        path_from_url("/search?q=http://a.com/b"),
+        "/search?q=http://a.com/b"
+    );
+    assert_eq!(path_from_url("web+app://host/a"), "/a");
+}
+
+#[test]
+fn invalid_escapes_are_kept() {
+    assert_eq!(decode_segment("%+1%2"), "%+1%2");
+    assert_eq!(decode("100%"), "100%");
+    assert_eq!(decode("%41%2b"), "A+");
+}
@@ -0,0 +1,98 @@
+use elmio_core::browser::selector::AttributeOperator;
+use elmio_core::browser::selector::PseudoClass;
+use elmio_core::browser::selector::Selector;
+use elmio_core::html::Document;
+use elmio_core::testing::selector::SelectorList;
+
+fn round_trip(selector: &str) -> String {
+    let parsed = Selector::parse(selector).unwrap();
+    let json = serde_json::to_string(&parsed).unwrap();
+    let decoded: Selector = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(decoded, parsed);
+    decoded.to_string()
+}
+
+fn query_ids(html: &str, selector: &str) -> Vec<String> {
+    let document = Document::parse(html);
+
+    SelectorList::parse(selector)
+        .unwrap()
+        .query_all(&document)
+        .into_iter()
+        .filter_map(|id| document.element(id)?.id().map(str::to_string))
+        .collect()
+}
+
+#[test]
+fn sibling_combinators() {
+    assert_eq!(round_trip("h2 + p"), "h2 + p");
+    assert_eq!(round_trip("h2~p"), "h2 ~ p");
+    assert_eq!(
+        Selector::tag("h2")
+            .next_sibling(Selector::tag("p"))
+            .to_string(),
+        "h2 + p"
+    );
+
+    let html = r#"<p id="a"></p><h2></h2><p id="b"></p><span></span><p id="c"></p>"#;
+
+    assert_eq!(query_ids(html, "h2 + p"), vec!["b"]);
+    assert_eq!(query_ids(html, "h2 ~ p"), vec!["b", "c"]);
+}
+
+#[test]
+fn pseudo_classes() {
+    assert_eq!(round_trip("input:checked"), "input:checked");
+    assert_eq!(round_trip("li:FIRST-CHILD"), "li:first-child");
+    assert_eq!(
+        Selector::tag("li")
+            .with_pseudo_class(PseudoClass::LastOfType)
+            .to_string(),
+        "li:last-of-type"
+    );
+    assert!(Selector::parse("a:hover").is_err());
+
+    let html = r#"
+        <ul><li id="first"></li><li id="middle">x</li><li id="last"></li></ul>
+        <input id="on" type="checkbox" checked><input id="off" type="checkbox" disabled>
+    "#;
+
+    assert_eq!(query_ids(html, "li:first-child"), vec!["first"]);
+    assert_eq!(query_ids(html, "li:last-child"), vec!["last"]);
+    assert_eq!(query_ids(html, "li:empty"), vec!["first", "last"]);
+    assert_eq!(query_ids(html, "input:checked"), vec!["on"]);
+    assert_eq!(query_ids(html, "input:enabled"), vec!["on"]);
+    assert_eq!(query_ids(html, "input:disabled"), vec!["off"]);
+}
+
+#[test]
+fn attribute_case_flag() {
+    assert_eq!(round_trip(r#"[type="EMAIL" i]"#), r#"[type="EMAIL" i]"#);
+    assert_eq!(round_trip("[type=email s]"), r#"[type="email"]"#);
+    assert!(Selector::parse("[type=email x]").is_err());
+
+    let html = r#"<input id="a" type="Email"><input id="b" type="text">"#;
+
+    assert_eq!(query_ids(html, r#"[type="email" i]"#), vec!["a"]);
+    assert!(query_ids(html, r#"[type="email"]"#).is_empty());
+    assert_eq!(
+        SelectorList::from(Selector::any().with_attribute_value_ignore_case(
+            "type",
+            AttributeOperator::Prefix,
+            "EM"
+        ))
+        .query_all(&Document::parse(html))
+        .len(),
+        1
+    );
+}
+
+#[test]
+fn empty_list_matches_nothing() {
+    let empty = Selector::list(vec![]);
+
+    assert_eq!(empty.to_string(), ":not(*)");
+    assert_eq!(round_trip(&empty.to_string()), ":not(*)");
+    assert!(query_ids(r#"<p id="a"></p>"#, &empty.to_string()).is_empty());
+}
@@ -0,0 +1,60 @@
+use elmio_core::browser::DebounceConfig;
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::event_listener::RateLimit;
+use elmio_core::browser::subscription::event_listener::on_click;
+use elmio_core::browser::subscription::interval::interval;
+use std::time::Duration;
+
+#[derive(Clone, Copy)]
+struct Id;
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "search")
+    }
+}
+
+impl DomId for Id {}
+
+#[derive(Clone, serde::Serialize)]
+enum Msg {
+    Search,
+    Tick,
+}
+
+#[test]
+fn debounce_rate_limits_listeners_in_batches() {
+    let subscription = subscription::batch(vec![
+        on_click(Id, Msg::Search),
+        subscription::batch(vec![on_click(Id, Msg::Search)]),
+    ])
+    .debounce(DebounceConfig::new(Duration::from_millis(300)));
+
+    for subscription in subscription.into_vec() {
+        let Subscription::EventListener(listener) = subscription else {
+            panic!("Expected an event listener");
+        };
+
+        assert!(matches!(listener.rate_limit, Some(RateLimit::Debounce(_))));
+    }
+}
+
+#[test]
+fn throttle_leaves_other_subscriptions_unchanged() {
+    let subscription = subscription::batch(vec![
+        on_click(Id, Msg::Search),
+        interval(Duration::from_secs(1), Msg::Tick),
+    ])
+    .throttle(DebounceConfig::new(Duration::from_millis(300)));
+
+    let subscriptions = subscription.into_vec();
+
+    assert!(matches!(
+        &subscriptions[0],
+        Subscription::EventListener(listener)
+            if matches!(listener.rate_limit, Some(RateLimit::Throttle(_)))
+    ));
+    assert!(matches!(&subscriptions[1], Subscription::Interval(_)));
+}
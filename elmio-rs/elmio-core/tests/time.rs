@@ -0,0 +1,75 @@
+use elmio_core::time::Posix;
+use elmio_core::time::Zone;
+use std::time::Duration;
+use std::time::UNIX_EPOCH;
+
+fn parse(s: &str) -> Posix {
+    Posix::from_iso_string(s).unwrap()
+}
+
+#[test]
+fn leap_days_round_trip() {
+    let leap_day = parse("2000-02-29");
+    assert_eq!(leap_day.as_millis(), 951_782_400_000);
+    assert_eq!(
+        leap_day.to_iso_string(&Zone::utc()),
+        "2000-02-29T00:00:00.000Z"
+    );
+
+    // 1900 is not a leap year, so March follows February 28th.
+    let march = parse("1900-03-01T00:00Z");
+    assert_eq!(march.as_millis(), -2_203_891_200_000);
+    assert_eq!(
+        Posix::from_millis(march.as_millis() - 1).to_iso_string(&Zone::utc()),
+        "1900-02-28T23:59:59.999Z"
+    );
+}
+
+#[test]
+fn times_before_1970() {
+    let time = Posix::from_millis(-1);
+
+    assert_eq!(time.to_iso_string(&Zone::utc()), "1969-12-31T23:59:59.999Z");
+    assert_eq!(parse("1969-12-31T23:59:59.999Z"), time);
+    assert_eq!(time.as_system_time(), UNIX_EPOCH - Duration::from_millis(1));
+}
+
+#[test]
+fn offsets_round_trip() {
+    let time = parse("2024-03-01T13:30Z");
+    assert_eq!(time.as_millis(), 1_709_299_800_000);
+
+    let kathmandu = Zone::fixed(5 * 60 + 45);
+    assert_eq!(
+        time.to_iso_string(&kathmandu),
+        "2024-03-01T19:15:00.000+05:45"
+    );
+    assert_eq!(parse("2024-03-01T19:15:00.000+05:45"), time);
+
+    let zone = Zone::fixed(-30);
+    assert_eq!(time.to_iso_string(&zone), "2024-03-01T13:00:00.000-00:30");
+    assert_eq!(parse("2024-03-01T13:00:00.000-00:30"), time);
+}
+
+#[test]
+fn utc_is_written_as_z() {
+    assert_eq!(
+        Posix::from_millis(0).to_iso_string(&Zone::utc()),
+        "1970-01-01T00:00:00.000Z"
+    );
+}
+
+#[test]
+fn invalid_dates_are_rejected() {
+    assert!(Posix::from_iso_string("2023-02-29").is_err());
+    assert!(Posix::from_iso_string("2024-03-01T24:00Z").is_err());
+    assert!(Posix::from_iso_string("2024-03-01T13:30:00.2x0Z").is_err());
+}
+
+#[test]
+fn long_fractions_are_truncated_to_milliseconds() {
+    assert_eq!(
+        parse("2024-03-01T13:30:00.123456789012Z"),
+        parse("2024-03-01T13:30:00.123Z")
+    );
+}
@@ -0,0 +1,147 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::effect::browser::Browser;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::event_listener::on_click;
+use elmio_core::browser::subscription::event_listener::on_input;
+use elmio_core::browser::value::Capture;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+use std::time::Duration;
+
+#[derive(Clone, Copy)]
+enum Id {
+    App,
+    Query,
+    Cancel,
+}
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let id = match self {
+            Id::App => "app",
+            Id::Query => "query",
+            Id::Cancel => "cancel",
+        };
+
+        write!(f, "{}", id)
+    }
+}
+
+impl DomId for Id {}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    QueryChanged(Capture<String>),
+    Cancel,
+    Search(String),
+    HideToast,
+}
+
+#[derive(Default)]
+struct Model {
+    searches: Vec<String>,
+    toast_hidden: bool,
+}
+
+struct Search;
+
+impl Page<Model, Msg, Markup> for Search {
+    fn id(&self) -> &'static dyn DomId {
+        &Id::App
+    }
+
+    fn init(&self) -> Result<(Model, Effect<Msg>), String> {
+        let effect = effect::browser::set_timeout(Duration::from_secs(5), Msg::HideToast);
+        Ok((Model::default(), effect))
+    }
+
+    fn subscriptions(&self, _model: &Model) -> Subscription<Msg> {
+        subscription::batch(vec![
+            on_input(Id::Query, Msg::QueryChanged),
+            on_click(Id::Cancel, Msg::Cancel),
+        ])
+    }
+
+    fn update(&self, msg: &Msg, model: &mut Model) -> Result<Effect<Msg>, String> {
+        match msg {
+            Msg::QueryChanged(query) => {
+                return Ok(effect::browser::set_named_timeout(
+                    "search",
+                    Duration::from_millis(300),
+                    Msg::Search(query.value()),
+                ));
+            }
+            Msg::Cancel => return Ok(effect::browser::clear_timeout("search")),
+            Msg::Search(query) => model.searches.push(query.clone()),
+            Msg::HideToast => model.toast_hidden = true,
+        }
+
+        Ok(effect::none())
+    }
+
+    fn view(&self, _model: &Model) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! {
+                div id=(Id::App) {
+                    input id=(Id::Query);
+                    button id=(Id::Cancel) { "Cancel" }
+                }
+            },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+fn pending_timers(tester: &PageTester<Search, Model, Msg, Markup>) -> Vec<Option<String>> {
+    tester
+        .effectful_msgs()
+        .into_iter()
+        .map(|effectful_msg| match &effectful_msg.effect {
+            Effect::Browser(Browser::SetNamedTimeout { key, .. }) => Some(key.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn named_timers_replace_the_pending_one() {
+    let mut tester = PageTester::new(Search).unwrap();
+
+    tester.input("#query", "el").unwrap();
+    tester.input("#query", "elm").unwrap();
+    assert_eq!(pending_timers(&tester), [None, Some("search".to_string())]);
+
+    // The toast timer was set first.
+    tester.resolve_next(()).unwrap();
+    tester.resolve_next(()).unwrap();
+
+    assert!(tester.model().toast_hidden);
+    assert_eq!(tester.model().searches, ["elm"]);
+}
+
+#[test]
+fn cleared_timers_never_fire() {
+    let mut tester = PageTester::new(Search).unwrap();
+
+    tester.input("#query", "elm").unwrap();
+    tester.click("#cancel").unwrap();
+    assert_eq!(pending_timers(&tester), [None]);
+
+    tester.resolve_next(()).unwrap();
+    assert!(tester.resolve_next(()).is_err());
+    assert!(tester.model().searches.is_empty());
+}
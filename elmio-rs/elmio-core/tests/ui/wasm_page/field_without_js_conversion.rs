@@ -0,0 +1,61 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use maud::Markup;
+use maud::html;
+
+struct Id;
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "home")
+    }
+}
+
+impl DomId for Id {}
+
+pub struct Session;
+
+#[elmio_macro::wasm_page]
+pub struct HomePage {
+    session: Session,
+}
+
+impl Page<(), (), Markup> for HomePage {
+    fn id(&self) -> &'static dyn DomId {
+        &Id
+    }
+
+    fn init(&self) -> Result<((), Effect<()>), String> {
+        Ok(((), effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &()) -> Subscription<()> {
+        subscription::none()
+    }
+
+    fn update(&self, _msg: &(), _model: &mut ()) -> Result<Effect<()>, String> {
+        Ok(effect::none())
+    }
+
+    fn view(&self, _model: &()) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! {},
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+fn main() {}
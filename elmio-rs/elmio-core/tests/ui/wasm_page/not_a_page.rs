@@ -0,0 +1,4 @@
+#[elmio_macro::wasm_page]
+pub struct HomePage;
+
+fn main() {}
@@ -0,0 +1,265 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::event_listener::on_click;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use elmio_core::vdom;
+use elmio_core::vdom::Patch;
+use elmio_core::vdom::VNode;
+use maud::Markup;
+use maud::html;
+
+fn node(html: &str) -> VNode {
+    match VNode::parse(html).as_slice() {
+        [node] => node.clone(),
+        nodes => panic!("expected a single node, got {:?}", nodes),
+    }
+}
+
+fn diff(old: &str, new: &str) -> Vec<Patch> {
+    vdom::diff(&node(old), &node(new))
+}
+
+#[test]
+fn identical_markup_yields_no_patches() {
+    let html = r#"<div id="app"><p class="a">text</p><input value="x"></div>"#;
+
+    assert_eq!(diff(html, html), []);
+}
+
+#[test]
+fn changed_text_is_set_in_place() {
+    assert_eq!(
+        diff(
+            r#"<div id="app"><p>1</p></div>"#,
+            r#"<div id="app"><p>2</p></div>"#
+        ),
+        [Patch::SetText {
+            path: vec![0, 0],
+            node: "#text".to_string(),
+            text: "2".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn attributes_are_set_and_removed() {
+    assert_eq!(
+        diff(
+            r#"<div id="app"><input value="a" disabled></div>"#,
+            r#"<div id="app"><input value="b" checked></div>"#
+        ),
+        [
+            Patch::SetAttribute {
+                path: vec![0],
+                node: "input".to_string(),
+                name: "value".to_string(),
+                value: "b".to_string(),
+            },
+            Patch::SetAttribute {
+                path: vec![0],
+                node: "input".to_string(),
+                name: "checked".to_string(),
+                value: String::new(),
+            },
+            Patch::RemoveAttribute {
+                path: vec![0],
+                node: "input".to_string(),
+                name: "disabled".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn changed_tags_are_replaced() {
+    assert_eq!(
+        diff(
+            r#"<div id="app"><p>a</p></div>"#,
+            r#"<div id="app"><span title="&quot;">a &amp; b</span></div>"#
+        ),
+        [Patch::Replace {
+            path: vec![0],
+            node: "p".to_string(),
+            html: r#"<span title="&quot;">a &amp; b</span>"#.to_string(),
+        }]
+    );
+}
+
+#[test]
+fn new_children_are_inserted_into_their_parent() {
+    assert_eq!(
+        diff(
+            r#"<div id="app"><ul><li>1</li></ul></div>"#,
+            r#"<div id="app"><ul><li>1</li><li>2</li><li>3</li></ul></div>"#
+        ),
+        [
+            Patch::Insert {
+                path: vec![0],
+                node: "ul".to_string(),
+                index: 1,
+                html: "<li>2</li>".to_string(),
+            },
+            Patch::Insert {
+                path: vec![0],
+                node: "ul".to_string(),
+                index: 2,
+                html: "<li>3</li>".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn removed_children_are_removed_from_the_end() {
+    assert_eq!(
+        diff(
+            r#"<div id="app"><ul><li>1</li>2<li>3</li></ul></div>"#,
+            r#"<div id="app"><ul><li>1</li></ul></div>"#
+        ),
+        [
+            Patch::Remove {
+                path: vec![0, 2],
+                node: "li".to_string(),
+            },
+            Patch::Remove {
+                path: vec![0, 1],
+                node: "#text".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn unmanaged_elements_are_left_alone() {
+    assert_eq!(
+        diff(
+            r#"<div id="app"><div unmanaged><p>a</p></div></div>"#,
+            r#"<div id="app"><div unmanaged class="x"><p>b</p></div></div>"#
+        ),
+        []
+    );
+}
+
+#[test]
+fn raw_text_and_void_elements_round_trip() {
+    let html = r#"<div id="app"><script>if (a < b) {}</script><br><img src="a.png"></div>"#;
+
+    assert_eq!(node(html).to_html(), html);
+}
+
+#[test]
+fn character_references_are_only_decoded_in_escapable_raw_text() {
+    let html = r#"<div id="app"><script>a &amp;&amp; b</script><textarea>&lt;b&gt;</textarea><title>a &amp; b</title></div>"#;
+
+    assert_eq!(node(html).to_html(), html);
+    assert_eq!(
+        node(r#"<textarea>&lt;b&gt;</textarea>"#),
+        VNode::Element {
+            tag: "textarea".to_string(),
+            attributes: vec![],
+            children: vec![VNode::Text("<b>".to_string())],
+        }
+    );
+}
+
+#[derive(Clone, Copy)]
+enum Id {
+    App,
+    Add,
+}
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Id::App => write!(f, "app"),
+            Id::Add => write!(f, "add"),
+        }
+    }
+}
+
+impl DomId for Id {}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    Add,
+}
+
+struct List;
+
+impl Page<Vec<u32>, Msg, Markup> for List {
+    fn id(&self) -> &'static dyn DomId {
+        &Id::App
+    }
+
+    fn init(&self) -> Result<(Vec<u32>, Effect<Msg>), String> {
+        Ok((vec![1], effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &Vec<u32>) -> Subscription<Msg> {
+        subscription::batch(vec![on_click(Id::Add, Msg::Add)])
+    }
+
+    fn update(&self, msg: &Msg, model: &mut Vec<u32>) -> Result<Effect<Msg>, String> {
+        match msg {
+            Msg::Add => model.push(model.len() as u32 + 1),
+        }
+
+        Ok(effect::none())
+    }
+
+    fn view(&self, model: &Vec<u32>) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! {
+                div id=(Id::App) {
+                    button id=(Id::Add) { "Add" }
+                    ul {
+                        @for item in model {
+                            li { (item) }
+                        }
+                    }
+                }
+            },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn view_patches_diff_against_the_previous_view() {
+    let mut tester = PageTester::new(List).unwrap();
+
+    assert_eq!(
+        tester.view_patches(),
+        [Patch::Replace {
+            path: vec![],
+            node: String::new(),
+            html: tester.view_body(),
+        }]
+    );
+    assert_eq!(tester.view_patches(), []);
+
+    tester.click("#add").unwrap();
+
+    assert_eq!(
+        tester.view_patches(),
+        [Patch::Insert {
+            path: vec![1],
+            node: "ul".to_string(),
+            index: 1,
+            html: "<li>2</li>".to_string(),
+        }]
+    );
+}
@@ -0,0 +1,105 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use maud::Markup;
+use maud::html;
+
+#[derive(Clone, Copy)]
+struct Id;
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "home")
+    }
+}
+
+impl DomId for Id {}
+
+#[elmio_macro::wasm_page]
+pub struct HomePage {
+    current_url: String,
+}
+
+#[elmio_macro::wasm_page(js_name = "login")]
+pub struct LoginPage;
+
+impl Page<String, (), Markup> for HomePage {
+    fn id(&self) -> &'static dyn DomId {
+        &Id
+    }
+
+    fn init(&self) -> Result<(String, Effect<()>), String> {
+        Ok((self.current_url.clone(), effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &String) -> Subscription<()> {
+        subscription::none()
+    }
+
+    fn update(&self, _msg: &(), _model: &mut String) -> Result<Effect<()>, String> {
+        Ok(effect::none())
+    }
+
+    fn view(&self, model: &String) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! { div id=(Id) { (model) } },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+impl Page<String, (), Markup> for LoginPage {
+    fn id(&self) -> &'static dyn DomId {
+        &Id
+    }
+
+    fn init(&self) -> Result<(String, Effect<()>), String> {
+        Ok((String::new(), effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &String) -> Subscription<()> {
+        subscription::none()
+    }
+
+    fn update(&self, _msg: &(), _model: &mut String) -> Result<Effect<()>, String> {
+        Ok(effect::none())
+    }
+
+    fn view(&self, _model: &String) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! { div id=(Id) {} },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn constructors_are_named_in_camel_case() {
+    assert_eq!(WasmHomePage::JS_CONSTRUCTOR, "homePage");
+    assert_eq!(WasmLoginPage::JS_CONSTRUCTOR, "login");
+}
+
+#[test]
+fn invalid_pages_do_not_compile() {
+    trybuild::TestCases::new().compile_fail("tests/ui/wasm_page/*.rs");
+}
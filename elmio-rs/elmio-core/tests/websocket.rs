@@ -0,0 +1,156 @@
+use elmio_core::browser::dom_id::DomId;
+use elmio_core::browser::effect;
+use elmio_core::browser::effect::Effect;
+use elmio_core::browser::effect::websocket::WebSocket;
+use elmio_core::browser::subscription;
+use elmio_core::browser::subscription::Subscription;
+use elmio_core::browser::subscription::websocket;
+use elmio_core::browser::subscription::websocket::Backoff;
+use elmio_core::browser::subscription::websocket::ConnectionState;
+use elmio_core::browser::value::Capture;
+use elmio_core::page::Page;
+use elmio_core::page::PageMarkup;
+use elmio_core::testing::PageTester;
+use maud::Markup;
+use maud::html;
+
+const CHAT_URL: &str = "wss://example.com/chat";
+const SCORES_URL: &str = "wss://example.com/scores";
+
+#[derive(Clone, Copy)]
+struct Id;
+
+impl std::fmt::Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "chat")
+    }
+}
+
+impl DomId for Id {}
+
+#[derive(Default)]
+struct Model {
+    states: Vec<ConnectionState>,
+    messages: Vec<String>,
+    scores: Vec<u32>,
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Msg {
+    Received(Capture<String>),
+    StateChanged(ConnectionState),
+    ScoresReceived(Capture<Vec<u32>>),
+}
+
+struct Chat;
+
+impl Page<Model, Msg, Markup> for Chat {
+    fn id(&self) -> &'static dyn DomId {
+        &Id
+    }
+
+    fn init(&self) -> Result<(Model, Effect<Msg>), String> {
+        Ok((Model::default(), effect::none()))
+    }
+
+    fn subscriptions(&self, _model: &Model) -> Subscription<Msg> {
+        subscription::batch(vec![
+            websocket::listen_with_state(
+                CHAT_URL,
+                Backoff::default(),
+                Msg::Received,
+                Msg::StateChanged,
+            ),
+            websocket::listen_json(SCORES_URL, Msg::ScoresReceived),
+        ])
+    }
+
+    fn update(&self, msg: &Msg, model: &mut Model) -> Result<Effect<Msg>, String> {
+        match msg {
+            Msg::Received(message) => {
+                let message = message.value();
+                model.messages.push(message.clone());
+
+                return Ok(effect::websocket::send(
+                    CHAT_URL,
+                    &format!("ack {}", message),
+                ));
+            }
+            Msg::StateChanged(state) => model.states.push(*state),
+            Msg::ScoresReceived(scores) => model.scores = scores.value(),
+        }
+
+        Ok(effect::none())
+    }
+
+    fn view(&self, _model: &Model) -> PageMarkup<Markup> {
+        PageMarkup {
+            head: html! {},
+            body: html! { div id=(Id) {} },
+        }
+    }
+
+    fn render(&self, markup: Markup) -> String {
+        markup.into_string()
+    }
+
+    fn render_page(&self, markup: PageMarkup<Markup>) -> String {
+        elmio_core::page::render_page_maud(markup)
+    }
+}
+
+#[test]
+fn state_changes_deliver_their_messages() {
+    let mut tester = PageTester::new(Chat).unwrap();
+
+    tester
+        .set_websocket_state(CHAT_URL, ConnectionState::Open)
+        .unwrap();
+    tester
+        .set_websocket_state(CHAT_URL, ConnectionState::Closed)
+        .unwrap();
+
+    assert_eq!(
+        tester.model().states,
+        [ConnectionState::Open, ConnectionState::Closed]
+    );
+    assert!(
+        tester
+            .set_websocket_state(SCORES_URL, ConnectionState::Open)
+            .is_err()
+    );
+}
+
+#[test]
+fn messages_are_delivered_to_the_socket_of_their_url() {
+    let mut tester = PageTester::new(Chat).unwrap();
+
+    tester.receive_websocket_message(CHAT_URL, "hello").unwrap();
+    tester
+        .receive_websocket_message(SCORES_URL, "[3, 5]")
+        .unwrap();
+
+    assert_eq!(tester.model().messages, ["hello"]);
+    assert_eq!(tester.model().scores, [3, 5]);
+
+    let sent: Vec<_> = tester
+        .effects()
+        .iter()
+        .filter_map(|effect| match effect {
+            Effect::WebSocket(WebSocket::Send { url, data }) => Some((url.as_str(), data.as_str())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(sent, [(CHAT_URL, "ack hello")]);
+
+    assert!(
+        tester
+            .receive_websocket_message(SCORES_URL, "not json")
+            .is_err()
+    );
+    assert!(
+        tester
+            .receive_websocket_message("wss://example.com/other", "hi")
+            .is_err()
+    );
+}
@@ -0,0 +1,231 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{Attribute, Data, DeriveInput, Fields, LitStr, Variant};
+
+struct RenderedVariant<'a> {
+    variant: &'a Variant,
+    id: String,
+}
+
+#[derive(Default)]
+struct ContainerAttrs {
+    display: bool,
+    prefix: Option<String>,
+}
+
+pub fn expand(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let Data::Enum(data) = &ast.data else {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "DomId can only be derived for enums",
+        ));
+    };
+
+    let attrs = parse_container_attrs(&ast.attrs)?;
+
+    let dom_id_impl = quote! {
+        impl #impl_generics ::elmio_core::browser::dom_id::DomId for #name #ty_generics #where_clause {}
+    };
+
+    if !attrs.display {
+        if let Some(attr) = find_attr(&ast.attrs).or_else(|| {
+            data.variants
+                .iter()
+                .find_map(|variant| find_attr(&variant.attrs))
+        }) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "dom_id attributes only apply to the generated `Display`, add `#[dom_id(display)]`",
+            ));
+        }
+
+        return Ok(dom_id_impl);
+    }
+
+    let prefix = attrs.prefix;
+
+    let rendered = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let name = match parse_rename(&variant.attrs)? {
+                Some(name) => name,
+                None => kebab_case(&variant.ident.unraw().to_string()),
+            };
+
+            let id = match &prefix {
+                Some(prefix) => format!("{}-{}", prefix, name),
+                None => name,
+            };
+
+            Ok(RenderedVariant { variant, id })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    check_unique(&rendered)?;
+
+    let arms = rendered.iter().map(|RenderedVariant { variant, id }| {
+        let variant_ident = &variant.ident;
+
+        match &variant.fields {
+            Fields::Unit => quote! {
+                Self::#variant_ident => f.write_str(#id),
+            },
+
+            Fields::Unnamed(fields) => {
+                let bindings: Vec<_> = (0..fields.unnamed.len())
+                    .map(|index| format_ident!("field_{}", index))
+                    .collect();
+
+                quote! {
+                    Self::#variant_ident(#(#bindings),*) => {
+                        f.write_str(#id)?;
+                        #(write!(f, "-{}", #bindings)?;)*
+                        Ok(())
+                    }
+                }
+            }
+
+            Fields::Named(fields) => {
+                let bindings: Vec<_> = fields
+                    .named
+                    .iter()
+                    .map(|field| field.ident.as_ref().expect("named field"))
+                    .collect();
+
+                quote! {
+                    Self::#variant_ident { #(#bindings),* } => {
+                        f.write_str(#id)?;
+                        #(write!(f, "-{}", #bindings)?;)*
+                        Ok(())
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        #dom_id_impl
+    })
+}
+
+// Rejects variants that render to the same id, including variants that
+// collide with the id of a variant carrying data, e.g. `RowA` or `RowCol(u32)`
+// and `Row(String)`.
+fn check_unique(rendered: &[RenderedVariant]) -> syn::Result<()> {
+    for (index, current) in rendered.iter().enumerate() {
+        for previous in &rendered[..index] {
+            let message = if current.id == previous.id {
+                "renders to the same DOM id as"
+            } else if shadows(previous, current) || shadows(current, previous) {
+                "can render to the same DOM id as"
+            } else {
+                continue;
+            };
+
+            return Err(syn::Error::new_spanned(
+                &current.variant.ident,
+                format!(
+                    "`{}` {} `{}` (\"{}\")",
+                    current.variant.ident, message, previous.variant.ident, current.id
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// The fields of `data` are rendered after a dash, so they can complete its id
+// to the id of `other`.
+fn shadows(other: &RenderedVariant, data: &RenderedVariant) -> bool {
+    !matches!(data.variant.fields, Fields::Unit) && other.id.starts_with(&format!("{}-", data.id))
+}
+
+fn find_attr(attrs: &[Attribute]) -> Option<&Attribute> {
+    attrs.iter().find(|attr| attr.path().is_ident("dom_id"))
+}
+
+fn parse_container_attrs(attrs: &[Attribute]) -> syn::Result<ContainerAttrs> {
+    let mut container = ContainerAttrs::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("dom_id")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("display") {
+                container.display = true;
+                Ok(())
+            } else if meta.path.is_ident("prefix") {
+                let lit: LitStr = meta.value()?.parse()?;
+                container.prefix = Some(lit.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported dom_id attribute, expected `display` or `prefix`"))
+            }
+        })?;
+    }
+
+    Ok(container)
+}
+
+fn parse_rename(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    let mut value = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("dom_id")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                let lit: LitStr = meta.value()?.parse()?;
+                value = Some(lit.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported dom_id attribute, expected `rename`"))
+            }
+        })?;
+    }
+
+    Ok(value)
+}
+
+// Splits words like `heck`, so `HTTPServer2Up` becomes `http-server2-up`.
+fn kebab_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut kebab = String::with_capacity(name.len() + 4);
+
+    for (index, c) in chars.iter().enumerate() {
+        if *c == '_' {
+            if !kebab.is_empty() && !kebab.ends_with('-') {
+                kebab.push('-');
+            }
+
+            continue;
+        }
+
+        if c.is_uppercase() && index > 0 && !kebab.ends_with('-') {
+            let previous = chars[index - 1];
+            let next = chars.get(index + 1);
+
+            let starts_word = previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next.is_some_and(|next| next.is_lowercase()));
+
+            if starts_word {
+                kebab.push('-');
+            }
+        }
+
+        kebab.extend(c.to_lowercase());
+    }
+
+    kebab
+}
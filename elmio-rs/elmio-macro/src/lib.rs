@@ -3,14 +3,99 @@ use quote::quote;
 use syn::DeriveInput;
 use syn::{Ident, parse_macro_input};
 
-#[proc_macro_derive(DomId)]
+mod dom_id;
+mod port;
+mod route;
+mod wasm_page;
+
+/// Derives `DomId` for an enum of DOM ids, which also needs `Display`.
+///
+/// With `#[dom_id(display)]` on the enum, `Display` is derived as well.
+/// Variants are then rendered in kebab-case, followed by their fields
+/// separated by dashes, so `Row(3)` becomes `row-3`. Ids can be prefixed
+/// with `#[dom_id(display, prefix = "todo")]`, and a variant renamed with
+/// `#[dom_id(rename = "app")]`. Variants that could render to the same id
+/// are rejected at compile time.
+#[proc_macro_derive(DomId, attributes(dom_id))]
 pub fn dom_id_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
-    let name = &ast.ident;
 
-    TokenStream::from(quote! {
-        impl DomId for #name {}
-    })
+    match dom_id::expand(&ast) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+/// Derives `Route` and `ParseRoute` for an enum whose variants carry a
+/// `#[route("/users/:id/posts")]` pattern.
+///
+/// Segments starting with `:` are parsed into the field of the same name
+/// (or, for tuple variants, into the fields in order). Remaining named
+/// fields are query parameters, optional when their type is an `Option`.
+#[proc_macro_derive(Route, attributes(route))]
+pub fn route_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match route::expand(&ast) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+/// Derives `IncomingPort` for an enum of messages sent from JavaScript.
+///
+/// Each variant is received as `{ type, data }`, where `type` is the variant
+/// name in camelCase. Unit variants have no data, single field tuple
+/// variants take the field as data, and struct variants take an object
+/// with camelCase keys.
+///
+/// Decode the messages in `Page::update_from_js` with
+/// `IncomingPort::from_js_msg`.
+#[proc_macro_derive(IncomingPort)]
+pub fn incoming_port_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match port::expand_incoming(&ast) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+/// Derives `OutgoingPort` for an enum of messages sent to JavaScript, encoded
+/// the same way as `IncomingPort`.
+#[proc_macro_derive(OutgoingPort)]
+pub fn outgoing_port_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match port::expand_outgoing(&ast) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+/// Generates the wasm-bindgen wrapper of a page struct and its JavaScript
+/// constructor, exported as the struct name in camelCase unless overridden
+/// with `#[wasm_page(js_name = "...")]`.
+///
+/// The constructor takes one argument per struct field, such as the current
+/// URL, each decoded from JavaScript with serde. Its name is available as
+/// `JS_CONSTRUCTOR` on the generated `Wasm` wrapper.
+///
+/// Stateful pages are not supported, since the wrapper would need the model,
+/// message and markup types of the page. Use `impl_stateful_wasm_page!` for
+/// those.
+#[proc_macro_attribute]
+pub fn wasm_page(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut page_args = wasm_page::Args::default();
+    let parser = syn::meta::parser(|meta| page_args.parse(meta));
+    parse_macro_input!(args with parser);
+
+    let item = parse_macro_input!(input as syn::ItemStruct);
+
+    match wasm_page::expand(page_args, &item) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
 }
 
 #[proc_macro]
@@ -57,3 +142,63 @@ pub fn impl_wasm_page(args: TokenStream) -> TokenStream {
         }
     ))
 }
+
+#[proc_macro]
+pub fn impl_stateful_wasm_page(args: TokenStream) -> TokenStream {
+    let name_ident = parse_macro_input!(args as Ident);
+
+    TokenStream::from(quote!(
+        #[wasm_bindgen]
+        impl #name_ident {
+            #[wasm_bindgen(js_name = "id")]
+            pub fn id(&self) -> Result<String, JsValue> {
+                Ok(self.0.page().id().to_string())
+            }
+
+            #[wasm_bindgen(js_name = "isStateful")]
+            pub fn is_stateful(&self) -> bool {
+                true
+            }
+
+            #[wasm_bindgen(js_name = "init")]
+            pub fn init(&mut self) -> Result<JsValue, JsValue> {
+                self.0.init()
+            }
+
+            #[wasm_bindgen(js_name = "view")]
+            pub fn view(&self) -> Result<String, JsValue> {
+                self.0.view()
+            }
+
+            #[wasm_bindgen(js_name = "viewBody")]
+            pub fn view_body(&self) -> Result<String, JsValue> {
+                self.0.view_body()
+            }
+
+            #[wasm_bindgen(js_name = "viewPatches")]
+            pub fn view_patches(&mut self) -> Result<JsValue, JsValue> {
+                self.0.view_patches()
+            }
+
+            #[wasm_bindgen(js_name = "resetView")]
+            pub fn reset_view(&mut self) {
+                self.0.reset_view()
+            }
+
+            #[wasm_bindgen(js_name = "getSubscriptions")]
+            pub fn get_subscriptions(&self) -> Result<JsValue, JsValue> {
+                self.0.get_subscriptions()
+            }
+
+            #[wasm_bindgen(js_name = "update")]
+            pub fn update(&mut self, js_msg: &JsValue) -> Result<JsValue, JsValue> {
+                self.0.update(js_msg)
+            }
+
+            #[wasm_bindgen(js_name = "updateFromJs")]
+            pub fn update_from_js(&mut self, js_msg: &JsValue) -> Result<JsValue, JsValue> {
+                self.0.update_from_js(js_msg)
+            }
+        }
+    ))
+}
@@ -0,0 +1,165 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{Data, DataEnum, DeriveInput, Fields};
+
+pub fn expand_incoming(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let data = port_enum(ast, "IncomingPort")?;
+
+    let arms = data.variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        let type_name = camel_case(&variant_ident.unraw().to_string());
+
+        let construct = match &variant.fields {
+            Fields::Unit => quote!(Self::#variant_ident),
+
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! {
+                Self::#variant_ident(::elmio_core::port::decode_data(&msg.type_, msg.data)?)
+            },
+
+            Fields::Unnamed(fields) => {
+                let bindings: Vec<_> = (0..fields.unnamed.len())
+                    .map(|index| format_ident!("field_{}", index))
+                    .collect();
+                let types = fields.unnamed.iter().map(|field| &field.ty);
+
+                quote! {{
+                    let (#(#bindings),*): (#(#types),*) =
+                        ::elmio_core::port::decode_data(&msg.type_, msg.data)?;
+                    Self::#variant_ident(#(#bindings),*)
+                }}
+            }
+
+            Fields::Named(fields) => {
+                let values = fields.named.iter().map(|field| {
+                    let ident = field.ident.as_ref().expect("named field");
+                    let field_name = camel_case(&ident.unraw().to_string());
+
+                    quote! {
+                        #ident: ::elmio_core::port::decode_field(&msg.type_, &msg.data, #field_name)?
+                    }
+                });
+
+                quote!(Self::#variant_ident { #(#values),* })
+            }
+        };
+
+        quote! {
+            #type_name => Ok(#construct),
+        }
+    });
+
+    Ok(quote! {
+        impl ::elmio_core::port::IncomingPort for #name {
+            fn from_js_msg(msg: ::elmio_core::page::JsMsg) -> Result<Self, String> {
+                match msg.type_.as_str() {
+                    #(#arms)*
+                    _ => Err(::elmio_core::port::unknown_type(&msg.type_)),
+                }
+            }
+        }
+    })
+}
+
+pub fn expand_outgoing(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let data = port_enum(ast, "OutgoingPort")?;
+
+    let arms = data.variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        let type_name = camel_case(&variant_ident.unraw().to_string());
+
+        let (pattern, data) = match &variant.fields {
+            Fields::Unit => (
+                quote!(Self::#variant_ident),
+                quote!(::elmio_core::port::encode_data(#type_name, &())?),
+            ),
+
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (
+                quote!(Self::#variant_ident(field_0)),
+                quote!(::elmio_core::port::encode_data(#type_name, field_0)?),
+            ),
+
+            Fields::Unnamed(fields) => {
+                let bindings: Vec<_> = (0..fields.unnamed.len())
+                    .map(|index| format_ident!("field_{}", index))
+                    .collect();
+
+                (
+                    quote!(Self::#variant_ident(#(#bindings),*)),
+                    quote!(::elmio_core::port::encode_data(#type_name, &(#(#bindings),*))?),
+                )
+            }
+
+            Fields::Named(fields) => {
+                let idents: Vec<_> = fields
+                    .named
+                    .iter()
+                    .map(|field| field.ident.as_ref().expect("named field"))
+                    .collect();
+
+                let entries = idents.iter().map(|ident| {
+                    let field_name = camel_case(&ident.unraw().to_string());
+                    quote!((#field_name, ::elmio_core::port::encode_data(#type_name, #ident)?))
+                });
+
+                (
+                    quote!(Self::#variant_ident { #(#idents),* }),
+                    quote!(::elmio_core::port::encode_fields(vec![#(#entries),*])),
+                )
+            }
+        };
+
+        quote! {
+            #pattern => ::elmio_core::port::js_msg(#type_name, #data),
+        }
+    });
+
+    Ok(quote! {
+        impl ::elmio_core::port::OutgoingPort for #name {
+            fn to_js_msg(&self) -> Result<::elmio_core::page::JsMsg, String> {
+                Ok(match self {
+                    #(#arms)*
+                })
+            }
+        }
+    })
+}
+
+fn port_enum<'a>(ast: &'a DeriveInput, derive: &str) -> syn::Result<&'a DataEnum> {
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &ast.generics,
+            format!("{} can not be derived for generic enums", derive),
+        ));
+    }
+
+    match &ast.data {
+        Data::Enum(data) => Ok(data),
+        _ => Err(syn::Error::new_spanned(
+            ast,
+            format!("{} can only be derived for enums", derive),
+        )),
+    }
+}
+
+pub fn camel_case(name: &str) -> String {
+    let mut camel = String::with_capacity(name.len());
+    let mut upper_next = false;
+
+    for (index, c) in name.chars().enumerate() {
+        if c == '_' {
+            upper_next = index > 0;
+        } else if index == 0 {
+            camel.extend(c.to_lowercase());
+        } else if upper_next {
+            camel.extend(c.to_uppercase());
+            upper_next = false;
+        } else {
+            camel.push(c);
+        }
+    }
+
+    camel
+}
@@ -0,0 +1,307 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, GenericArgument, Ident, LitStr, PathArguments, Type};
+
+enum RouteSegment {
+    Static(String),
+    Param(usize),
+}
+
+struct RouteField {
+    binding: Ident,
+    ty: Type,
+    query_name: Option<String>,
+}
+
+pub fn expand(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let Data::Enum(data) = &ast.data else {
+        return Err(syn::Error::new_spanned(
+            ast,
+            "Route can only be derived for enums",
+        ));
+    };
+
+    let mut name_arms = Vec::new();
+    let mut path_arms = Vec::new();
+    let mut parsers = Vec::new();
+
+    for variant in &data.variants {
+        let variant_ident = &variant.ident;
+        let variant_name = variant_ident.to_string();
+
+        let pattern = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("route"))
+            .ok_or_else(|| {
+                syn::Error::new_spanned(variant, "missing #[route(\"/path\")] attribute")
+            })?
+            .parse_args::<LitStr>()?;
+
+        let fields: Vec<RouteField> = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| RouteField {
+                binding: match &field.ident {
+                    Some(ident) => ident.clone(),
+                    None => format_ident!("field_{}", index),
+                },
+                ty: field.ty.clone(),
+                query_name: field.ident.as_ref().map(|ident| ident.unraw().to_string()),
+            })
+            .collect();
+
+        let (segments, fields) = parse_route_pattern(&pattern, &variant.fields, fields)?;
+
+        let bindings: Vec<&Ident> = fields.iter().map(|field| &field.binding).collect();
+        let destructure = match &variant.fields {
+            Fields::Named(_) => quote!(Self::#variant_ident { #(#bindings),* }),
+            Fields::Unnamed(_) => quote!(Self::#variant_ident ( #(#bindings),* )),
+            Fields::Unit => quote!(Self::#variant_ident),
+        };
+
+        let wildcard = match &variant.fields {
+            Fields::Named(_) => quote!(Self::#variant_ident { .. }),
+            Fields::Unnamed(_) => quote!(Self::#variant_ident(..)),
+            Fields::Unit => quote!(Self::#variant_ident),
+        };
+
+        name_arms.push(quote! {
+            #wildcard => #variant_name.to_string(),
+        });
+
+        // Building the path.
+
+        let push_segments = segments.iter().map(|segment| match segment {
+            RouteSegment::Static(value) => quote! {
+                path.push('/');
+                path.push_str(#value);
+            },
+            RouteSegment::Param(index) => {
+                let binding = &fields[*index].binding;
+                quote! {
+                    path.push('/');
+                    path.push_str(&::elmio_core::route::encode(&#binding.to_string()));
+                }
+            }
+        });
+
+        let push_query = fields.iter().filter_map(|field| {
+            let query_name = field.query_name.as_ref()?;
+            let binding = &field.binding;
+
+            Some(match option_inner(&field.ty) {
+                Some(_) => quote! {
+                    if let Some(value) = #binding {
+                        query.push((#query_name, value.to_string()));
+                    }
+                },
+                None => quote! {
+                    query.push((#query_name, #binding.to_string()));
+                },
+            })
+        });
+
+        path_arms.push(quote! {
+            #[allow(unused_variables)]
+            #destructure => {
+                let mut path = String::new();
+                #(#push_segments)*
+                if path.is_empty() {
+                    path.push('/');
+                }
+
+                #[allow(unused_mut)]
+                let mut query: Vec<(&str, String)> = Vec::new();
+                #(#push_query)*
+
+                ::elmio_core::route::with_query(path, &query)
+            }
+        });
+
+        // Parsing the path.
+
+        let matchers = segments.iter().map(|segment| match segment {
+            RouteSegment::Static(value) => value.clone(),
+            RouteSegment::Param(index) => format!(":{}", fields[*index].binding),
+        });
+
+        let field_values = fields.iter().map(|field| {
+            let binding = &field.binding;
+
+            match (&field.query_name, option_inner(&field.ty)) {
+                (Some(query_name), Some(inner)) => quote! {
+                    route_path.optional_query::<#inner>(#query_name)?
+                },
+                (Some(query_name), None) => {
+                    let ty = &field.ty;
+                    quote! {
+                        route_path.required_query::<#ty>(#query_name)?
+                    }
+                }
+                (None, _) => quote!(#binding),
+            }
+        });
+
+        let construct = match &variant.fields {
+            Fields::Named(_) => quote! {
+                Self::#variant_ident { #(#bindings: #field_values),* }
+            },
+            Fields::Unnamed(_) => quote!(Self::#variant_ident ( #(#field_values),* )),
+            Fields::Unit => quote!(Self::#variant_ident),
+        };
+
+        let params: Vec<(usize, &RouteField)> = segments
+            .iter()
+            .filter_map(|segment| match segment {
+                RouteSegment::Param(index) => Some(*index),
+                RouteSegment::Static(_) => None,
+            })
+            .map(|index| &fields[index])
+            .enumerate()
+            .collect();
+
+        // A segment that fails to parse falls through to the next variant,
+        // so `/users/new` can follow `/users/:id` with a numeric id.
+        let parser =
+            params
+                .iter()
+                .rev()
+                .fold(quote!(return Ok(#construct);), |body, (capture, field)| {
+                    let binding = &field.binding;
+                    let ty = &field.ty;
+                    quote! {
+                        if let Ok(#binding) = captures[#capture].parse::<#ty>() {
+                            #body
+                        }
+                    }
+                });
+
+        parsers.push(if params.is_empty() {
+            quote! {
+                if route_path.matches(&[#(#matchers),*]).is_some() {
+                    #parser
+                }
+            }
+        } else {
+            quote! {
+                if let Some(captures) = route_path.matches(&[#(#matchers),*]) {
+                    #parser
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::elmio_core::route::Route for #name #ty_generics #where_clause {
+            fn name(&self) -> String {
+                match self {
+                    #(#name_arms)*
+                }
+            }
+
+            fn path(&self) -> String {
+                match self {
+                    #(#path_arms)*
+                }
+            }
+        }
+
+        impl #impl_generics ::elmio_core::route::ParseRoute for #name #ty_generics #where_clause {
+            fn from_path(path: &str) -> Result<Self, ::elmio_core::route::RouteError> {
+                let route_path = ::elmio_core::route::RoutePath::parse(path);
+
+                #(#parsers)*
+
+                Err(::elmio_core::route::RouteError::NotFound(path.to_string()))
+            }
+        }
+    })
+}
+
+fn parse_route_pattern(
+    pattern: &LitStr,
+    variant_fields: &Fields,
+    mut fields: Vec<RouteField>,
+) -> syn::Result<(Vec<RouteSegment>, Vec<RouteField>)> {
+    let value = pattern.value();
+
+    if !value.starts_with('/') {
+        return Err(syn::Error::new_spanned(
+            pattern,
+            "route patterns must start with '/'",
+        ));
+    }
+
+    let mut segments = Vec::new();
+    let mut param_count = 0;
+
+    for segment in value.split('/').filter(|segment| !segment.is_empty()) {
+        let Some(param) = segment.strip_prefix(':') else {
+            segments.push(RouteSegment::Static(segment.to_string()));
+            continue;
+        };
+
+        let index = match variant_fields {
+            Fields::Unnamed(_) => Some(param_count).filter(|index| *index < fields.len()),
+            _ => fields
+                .iter()
+                .position(|field| field.query_name.as_deref() == Some(param)),
+        };
+
+        let Some(index) = index else {
+            return Err(syn::Error::new_spanned(
+                pattern,
+                format!("no field for path parameter ':{}'", param),
+            ));
+        };
+
+        if fields[index].query_name.is_none() && matches!(variant_fields, Fields::Named(_)) {
+            return Err(syn::Error::new_spanned(
+                pattern,
+                format!("path parameter ':{}' is used more than once", param),
+            ));
+        }
+
+        fields[index].query_name = None;
+        segments.push(RouteSegment::Param(index));
+        param_count += 1;
+    }
+
+    if let Fields::Unnamed(unnamed) = variant_fields
+        && param_count != fields.len()
+    {
+        return Err(syn::Error::new(
+            unnamed.span(),
+            "tuple variants need one path parameter per field",
+        ));
+    }
+
+    Ok((segments, fields))
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
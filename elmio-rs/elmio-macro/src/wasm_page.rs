@@ -0,0 +1,180 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::{Fields, ItemStruct, LitStr, meta::ParseNestedMeta};
+
+use crate::port::camel_case;
+
+#[derive(Default)]
+pub struct Args {
+    js_name: Option<String>,
+}
+
+impl Args {
+    pub fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("js_name") {
+            let value: LitStr = meta.value()?.parse()?;
+            self.js_name = Some(value.value());
+            Ok(())
+        } else {
+            Err(meta.error("unsupported wasm_page argument, expected `js_name`"))
+        }
+    }
+}
+
+pub fn expand(args: Args, item: &ItemStruct) -> syn::Result<TokenStream> {
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item.generics,
+            "wasm_page can not be used on generic structs",
+        ));
+    }
+
+    let name = &item.ident;
+    let name_string = name.unraw().to_string();
+    let module = format_ident!("__elmio_wasm_{}", snake_case(&name_string));
+    let wrapper = format_ident!("Wasm{}", name_string);
+    let constructor = format_ident!("new_{}", snake_case(&name_string));
+    let js_name = args.js_name.unwrap_or_else(|| camel_case(&name_string));
+
+    // One JS argument per field, decoded with serde.
+    let fields: Vec<_> = item
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let name = match &field.ident {
+                Some(ident) => ident.unraw().to_string(),
+                None => format!("field_{}", index),
+            };
+
+            (format_ident!("js_{}", name), camel_case(&name))
+        })
+        .collect();
+
+    let params = fields.iter().map(|(param, _)| quote!(#param: JsValue));
+
+    let decoders = fields.iter().map(|(param, js_name)| {
+        quote! {
+            let #param = wasm::decode_js_value(#param)
+                .map_err(|err| format!("Failed to decode {}: {}", #js_name, err))?;
+        }
+    });
+
+    let params_iter = fields.iter().map(|(param, _)| param);
+    let field_idents = item.fields.iter().filter_map(|field| field.ident.as_ref());
+
+    let page = match &item.fields {
+        Fields::Named(_) => quote!(super::#name { #(#field_idents: #params_iter),* }),
+        Fields::Unnamed(_) => quote!(super::#name(#(#params_iter),*)),
+        Fields::Unit => quote!(super::#name),
+    };
+
+    // Reports a missing `Page` impl on the struct itself, before the errors
+    // of the generated methods.
+    let assert_page = quote_spanned! {name.span()=>
+        const _: fn() = {
+            fn assert_page<P, Model, Msg, Markup>()
+            where
+                P: ::elmio_core::page::Page<Model, Msg, Markup>,
+            {
+            }
+
+            assert_page::<#name, _, _, _>
+        };
+    };
+
+    let vis = &item.vis;
+    let bindgen_path = quote!(::elmio_core::page::wasm::wasm_bindgen);
+
+    // The wrapper lives in its own module so that the wasm-bindgen
+    // re-exported by elmio-core is in scope for the generated bindings, which
+    // do not all honor the `wasm_bindgen` path.
+    Ok(quote! {
+        #item
+
+        #assert_page
+
+        #[doc(hidden)]
+        mod #module {
+            use ::elmio_core::page::Page;
+            use ::elmio_core::page::wasm;
+            use ::elmio_core::page::wasm::wasm_bindgen;
+            use ::elmio_core::page::wasm::wasm_bindgen::JsValue;
+            use ::elmio_core::page::wasm::wasm_bindgen::prelude::wasm_bindgen;
+
+            #[wasm_bindgen(wasm_bindgen = #bindgen_path, js_name = #name_string)]
+            pub struct #wrapper(super::#name);
+
+            #[wasm_bindgen(wasm_bindgen = #bindgen_path)]
+            impl #wrapper {
+                #[wasm_bindgen(js_name = "id")]
+                pub fn id(&self) -> Result<String, JsValue> {
+                    Ok(Page::id(&self.0).to_string())
+                }
+
+                #[wasm_bindgen(js_name = "init")]
+                pub fn initial_model(&self) -> Result<JsValue, JsValue> {
+                    wasm::init(&self.0)
+                }
+
+                #[wasm_bindgen(js_name = "view")]
+                pub fn view(&self, js_model: &JsValue) -> Result<String, JsValue> {
+                    wasm::view(&self.0, js_model)
+                }
+
+                #[wasm_bindgen(js_name = "viewBody")]
+                pub fn view_body(&self, js_model: &JsValue) -> Result<String, JsValue> {
+                    wasm::view_body(&self.0, js_model)
+                }
+
+                #[wasm_bindgen(js_name = "getSubscriptions")]
+                pub fn get_subscriptions(&self, js_model: &JsValue) -> Result<JsValue, JsValue> {
+                    wasm::get_subscriptions(&self.0, js_model)
+                }
+
+                #[wasm_bindgen(js_name = "update")]
+                pub fn update(&self, js_msg: &JsValue, js_model: &JsValue) -> Result<JsValue, JsValue> {
+                    wasm::update(&self.0, js_msg, js_model)
+                }
+
+                #[wasm_bindgen(js_name = "updateFromJs")]
+                pub fn update_from_js(
+                    &self,
+                    js_msg: &JsValue,
+                    js_model: &JsValue,
+                ) -> Result<JsValue, JsValue> {
+                    wasm::update_from_js(&self.0, js_msg, js_model)
+                }
+            }
+
+            impl #wrapper {
+                /// The name of the JavaScript constructor.
+                pub const JS_CONSTRUCTOR: &'static str = #js_name;
+            }
+
+            #[wasm_bindgen(wasm_bindgen = #bindgen_path, js_name = #js_name)]
+            pub fn #constructor(#(#params),*) -> Result<#wrapper, JsValue> {
+                #(#decoders)*
+
+                Ok(#wrapper(#page))
+            }
+        }
+
+        #vis use #module::#wrapper;
+    })
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len());
+
+    for (index, c) in name.chars().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            snake.push('_');
+        }
+
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}